@@ -3,6 +3,7 @@ use crate::test_context::{
     BASE_AMOUNT,
 };
 use fuel_core_chain_config::ContractConfig;
+use fuel_core_client::client::types::DryRunOptions;
 use fuel_core_types::{
     fuel_tx::{
         field::{
@@ -42,7 +43,7 @@ pub async fn receipts(ctx: &TestContext) -> Result<(), Failed> {
     let queries = futures::future::join_all(queries).await;
     for query in queries {
         let (query, query_number) = query;
-        let receipts = query?;
+        let receipts = query?.receipts;
         if receipts.is_none() {
             return Err(
                 format!("Receipts are empty for query_number {query_number}").into(),
@@ -128,7 +129,17 @@ async fn _dry_runs(
     for i in 0..count {
         queries.push(async move {
             let before = tokio::time::Instant::now();
-            let query = ctx.alice.client.dry_run_opt(transaction, Some(false)).await;
+            let query = ctx
+                .alice
+                .client
+                .dry_run_opt(
+                    &[transaction.clone()],
+                    DryRunOptions {
+                        utxo_validation: Some(false),
+                        ..Default::default()
+                    },
+                )
+                .await;
             println!(
                 "Received the response for the query number {i} for {}ms",
                 before.elapsed().as_millis()
@@ -147,7 +158,7 @@ async fn _dry_runs(
             println!("The query {query_number} failed with {e}");
         }
 
-        let receipts = query?;
+        let receipts = &query?[0].receipts;
         if receipts.is_empty() {
             return Err(
                 format!("Receipts are empty for query_number {query_number}").into(),
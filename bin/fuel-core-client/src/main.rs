@@ -68,7 +68,7 @@ impl CliArgs {
                     let tx: Transaction =
                         serde_json::from_str(tx).expect("invalid transaction json");
 
-                    let result = client.dry_run(&tx).await;
+                    let result = client.dry_run(&[tx]).await;
                     println!("{:?}", result.unwrap());
                 }
                 TransactionCommands::Get { id } => {
@@ -19,6 +19,7 @@ lazy_static::lazy_static! {
 }
 
 pub mod fee_contract;
+pub mod migrate;
 pub mod run;
 pub mod snapshot;
 
@@ -39,11 +40,14 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    Migrate(migrate::Command),
     GenerateFeeContract(fee_contract::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
 pub const HUMAN_LOGGING: &str = "HUMAN_LOGGING";
+/// The endpoint of the OpenTelemetry collector. Spans are exported over OTLP/HTTP if it is set.
+pub const OTLP_ENDPOINT: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
 
 #[cfg(feature = "env")]
 fn init_environment() -> Option<PathBuf> {
@@ -91,9 +95,15 @@ pub async fn init_logging() -> anyhow::Result<()> {
             .boxed()
     };
 
+    let otlp = env::var(OTLP_ENDPOINT)
+        .ok()
+        .map(|endpoint| fuel_core_trace::otlp_layer(&endpoint, "fuel-core"))
+        .transpose()?;
+
     let subscriber = registry::Registry::default() // provide underlying span data store
         .with(filter) // filter out low-level debug tracing (eg tokio executor)
-        .with(fmt); // log to stdout
+        .with(fmt) // log to stdout
+        .with(otlp); // export spans to the OpenTelemetry collector, if enabled
 
     tracing::subscriber::set_global_default(subscriber)
         .expect("setting global default failed");
@@ -115,15 +125,21 @@ pub async fn run_cli() -> anyhow::Result<()> {
         }
     }
 
-    match opt {
+    let result = match opt {
         Ok(opt) => match opt.command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            Fuel::Migrate(command) => migrate::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
             e.exit()
         }
-    }
+    };
+
+    // Export the remaining spans before exit. The shutdown blocks the thread
+    // until the batch exporter sends them to the collector.
+    tokio::task::spawn_blocking(fuel_core_trace::shutdown_otlp).await?;
+    result
 }
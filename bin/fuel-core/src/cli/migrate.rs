@@ -0,0 +1,62 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Upgrade the database to the version supported by this build.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// Run the migrations and report the changes without writing them to the database.
+    #[clap(long = "dry-run")]
+    dry_run: bool,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::Database;
+    let path = command.database_path;
+    let data_source = fuel_core::state::rocks_db::RocksDb::default_open(&path, None)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!(
+            "failed to open database at path {}",
+            path.display()
+        ))?;
+    let db = Database::new(std::sync::Arc::new(data_source));
+
+    let summaries = db.migrate(command.dry_run)?;
+    if summaries.is_empty() {
+        tracing::info!("The database is up to date");
+    }
+    for summary in summaries {
+        tracing::info!(
+            "Version {:#x} ({}): {} of {} entries {}",
+            summary.version,
+            summary.description,
+            summary.changed_entries,
+            summary.visited_entries,
+            if command.dry_run {
+                "would be changed"
+            } else {
+                "changed"
+            }
+        );
+    }
+    Ok(())
+}
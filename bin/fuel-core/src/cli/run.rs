@@ -16,11 +16,16 @@ use fuel_core::{
         default_consensus_dev_key,
         ChainConfig,
     },
+    database::history::StateRewindPolicy,
     producer::Config as ProducerConfig,
     service::{
         config::Trigger,
+        ApiLimitsConfig,
         Config,
         DbType,
+        DebuggerConfig,
+        IndexesConfig,
+        RateLimitConfig,
         RelayerConsensusConfig,
         ServiceTrait,
         VMConfig,
@@ -31,6 +36,7 @@ use fuel_core::{
         fuel_tx::ContractId,
         fuel_vm::SecretKey,
         secrecy::Secret,
+        services::graphql_api::OffChainIndex,
     },
 };
 use pyroscope::{
@@ -104,6 +110,13 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    /// The number of latest blocks whose modifications of the state are kept in the database.
+    /// It allows dry runs and debugging on top of the state of these blocks.
+    /// The history is not recorded if it is not set, so the debugging of the transactions
+    /// from the chain is not available.
+    #[arg(long = "state-rewind-blocks", env)]
+    pub state_rewind_blocks: Option<u32>,
+
     /// Specify either an alias to a built-in configuration or filepath to a JSON file.
     #[arg(
         name = "CHAIN_CONFIG",
@@ -120,10 +133,48 @@ pub struct Command {
     #[arg(long = "debug", env)]
     pub debug: bool,
 
+    /// The maximum number of debugger sessions that can exist at the same time.
+    #[arg(long = "debug-max-sessions", default_value = "8", env)]
+    pub debug_max_sessions: usize,
+
+    /// The debugger session is killed if it is not used longer than this timeout.
+    #[arg(long = "debug-session-timeout", default_value = "10m", env)]
+    pub debug_session_timeout: humantime::Duration,
+
+    /// The maximum memory in bytes allocated by the VMs of all debugger sessions.
+    /// Each session allocates 64 MiB for the VM and the same for the evaluated predicate.
+    #[arg(long = "debug-max-memory", default_value = "1073741824", env)]
+    pub debug_max_memory: usize,
+
     /// Enable logging of backtraces from vm errors
     #[arg(long = "vm-backtrace", env)]
     pub vm_backtrace: bool,
 
+    /// Execute transactions of the block that don't conflict with each other
+    /// concurrently. The result is the same as of the sequential execution.
+    #[arg(long = "parallel-execution", env)]
+    pub parallel_execution: bool,
+
+    /// Record the changes of the state made by each executed transaction and store
+    /// them in the off-chain database, so they are available over the GraphQL API.
+    #[arg(long = "state-diff", env)]
+    pub state_diff: bool,
+
+    /// The maximum number of instructions in the trace of the dry run.
+    /// The traced dry run fails if the script executes more instructions.
+    #[arg(long = "max-trace-steps", default_value = "10000", env)]
+    pub max_trace_steps: usize,
+
+    /// The comma-separated list of the off-chain indexes used by the GraphQL API:
+    /// `owners`, `contracts`, `predicate-roots`, `script-hashes`, `coins-by-amount`,
+    /// `assets` and `block-times`. All indexes except `predicate-roots` and
+    /// `script-hashes` are enabled by default. The flag without values disables all
+    /// indexes. The newly enabled index is backfilled from the stored blocks in the background,
+    /// and `coins-by-amount` is built from the unspent coins. Without `coins-by-amount`,
+    /// the coin selection loads all coins of the owner.
+    #[arg(long = "indexes", value_delimiter = ',', num_args = 0.., env)]
+    pub indexes: Option<Vec<OffChainIndex>>,
+
     /// Enable full utxo stateful validation
     /// disabled by default until downstream consumers stabilize
     #[arg(long = "utxo-validation", env)]
@@ -193,6 +244,16 @@ pub struct Command {
     #[clap(long = "time-until-synced", default_value = "0s", env)]
     pub time_until_synced: humantime::Duration,
 
+    /// The maximum number of blocks the node can lag behind peers
+    /// to be reported as ready by the `/health/ready` endpoint.
+    #[clap(long = "readiness-max-block-lag", default_value = "10", env)]
+    pub readiness_max_block_lag: u32,
+
+    /// The maximum number of DA blocks the relayer can lag behind the DA layer
+    /// to be reported as ready by the `/health/ready` endpoint.
+    #[clap(long = "readiness-max-da-lag", default_value = "10", env)]
+    pub readiness_max_da_lag: u64,
+
     /// Time to wait after submitting a query before debug info will be logged about query.
     #[clap(long = "query-log-threshold-time", default_value = "2s", env)]
     pub query_log_threshold_time: humantime::Duration,
@@ -201,6 +262,53 @@ pub struct Command {
     #[clap(long = "api-request-timeout", default_value = "30m", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The max depth of the GraphQL query.
+    #[clap(long = "graphql-max-depth", default_value = "16", env)]
+    pub graphql_max_depth: usize,
+
+    /// The max complexity of the GraphQL query. Each field costs one, and the paginated
+    /// fields multiply the cost of their nodes by the page size.
+    #[clap(long = "graphql-max-complexity", default_value = "80000", env)]
+    pub graphql_max_complexity: usize,
+
+    /// The max number of entries requested from the paginated GraphQL field.
+    #[clap(long = "graphql-max-page-size", default_value = "10000", env)]
+    pub graphql_max_page_size: usize,
+
+    /// The max number of transactions in one dry run request.
+    #[clap(long = "graphql-max-dry-run-transactions", default_value = "64", env)]
+    pub graphql_max_dry_run_transactions: usize,
+
+    /// The max number of blocks the state is rewound by for one dry run request.
+    #[clap(long = "graphql-max-dry-run-rewind", default_value = "128", env)]
+    pub graphql_max_dry_run_rewind: u32,
+
+    /// The number of GraphQL requests allowed to one client during `api-rate-limit-period`.
+    /// The requests are not limited if it is not set.
+    #[clap(
+        long = "api-rate-limit",
+        value_parser = clap::value_parser!(u32).range(1..),
+        env
+    )]
+    pub api_rate_limit: Option<u32>,
+
+    /// The period of the `api-rate-limit`.
+    #[clap(long = "api-rate-limit-period", default_value = "1m", env)]
+    pub api_rate_limit_period: humantime::Duration,
+
+    /// The requests with one of these keys in the `X-Api-Key` header are rate limited
+    /// per key instead of per IP address of the client.
+    #[clap(long = "api-keys", value_delimiter = ',', env)]
+    pub api_keys: Vec<String>,
+
+    /// The header with the IP address of the client set by the trusted reverse proxy,
+    /// e.g. `X-Forwarded-For`. The last address in the header is used for the rate limit.
+    /// Without it, all clients behind a proxy share one limit of the proxy address.
+    /// Set it only if the node is reachable through the proxy alone,
+    /// otherwise, the clients can forge the header.
+    #[clap(long = "api-rate-limit-forwarded-header", env)]
+    pub api_rate_limit_forwarded_header: Option<String>,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
 }
@@ -214,9 +322,17 @@ impl Command {
             max_database_cache_size,
             database_path,
             database_type,
+            state_rewind_blocks,
             chain_config,
             vm_backtrace,
+            parallel_execution,
+            state_diff,
+            max_trace_steps,
+            indexes,
             debug,
+            debug_max_sessions,
+            debug_session_timeout,
+            debug_max_memory,
             utxo_validation,
             min_gas_price,
             consensus_key,
@@ -237,8 +353,19 @@ impl Command {
             tx_number_active_subscriptions,
             min_connected_reserved_peers,
             time_until_synced,
+            readiness_max_block_lag,
+            readiness_max_da_lag,
             query_log_threshold_time,
             api_request_timeout,
+            graphql_max_depth,
+            graphql_max_complexity,
+            graphql_max_page_size,
+            graphql_max_dry_run_transactions,
+            graphql_max_dry_run_rewind,
+            api_rate_limit,
+            api_rate_limit_period,
+            api_keys,
+            api_rate_limit_forwarded_header,
             profiling: _,
         } = self;
 
@@ -300,15 +427,40 @@ impl Command {
         let config = Config {
             addr,
             api_request_timeout: api_request_timeout.into(),
+            api_limits: ApiLimitsConfig {
+                max_depth: graphql_max_depth,
+                max_complexity: graphql_max_complexity,
+                max_page_size: graphql_max_page_size,
+                max_dry_run_transactions: graphql_max_dry_run_transactions,
+                max_dry_run_rewind: graphql_max_dry_run_rewind,
+                rate_limit: api_rate_limit.map(|requests| RateLimitConfig {
+                    requests,
+                    period: api_rate_limit_period.into(),
+                    api_keys: api_keys.into_iter().collect(),
+                    forwarded_for_header: api_rate_limit_forwarded_header,
+                }),
+            },
+            indexes: indexes.map(IndexesConfig::new).unwrap_or_default(),
             max_database_cache_size,
             database_path,
             database_type,
+            state_rewind_policy: state_rewind_blocks
+                .map(|size| StateRewindPolicy::RewindRange { size })
+                .unwrap_or_default(),
             chain_conf: chain_conf.clone(),
             debug,
+            debugger: DebuggerConfig {
+                max_sessions: debug_max_sessions,
+                session_timeout: debug_session_timeout.into(),
+                max_memory: debug_max_memory,
+            },
             utxo_validation,
             block_production: trigger,
             vm: VMConfig {
                 backtrace: vm_backtrace,
+                parallel_execution,
+                state_diff,
+                max_trace_steps,
             },
             txpool: TxPoolConfig::new(
                 tx_max_number,
@@ -337,6 +489,8 @@ impl Command {
             relayer_consensus_config: verifier,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
+            readiness_max_block_lag,
+            readiness_max_da_lag,
             query_log_threshold_time: query_log_threshold_time.into(),
         };
         Ok(config)
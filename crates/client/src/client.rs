@@ -1,6 +1,9 @@
 use crate::client::{
     schema::{
-        block::BlockByHeightArgs,
+        block::{
+            BlockByHeightArgs,
+            BlockByTimeArgs,
+        },
         coins::{
             ExcludeInput,
             SpendQueryElementInput,
@@ -17,6 +20,7 @@ use crate::client::{
             Address,
             AssetId,
             BlockId,
+            Bytes32,
             ContractId,
             UtxoId,
         },
@@ -49,7 +53,10 @@ use fuel_core_types::{
         BlockHeight,
         Nonce,
     },
-    services::p2p::PeerInfo,
+    services::{
+        health::ReadinessReport,
+        p2p::PeerInfo,
+    },
 };
 #[cfg(feature = "subscriptions")]
 use futures::StreamExt;
@@ -69,6 +76,7 @@ use schema::{
         TxIdArgs,
     },
     Bytes,
+    CallFrame,
     ContinueTx,
     ContinueTxArgs,
     ConversionError,
@@ -81,8 +89,12 @@ use schema::{
     SetBreakpointArgs,
     SetSingleStepping,
     SetSingleSteppingArgs,
+    StartPredicate,
+    StartPredicateArgs,
     StartTx,
     StartTxArgs,
+    StartTxFromChain,
+    StartTxFromChainArgs,
     U32,
     U64,
 };
@@ -111,6 +123,8 @@ use self::schema::{
     block::ProduceBlockArgs,
     message::{
         MessageProofArgs,
+        MessageProofInput,
+        MessageProofsArgs,
         NonceArgs,
     },
 };
@@ -340,6 +354,11 @@ impl FuelClient {
         self.query(query).await.map(|r| r.health)
     }
 
+    pub async fn readiness(&self) -> io::Result<ReadinessReport> {
+        let query = schema::health::QueryReadiness::build(());
+        self.query(query).await.map(|r| r.readiness.into())
+    }
+
     pub async fn node_info(&self) -> io::Result<types::NodeInfo> {
         let query = schema::node_info::QueryNodeInfo::build(());
         self.query(query).await.map(|r| r.node_info.into())
@@ -357,27 +376,36 @@ impl FuelClient {
         self.query(query).await.map(|r| r.chain.into())
     }
 
-    /// Default dry run, matching the exact configuration as the node
-    pub async fn dry_run(&self, tx: &Transaction) -> io::Result<Vec<Receipt>> {
-        self.dry_run_opt(tx, None).await
+    /// Default dry run, matching the exact configuration as the node.
+    /// Transactions are executed in order, so each of them can use the outputs of the previous ones.
+    pub async fn dry_run(
+        &self,
+        txs: &[Transaction],
+    ) -> io::Result<Vec<types::DryRunTransactionExecutionStatus>> {
+        self.dry_run_opt(txs, Default::default()).await
     }
 
     /// Dry run with options to override the node behavior
     pub async fn dry_run_opt(
         &self,
-        tx: &Transaction,
-        // Disable utxo input checks (exists, unspent, and valid signature)
-        utxo_validation: Option<bool>,
-    ) -> io::Result<Vec<Receipt>> {
-        let tx = tx.clone().to_bytes();
+        txs: &[Transaction],
+        options: types::DryRunOptions,
+    ) -> io::Result<Vec<types::DryRunTransactionExecutionStatus>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect();
         let query = schema::tx::DryRun::build(DryRunArg {
-            tx: HexString(Bytes(tx)),
-            utxo_validation,
+            txs,
+            utxo_validation: options.utxo_validation,
+            trace: options.trace,
+            state_diff: options.state_diff,
+            block_height: options.block_height.map(Into::into),
         });
-        let receipts = self.query(query).await.map(|r| r.dry_run)?;
-        receipts
+        let statuses = self.query(query).await.map(|r| r.dry_run)?;
+        statuses
             .into_iter()
-            .map(|receipt| receipt.try_into().map_err(Into::into))
+            .map(|status| status.try_into().map_err(Into::into))
             .collect()
     }
 
@@ -516,6 +544,47 @@ impl FuelClient {
         Ok(serde_json::from_str(memory.as_str())?)
     }
 
+    /// Returns the call frames of the session from the outermost to the innermost one.
+    pub async fn call_frames(&self, id: &str) -> io::Result<Vec<CallFrame>> {
+        let query = schema::CallFrames::build(IdArg { id: id.into() });
+
+        self.query(query).await.map(|r| r.call_frames)
+    }
+
+    /// Returns the value of the contract storage slot as seen by the session.
+    pub async fn session_storage_slot(
+        &self,
+        id: &str,
+        contract_id: &fuel_types::ContractId,
+        key: &fuel_types::Bytes32,
+    ) -> io::Result<Option<fuel_types::Bytes32>> {
+        let query = schema::SessionStorageSlot::build(schema::SessionStorageSlotArgs {
+            id: id.into(),
+            contract_id: (*contract_id).into(),
+            key: (*key).into(),
+        });
+
+        let value = self.query(query).await?.session_storage_slot;
+
+        Ok(value.map(Into::into))
+    }
+
+    /// Returns the balance of the contract as seen by the session.
+    pub async fn session_balance(
+        &self,
+        id: &str,
+        contract_id: &fuel_types::ContractId,
+        asset_id: &fuel_types::AssetId,
+    ) -> io::Result<u64> {
+        let query = schema::SessionBalance::build(schema::SessionBalanceArgs {
+            id: id.into(),
+            contract_id: (*contract_id).into(),
+            asset_id: (*asset_id).into(),
+        });
+
+        Ok(self.query(query).await?.session_balance.into())
+    }
+
     pub async fn set_breakpoint(
         &self,
         session_id: &str,
@@ -538,6 +607,28 @@ impl FuelClient {
         Ok(())
     }
 
+    pub async fn remove_breakpoint(
+        &self,
+        session_id: &str,
+        contract: fuel_types::ContractId,
+        pc: u64,
+    ) -> io::Result<()> {
+        let operation = schema::RemoveBreakpoint::build(SetBreakpointArgs {
+            id: Id::new(session_id),
+            bp: schema::Breakpoint {
+                contract: contract.into(),
+                pc: U64(pc),
+            },
+        });
+
+        let response = self.query(operation).await?;
+        assert!(
+            response.remove_breakpoint,
+            "Removing breakpoint returned invalid reply"
+        );
+        Ok(())
+    }
+
     pub async fn set_single_stepping(
         &self,
         session_id: &str,
@@ -564,6 +655,40 @@ impl FuelClient {
         Ok(response)
     }
 
+    /// Starts the evaluation of the predicate of the `input_index` input of the
+    /// transaction. If `estimate` is `false`, the predicate uses the gas set in the input.
+    pub async fn start_predicate(
+        &self,
+        session_id: &str,
+        tx: &Transaction,
+        input_index: u32,
+        estimate: bool,
+    ) -> io::Result<RunResult> {
+        let operation = StartPredicate::build(StartPredicateArgs {
+            id: Id::new(session_id),
+            tx: serde_json::to_string(tx).expect("Couldn't serialize tx to json"),
+            input_index: input_index.into(),
+            estimate,
+        });
+        let response = self.query(operation).await?.start_predicate;
+        Ok(response)
+    }
+
+    /// Starts the debugging of the transaction included into the chain
+    /// on top of the state right before its execution.
+    pub async fn start_tx_from_chain(
+        &self,
+        session_id: &str,
+        tx_id: &TxId,
+    ) -> io::Result<RunResult> {
+        let operation = StartTxFromChain::build(StartTxFromChainArgs {
+            id: Id::new(session_id),
+            tx_id: (*tx_id).into(),
+        });
+        let response = self.query(operation).await?.start_tx_from_chain;
+        Ok(response)
+    }
+
     pub async fn continue_tx(&self, session_id: &str) -> io::Result<RunResult> {
         let operation = ContinueTx::build(ContinueTxArgs {
             id: Id::new(session_id),
@@ -681,6 +806,63 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions that use the contract as an input.
+    pub async fn transactions_by_contract(
+        &self,
+        contract_id: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let contract_id: schema::ContractId = (*contract_id).into();
+        let query =
+            schema::tx::TransactionsByContractQuery::build((contract_id, request).into());
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_contract
+            .try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of transactions that spend from the predicate with the root.
+    pub async fn transactions_by_predicate_root(
+        &self,
+        predicate_root: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let predicate_root: schema::Address = (*predicate_root).into();
+        let query = schema::tx::TransactionsByPredicateRootQuery::build(
+            (predicate_root, request).into(),
+        );
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_predicate_root
+            .try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of transactions with the script of the hash
+    /// or that deploy the contract bytecode with the root.
+    pub async fn transactions_by_script_hash(
+        &self,
+        script_hash: &Bytes32,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let script_hash: schema::Bytes32 = (*script_hash).into();
+        let query = schema::tx::TransactionsByScriptHashQuery::build(
+            (script_hash, request).into(),
+        );
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_script_hash
+            .try_into()?;
+        Ok(transactions)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
@@ -749,6 +931,17 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Retrieve the first block produced at or after the `time`.
+    pub async fn block_by_time(&self, time: Tai64) -> io::Result<Option<types::Block>> {
+        let query = schema::block::BlockByTimeQuery::build(BlockByTimeArgs {
+            time: Some(time.into()),
+        });
+
+        let block = self.query(query).await?.block.map(Into::into);
+
+        Ok(block)
+    }
+
     /// Retrieve multiple blocks
     pub async fn blocks(
         &self,
@@ -761,6 +954,45 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Retrieve multiple blocks produced within the inclusive time range.
+    /// The range is not limited from the side where the time is `None`.
+    pub async fn blocks_by_time(
+        &self,
+        from_time: Option<Tai64>,
+        to_time: Option<Tai64>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Block, String>> {
+        let filter = schema::block::BlockFilterInput {
+            from_time: from_time.map(Into::into),
+            to_time: to_time.map(Into::into),
+        };
+        let query = schema::block::BlocksByTimeQuery::build((filter, request).into());
+
+        let blocks = self.query(query).await?.blocks.into();
+
+        Ok(blocks)
+    }
+
+    pub async fn asset(&self, id: &AssetId) -> io::Result<Option<types::Asset>> {
+        let query = schema::asset::AssetByIdQuery::build(schema::asset::AssetByIdArgs {
+            id: (*id).into(),
+        });
+        let asset = self.query(query).await?.asset.map(Into::into);
+        Ok(asset)
+    }
+
+    /// Retrieve a page of assets known by the node.
+    pub async fn assets(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Asset, String>> {
+        let query = schema::asset::AssetsQuery::build(request.into());
+
+        let assets = self.query(query).await?.assets.into();
+
+        Ok(assets)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -794,6 +1026,19 @@ impl FuelClient {
         spend_query: Vec<(AssetId, u64, Option<u32>)>,
         // (Utxos, Messages Nonce)
         excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        self.coins_to_spend_opt(owner, spend_query, excluded_ids, Default::default())
+            .await
+    }
+
+    /// Coins to spend with options to override the node behavior
+    pub async fn coins_to_spend_opt(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        options: types::CoinsToSpendOptions,
     ) -> io::Result<Vec<Vec<types::CoinType>>> {
         let owner: schema::Address = (*owner).into();
         let spend_query: Vec<SpendQueryElementInput> = spend_query
@@ -817,7 +1062,14 @@ impl FuelClient {
             )
             .map(Into::into);
         let query = schema::coins::CoinsToSpendQuery::build(
-            (owner, spend_query, excluded_ids).into(),
+            (
+                owner,
+                spend_query,
+                excluded_ids,
+                options.exclude_pending_inputs,
+                options.strategy.map(Into::into),
+            )
+                .into(),
         );
 
         let coins_per_asset = self
@@ -932,6 +1184,62 @@ impl FuelClient {
         Ok(status)
     }
 
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the status of a message. The stream ends after the message is spent.
+    pub async fn subscribe_message_status(
+        &self,
+        nonce: &Nonce,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<MessageStatus>>> {
+        use cynic::SubscriptionBuilder;
+        let s =
+            schema::message::MessageStatusChangeSubscription::build(MessageStatusArgs {
+                nonce: (*nonce).into(),
+            });
+
+        let stream = self.subscribe(s).await?.map(|r| {
+            let status = r?.message_status_change.into();
+            Ok(status)
+        });
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Awaits for the output message to become provable relatively to the commit block
+    /// at `commit_block_height` and returns the proof.
+    ///
+    /// This will wait forever if needed, so consider wrapping this call
+    /// with a `tokio::time::timeout`.
+    pub async fn await_message_proof(
+        &self,
+        transaction_id: &TxId,
+        nonce: &Nonce,
+        commit_block_height: BlockHeight,
+    ) -> io::Result<types::MessageProof> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::message::MessageProofReadySubscription::build(
+            schema::message::MessageProofReadyArgs {
+                transaction_id: (*transaction_id).into(),
+                nonce: (*nonce).into(),
+                commit_block_height: commit_block_height.into(),
+            },
+        );
+
+        let mut stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::message::MessageProofReadySubscription>| {
+                let proof: types::MessageProof = r?.message_proof_ready.into();
+                Result::<_, io::Error>::Ok(proof)
+            },
+        );
+
+        let proof = stream.next().await.ok_or(io::Error::new(
+            io::ErrorKind::Other,
+            "Failed to get the message proof",
+        ))??;
+
+        Ok(proof)
+    }
+
     /// Request a merkle proof of an output message.
     pub async fn message_proof(
         &self,
@@ -956,6 +1264,41 @@ impl FuelClient {
 
         Ok(proof)
     }
+
+    /// Request the merkle proofs of several output messages relatively to the same
+    /// commit block. The proofs are in the same order as the `messages`.
+    pub async fn message_proofs(
+        &self,
+        messages: &[(TxId, Nonce)],
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<Vec<Option<types::MessageProof>>> {
+        let messages = messages
+            .iter()
+            .map(|(transaction_id, nonce)| MessageProofInput {
+                transaction_id: (*transaction_id).into(),
+                nonce: (*nonce).into(),
+            })
+            .collect();
+        let commit_block_id: Option<schema::BlockId> =
+            commit_block_id.map(|commit_block_id| (*commit_block_id).into());
+        let commit_block_height = commit_block_height.map(Into::into);
+        let query = schema::message::MessageProofsQuery::build(MessageProofsArgs {
+            messages,
+            commit_block_id,
+            commit_block_height,
+        });
+
+        let proofs = self
+            .query(query)
+            .await?
+            .message_proofs
+            .into_iter()
+            .map(|proof| proof.map(Into::into))
+            .collect();
+
+        Ok(proofs)
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -26,11 +26,13 @@ use crate::client::pagination::{
 };
 pub use primitives::*;
 
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod health;
 pub mod message;
 pub mod node_info;
 pub mod primitives;
@@ -127,6 +129,64 @@ pub struct Memory {
     pub memory: String,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "IdArg"
+)]
+pub struct CallFrames {
+    #[arguments(id: $id)]
+    pub call_frames: Vec<CallFrame>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CallFrame {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub code_offset: U64,
+    pub code_size: U64,
+    pub param1: U64,
+    pub param2: U64,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct SessionStorageSlotArgs {
+    pub id: cynic::Id,
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "SessionStorageSlotArgs"
+)]
+pub struct SessionStorageSlot {
+    #[arguments(id: $id, contractId: $contract_id, key: $key)]
+    pub session_storage_slot: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct SessionBalanceArgs {
+    pub id: cynic::Id,
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "SessionBalanceArgs"
+)]
+pub struct SessionBalance {
+    #[arguments(id: $id, contractId: $contract_id, assetId: $asset_id)]
+    pub session_balance: U64,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct SetBreakpointArgs {
     pub id: cynic::Id,
@@ -144,6 +204,17 @@ pub struct SetBreakpoint {
     pub set_breakpoint: bool,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SetBreakpointArgs"
+)]
+pub struct RemoveBreakpoint {
+    #[arguments(id: $id, breakpoint: $bp)]
+    pub remove_breakpoint: bool,
+}
+
 #[derive(cynic::InputObject, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Breakpoint {
@@ -185,6 +256,42 @@ pub struct StartTx {
     pub start_tx: RunResult,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct StartTxFromChainArgs {
+    pub id: cynic::Id,
+    pub tx_id: TransactionId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "StartTxFromChainArgs"
+)]
+pub struct StartTxFromChain {
+    #[arguments(id: $id, txId: $tx_id)]
+    pub start_tx_from_chain: RunResult,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct StartPredicateArgs {
+    pub id: cynic::Id,
+    pub tx: String,
+    pub input_index: U32,
+    pub estimate: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "StartPredicateArgs"
+)]
+pub struct StartPredicate {
+    #[arguments(id: $id, txJson: $tx, inputIndex: $input_index, estimate: $estimate)]
+    pub start_predicate: RunResult,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct ContinueTxArgs {
     pub id: cynic::Id,
@@ -206,6 +313,7 @@ pub struct ContinueTx {
 pub struct RunResult {
     pub breakpoint: Option<OutputBreakpoint>,
     pub json_receipts: Vec<String>,
+    pub predicate_failure: Option<PredicateFailure>,
 }
 
 impl RunResult {
@@ -223,6 +331,7 @@ impl fmt::Display for RunResult {
         f.debug_struct("RunResult")
             .field("breakpoint", &self.breakpoint)
             .field("receipts", &receipts)
+            .field("predicate_failure", &self.predicate_failure)
             .finish()
     }
 }
@@ -234,6 +343,14 @@ pub struct OutputBreakpoint {
     pub pc: U64,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PredicateFailure {
+    pub input_index: U32,
+    pub reason: String,
+    pub pc: U64,
+}
+
 /// Generic graphql pagination query args
 #[derive(cynic::QueryVariables, Debug, Default)]
 pub struct ConnectionArgs {
@@ -0,0 +1,89 @@
+use crate::client::schema::{
+    schema,
+    AssetId,
+    Bytes32,
+    ConnectionArgs,
+    ContractId,
+    PageInfo,
+    U128,
+    U64,
+};
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetByIdArgs {
+    pub id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetByIdArgs"
+)]
+pub struct AssetByIdQuery {
+    #[arguments(id: $id)]
+    pub asset: Option<Asset>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Asset {
+    pub id: AssetId,
+    pub contract_id: Option<ContractId>,
+    pub sub_id: Option<Bytes32>,
+    pub total_minted: U128,
+    pub total_burned: U128,
+    pub total_supply: U128,
+    pub holders: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetEdge {
+    pub cursor: String,
+    pub node: Asset,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetConnection {
+    pub edges: Vec<AssetEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConnectionArgs"
+)]
+pub struct AssetsQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub assets: AssetConnection,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_by_id_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetByIdQuery::build(AssetByIdArgs {
+            id: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn assets_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetsQuery::build(ConnectionArgs {
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -1,12 +1,16 @@
-use crate::client::schema::{
-    schema,
-    BlockId,
-    ConnectionArgs,
-    PageInfo,
-    Signature,
-    Tai64Timestamp,
-    U32,
-    U64,
+use crate::client::{
+    schema::{
+        schema,
+        BlockId,
+        ConnectionArgs,
+        PageInfo,
+        Signature,
+        Tai64Timestamp,
+        U32,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
 };
 use fuel_core_types::fuel_crypto;
 
@@ -47,6 +51,22 @@ pub struct BlockByHeightQuery {
     pub block: Option<Block>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlockByTimeArgs {
+    pub time: Option<Tai64Timestamp>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockByTimeArgs"
+)]
+pub struct BlockByTimeQuery {
+    #[arguments(time: $time)]
+    pub block: Option<Block>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -58,6 +78,62 @@ pub struct BlocksQuery {
     pub blocks: BlockConnection,
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockFilterInput {
+    /// Filter blocks produced at or after the time
+    pub from_time: Option<Tai64Timestamp>,
+    /// Filter blocks produced at or before the time
+    pub to_time: Option<Tai64Timestamp>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksByTimeConnectionArgs {
+    /// Filter blocks based on the time range
+    pub filter: Option<BlockFilterInput>,
+    /// Skip until block height (forward pagination)
+    pub after: Option<String>,
+    /// Skip until block height (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n blocks in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n blocks in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(BlockFilterInput, PaginationRequest<String>)> for BlocksByTimeConnectionArgs {
+    fn from(r: (BlockFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => BlocksByTimeConnectionArgs {
+                filter: Some(r.0),
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => BlocksByTimeConnectionArgs {
+                filter: Some(r.0),
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlocksByTimeConnectionArgs"
+)]
+pub struct BlocksByTimeQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub blocks: BlockConnection,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BlockConnection {
@@ -181,6 +257,15 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn block_by_time_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlockByTimeQuery::build(BlockByTimeArgs {
+            time: Some(Tai64Timestamp(tai64::Tai64(0))),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn block_mutation_query_gql_output() {
         use cynic::MutationBuilder;
@@ -202,4 +287,20 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn blocks_by_time_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlocksByTimeQuery::build(BlocksByTimeConnectionArgs {
+            filter: Some(BlockFilterInput {
+                from_time: Some(Tai64Timestamp(tai64::Tai64(0))),
+                to_time: None,
+            }),
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
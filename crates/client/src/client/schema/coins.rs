@@ -149,6 +149,15 @@ pub struct SpendQueryElementInput {
     pub max: Option<U32>,
 }
 
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinSelectionStrategy {
+    LargestFirst,
+    RandomImprove,
+    SmallestFirst,
+    ExactMatch,
+}
+
 #[derive(cynic::QueryFragment, Debug, Clone)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct MessageCoin {
@@ -186,10 +195,19 @@ pub struct CoinsToSpendArgs {
     query_per_asset: Vec<SpendQueryElementInput>,
     /// A list of ids to exclude from the selection.
     excluded_ids: Option<ExcludeInput>,
+    /// Exclude the ids used by the pending transactions in the `TxPool`.
+    exclude_pending_inputs: Option<bool>,
+    /// The strategy used to select the coins.
+    strategy: Option<CoinSelectionStrategy>,
 }
 
-pub(crate) type CoinsToSpendArgsTuple =
-    (Address, Vec<SpendQueryElementInput>, Option<ExcludeInput>);
+pub(crate) type CoinsToSpendArgsTuple = (
+    Address,
+    Vec<SpendQueryElementInput>,
+    Option<ExcludeInput>,
+    Option<bool>,
+    Option<CoinSelectionStrategy>,
+);
 
 impl From<CoinsToSpendArgsTuple> for CoinsToSpendArgs {
     fn from(r: CoinsToSpendArgsTuple) -> Self {
@@ -197,6 +215,8 @@ impl From<CoinsToSpendArgsTuple> for CoinsToSpendArgs {
             owner: r.0,
             query_per_asset: r.1,
             excluded_ids: r.2,
+            exclude_pending_inputs: r.3,
+            strategy: r.4,
         }
     }
 }
@@ -208,7 +228,13 @@ impl From<CoinsToSpendArgsTuple> for CoinsToSpendArgs {
     variables = "CoinsToSpendArgs"
 )]
 pub struct CoinsToSpendQuery {
-    #[arguments(owner: $owner, queryPerAsset: $query_per_asset, excludedIds: $excluded_ids)]
+    #[arguments(
+        owner: $owner,
+        queryPerAsset: $query_per_asset,
+        excludedIds: $excluded_ids,
+        excludePendingInputs: $exclude_pending_inputs,
+        strategy: $strategy
+    )]
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
@@ -0,0 +1,148 @@
+use crate::client::schema::{
+    schema,
+    U32,
+    U64,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    services::health,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryReadiness {
+    pub readiness: Readiness,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Readiness {
+    pub relayer: Option<RelayerReadiness>,
+    pub network: Option<NetworkReadiness>,
+    pub sync: Option<SyncReadiness>,
+    pub importer: ImporterReadiness,
+    pub database_writable: bool,
+    pub services: Vec<ServiceReadiness>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RelayerReadiness {
+    pub synced_da_height: Option<U64>,
+    pub finalized_da_height: Option<U64>,
+    pub latest_da_finalized_height: Option<U64>,
+    pub max_lag: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct NetworkReadiness {
+    pub connected_peers: U32,
+    pub connected_reserved_peers: U32,
+    pub min_connected_reserved_peers: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SyncReadiness {
+    pub synced: bool,
+    pub synced_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ImporterReadiness {
+    pub latest_height: U32,
+    pub highest_peer_height: Option<U32>,
+    pub lag: U32,
+    pub max_lag: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ServiceReadiness {
+    pub name: String,
+    pub state: ServiceState,
+    pub error: Option<String>,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum ServiceState {
+    NotStarted,
+    Starting,
+    Started,
+    Stopping,
+    Stopped,
+    StoppedWithError,
+}
+
+impl From<Readiness> for health::ReadinessReport {
+    fn from(readiness: Readiness) -> Self {
+        Self {
+            relayer: readiness.relayer.map(|relayer| health::RelayerReadiness {
+                synced_da_height: relayer.synced_da_height.map(|h| DaBlockHeight(h.0)),
+                finalized_da_height: relayer
+                    .finalized_da_height
+                    .map(|h| DaBlockHeight(h.0)),
+                latest_da_finalized_height: relayer
+                    .latest_da_finalized_height
+                    .map(|h| DaBlockHeight(h.0)),
+                max_lag: relayer.max_lag.0,
+            }),
+            p2p: readiness.network.map(|network| health::P2pReadiness {
+                connected_peers: network.connected_peers.0,
+                connected_reserved_peers: network.connected_reserved_peers.0,
+                min_connected_reserved_peers: network.min_connected_reserved_peers.0,
+            }),
+            sync: readiness.sync.map(|sync| health::SyncReadiness {
+                synced: sync.synced,
+                synced_height: sync.synced_height.map(Into::into),
+            }),
+            importer: health::ImporterReadiness {
+                latest_height: readiness.importer.latest_height.into(),
+                highest_peer_height: readiness
+                    .importer
+                    .highest_peer_height
+                    .map(Into::into),
+                lag: readiness.importer.lag.0,
+                max_lag: readiness.importer.max_lag.0,
+            },
+            database_writable: readiness.database_writable,
+            services: readiness
+                .services
+                .into_iter()
+                .map(|service| health::ServiceReadiness {
+                    name: service.name,
+                    state: service.state.into(),
+                    error: service.error,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<ServiceState> for health::ServiceState {
+    fn from(state: ServiceState) -> Self {
+        match state {
+            ServiceState::NotStarted => health::ServiceState::NotStarted,
+            ServiceState::Starting => health::ServiceState::Starting,
+            ServiceState::Started => health::ServiceState::Started,
+            ServiceState::Stopping => health::ServiceState::Stopping,
+            ServiceState::Stopped => health::ServiceState::Stopped,
+            ServiceState::StoppedWithError => health::ServiceState::StoppedWithError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readiness_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryReadiness::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -117,6 +117,21 @@ pub struct MessageProofQuery {
     pub message_proof: Option<MessageProof>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MessageProofsArgs"
+)]
+pub struct MessageProofsQuery {
+    #[arguments(
+        messages: $messages,
+        commitBlockId: $commit_block_id,
+        commitBlockHeight: $commit_block_height
+    )]
+    pub message_proofs: Vec<Option<MessageProof>>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct MerkleProof {
@@ -169,6 +184,30 @@ pub struct MessageProofArgs {
     pub commit_block_height: Option<U32>,
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageProofInput {
+    /// Transaction id that contains the output message.
+    pub transaction_id: TransactionId,
+    /// The `Nonce` identifier of the output message.
+    pub nonce: Nonce,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessageProofsArgs {
+    /// The output messages that require a proof.
+    pub messages: Vec<MessageProofInput>,
+
+    /// The query supports either `commit_block_id`, or `commit_block_height` set on, not both.
+
+    /// The block id of the commitment block.
+    /// If it is `None`, the `commit_block_height` should be `Some`.
+    pub commit_block_id: Option<BlockId>,
+    /// The block height of the commitment block.
+    /// If it is `None`, the `commit_block_id` should be `Some`.
+    pub commit_block_height: Option<U32>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -186,6 +225,42 @@ pub struct MessageStatusArgs {
     pub nonce: Nonce,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "MessageStatusArgs"
+)]
+pub struct MessageStatusChangeSubscription {
+    #[arguments(nonce: $nonce)]
+    pub message_status_change: MessageStatus,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "MessageProofReadyArgs"
+)]
+pub struct MessageProofReadySubscription {
+    #[arguments(
+        transactionId: $transaction_id,
+        nonce: $nonce,
+        commitBlockHeight: $commit_block_height
+    )]
+    pub message_proof_ready: MessageProof,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessageProofReadyArgs {
+    /// Transaction id that contains the output message.
+    pub transaction_id: TransactionId,
+    /// The `Nonce` identifier of the output message that requires a proof.
+    pub nonce: Nonce,
+    /// The block height of the commitment block.
+    pub commit_block_height: U32,
+}
+
 impl From<(Option<Address>, PaginationRequest<String>)> for OwnedMessagesConnectionArgs {
     fn from(r: (Option<Address>, PaginationRequest<String>)) -> Self {
         match r.1.direction {
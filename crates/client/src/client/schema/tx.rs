@@ -1,15 +1,24 @@
 use super::block::BlockIdFragment;
 use crate::client::{
     schema::{
+        coins::Coin,
         schema,
         tx::transparent_receipt::Receipt,
         Address,
+        AssetId,
+        Bytes32,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
+        Nonce,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        UtxoId,
+        U32,
+        U64,
+        U8,
     },
     types::TransactionResponse,
     PageDirection,
@@ -19,8 +28,8 @@ use crate::client::{
 use fuel_core_types::{
     fuel_tx,
     fuel_types::{
+        self,
         canonical::Deserialize,
-        Bytes32,
     },
     fuel_vm,
 };
@@ -142,7 +151,7 @@ impl TryFrom<ProgramState> for fuel_vm::ProgramState {
                 u64::from_be_bytes(b)
             }),
             ReturnType::ReturnData => fuel_vm::ProgramState::ReturnData({
-                Bytes32::try_from(state.data.0 .0.as_slice())?
+                fuel_types::Bytes32::try_from(state.data.0 .0.as_slice())?
             }),
             ReturnType::Revert => fuel_vm::ProgramState::Revert({
                 let b = state.data.0 .0;
@@ -246,6 +255,153 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByContractConnectionArgs {
+    /// Select transactions that use the contract as an input
+    pub contract_id: ContractId,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for TransactionsByContractConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByContractConnectionArgs {
+                contract_id: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByContractConnectionArgs {
+                contract_id: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByContractConnectionArgs"
+)]
+pub struct TransactionsByContractQuery {
+    #[arguments(contractId: $contract_id, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_contract: TransactionConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByPredicateRootConnectionArgs {
+    /// Select transactions that spend from the predicate with the root
+    pub predicate_root: Address,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Address, PaginationRequest<String>)>
+    for TransactionsByPredicateRootConnectionArgs
+{
+    fn from(r: (Address, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByPredicateRootConnectionArgs {
+                predicate_root: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByPredicateRootConnectionArgs {
+                predicate_root: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByPredicateRootConnectionArgs"
+)]
+pub struct TransactionsByPredicateRootQuery {
+    #[arguments(predicateRoot: $predicate_root, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_predicate_root: TransactionConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByScriptHashConnectionArgs {
+    /// Select transactions with the script or the deployed bytecode of the hash
+    pub script_hash: Bytes32,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Bytes32, PaginationRequest<String>)>
+    for TransactionsByScriptHashConnectionArgs
+{
+    fn from(r: (Bytes32, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByScriptHashConnectionArgs {
+                script_hash: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByScriptHashConnectionArgs {
+                script_hash: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByScriptHashConnectionArgs"
+)]
+pub struct TransactionsByScriptHashQuery {
+    #[arguments(scriptHash: $script_hash, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_script_hash: TransactionConnection,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -277,8 +433,11 @@ pub struct EstimatePredicates {
 
 #[derive(cynic::QueryVariables)]
 pub struct DryRunArg {
-    pub tx: HexString,
+    pub txs: Vec<HexString>,
     pub utxo_validation: Option<bool>,
+    pub trace: Option<bool>,
+    pub state_diff: Option<bool>,
+    pub block_height: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -288,8 +447,128 @@ pub struct DryRunArg {
     variables = "DryRunArg"
 )]
 pub struct DryRun {
-    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
-    pub dry_run: Vec<Receipt>,
+    #[arguments(
+        txs: $txs,
+        utxoValidation: $utxo_validation,
+        trace: $trace,
+        stateDiff: $state_diff,
+        blockHeight: $block_height
+    )]
+    pub dry_run: Vec<DryRunTransactionExecutionStatus>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunTransactionExecutionStatus {
+    pub id: TransactionId,
+    pub status: DryRunTransactionStatus,
+    pub receipts: Vec<Receipt>,
+    pub trace: Option<TransactionTrace>,
+    pub state_diff: Option<StateDiff>,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum DryRunTransactionStatus {
+    DryRunSuccessStatus(DryRunSuccessStatus),
+    DryRunFailureStatus(DryRunFailureStatus),
+    #[cynic(fallback)]
+    Unknown,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunSuccessStatus {
+    pub program_state: Option<ProgramState>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunFailureStatus {
+    pub program_state: Option<ProgramState>,
+    pub reason: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionTrace {
+    pub steps: Vec<TraceStep>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TraceStep {
+    pub pc: U64,
+    pub instruction: U32,
+    pub opcode: Option<String>,
+    pub gas_used: U64,
+    pub changed_registers: Vec<RegisterChange>,
+    pub call_frames: Vec<TraceCallFrame>,
+    pub storage_access: Option<StorageAccess>,
+    pub panic_reason: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RegisterChange {
+    pub register: U8,
+    pub value: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TraceCallFrame {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub code_size: U64,
+    pub param1: U64,
+    pub param2: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageAccess {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    pub slots: U64,
+    pub kind: StorageAccessKind,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+    Clear,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateDiff {
+    pub coins_created: Vec<Coin>,
+    pub coins_spent: Vec<UtxoId>,
+    pub messages_consumed: Vec<Nonce>,
+    pub storage_changes: Vec<StorageSlotChange>,
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageSlotChange {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    pub previous: Option<Bytes32>,
+    pub current: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BalanceChange {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub previous: U64,
+    pub current: U64,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -370,13 +649,60 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transactions_by_contract_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TransactionsByContractQuery::build(TransactionsByContractConnectionArgs {
+                contract_id: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transactions_by_predicate_root_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionsByPredicateRootQuery::build(
+            TransactionsByPredicateRootConnectionArgs {
+                predicate_root: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            },
+        );
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transactions_by_script_hash_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionsByScriptHashQuery::build(
+            TransactionsByScriptHashConnectionArgs {
+                script_hash: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            },
+        );
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
         let tx = fuel_tx::Transaction::default_test_tx();
         let query = DryRun::build(DryRunArg {
-            tx: HexString(Bytes(tx.to_bytes())),
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
             utxo_validation: None,
+            trace: None,
+            state_diff: None,
+            block_height: None,
         });
         insta::assert_snapshot!(query.query)
     }
@@ -1,13 +1,16 @@
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain_info;
 pub mod coins;
 pub mod contract;
+pub mod dry_run;
 pub mod gas_costs;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
 
+pub use asset::Asset;
 pub use balance::Balance;
 pub use block::{
     Block,
@@ -16,13 +19,28 @@ pub use block::{
 pub use chain_info::ChainInfo;
 pub use coins::{
     Coin,
+    CoinSelectionStrategy,
     CoinType,
+    CoinsToSpendOptions,
     MessageCoin,
 };
 pub use contract::{
     Contract,
     ContractBalance,
 };
+pub use dry_run::{
+    BalanceChange,
+    DryRunOptions,
+    DryRunTransactionExecutionStatus,
+    DryRunTransactionStatus,
+    StateDiff,
+    StorageAccess,
+    StorageAccessKind,
+    StorageSlotChange,
+    TraceCallFrame,
+    TraceStep,
+    TransactionTrace,
+};
 pub use gas_costs::{
     DependentCost,
     GasCosts,
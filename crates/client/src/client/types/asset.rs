@@ -0,0 +1,47 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+    PaginatedResult,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    pub id: AssetId,
+    pub contract_id: Option<ContractId>,
+    pub sub_id: Option<Bytes32>,
+    pub total_minted: u128,
+    pub total_burned: u128,
+    pub total_supply: u128,
+    pub holders: u64,
+}
+
+// GraphQL Translation
+
+impl From<schema::asset::Asset> for Asset {
+    fn from(value: schema::asset::Asset) -> Self {
+        Self {
+            id: value.id.into(),
+            contract_id: value.contract_id.map(Into::into),
+            sub_id: value.sub_id.map(Into::into),
+            total_minted: value.total_minted.into(),
+            total_burned: value.total_burned.into(),
+            total_supply: value.total_supply.into(),
+            holders: value.holders.into(),
+        }
+    }
+}
+
+impl From<schema::asset::AssetConnection> for PaginatedResult<Asset, String> {
+    fn from(conn: schema::asset::AssetConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
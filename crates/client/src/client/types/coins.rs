@@ -26,7 +26,41 @@ impl CoinType {
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// The strategy used by the node to select the coins to spend.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CoinSelectionStrategy {
+    /// Uses the biggest coins to reach the target.
+    LargestFirst,
+    /// Uses random coins, preferring the selection with the change close to the target.
+    #[default]
+    RandomImprove,
+    /// Uses as many of the smallest coins as allowed by `max` to consolidate the dust.
+    SmallestFirst,
+    /// Uses the coins that add up exactly to the target to avoid the change output.
+    ExactMatch,
+}
+
+/// The options to override the node behavior during the selection of the coins to spend.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CoinsToSpendOptions {
+    /// Exclude the coins and messages used by the pending transactions in the `TxPool`.
+    pub exclude_pending_inputs: Option<bool>,
+    /// The strategy used to select the coins, `RandomImprove` by default.
+    pub strategy: Option<CoinSelectionStrategy>,
+}
+
+impl From<CoinSelectionStrategy> for schema::coins::CoinSelectionStrategy {
+    fn from(value: CoinSelectionStrategy) -> Self {
+        match value {
+            CoinSelectionStrategy::LargestFirst => Self::LargestFirst,
+            CoinSelectionStrategy::RandomImprove => Self::RandomImprove,
+            CoinSelectionStrategy::SmallestFirst => Self::SmallestFirst,
+            CoinSelectionStrategy::ExactMatch => Self::ExactMatch,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Coin {
     pub amount: u64,
     pub block_created: u32,
@@ -0,0 +1,259 @@
+use crate::client::{
+    schema,
+    schema::ConversionError,
+    types::{
+        primitives::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            Nonce,
+            TransactionId,
+            UtxoId,
+        },
+        Coin,
+    },
+};
+use fuel_core_types::{
+    fuel_asm::RawInstruction,
+    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
+    fuel_vm::ProgramState,
+};
+
+/// The options to override the node behavior during the dry run.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DryRunOptions {
+    /// Disable utxo input checks (exists, unspent, and valid signature).
+    pub utxo_validation: Option<bool>,
+    /// Collect the per-instruction trace of the execution.
+    pub trace: Option<bool>,
+    /// Collect the changes of the state made by each transaction.
+    pub state_diff: Option<bool>,
+    /// Execute transactions at the height of the block, by default on top of the latest one.
+    pub block_height: Option<BlockHeight>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DryRunTransactionExecutionStatus {
+    pub id: TransactionId,
+    pub status: DryRunTransactionStatus,
+    pub receipts: Vec<Receipt>,
+    pub trace: Option<TransactionTrace>,
+    pub state_diff: Option<StateDiff>,
+}
+
+#[derive(Debug, Clone)]
+pub enum DryRunTransactionStatus {
+    Success {
+        program_state: Option<ProgramState>,
+    },
+    Failure {
+        reason: String,
+        program_state: Option<ProgramState>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionTrace {
+    pub steps: Vec<TraceStep>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    pub pc: u64,
+    pub instruction: RawInstruction,
+    pub opcode: Option<String>,
+    pub gas_used: u64,
+    /// The registers modified by the instruction with their new values.
+    pub changed_registers: Vec<(u8, u64)>,
+    /// The contract call frames, starting from the outermost.
+    pub call_frames: Vec<TraceCallFrame>,
+    pub storage_access: Option<StorageAccess>,
+    pub panic_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceCallFrame {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub code_size: u64,
+    pub param1: u64,
+    pub param2: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageAccess {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    pub slots: u64,
+    pub kind: StorageAccessKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+    Clear,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateDiff {
+    pub coins_created: Vec<Coin>,
+    pub coins_spent: Vec<UtxoId>,
+    pub messages_consumed: Vec<Nonce>,
+    pub storage_changes: Vec<StorageSlotChange>,
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageSlotChange {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    /// The value before the execution, `None` if the slot was unset.
+    pub previous: Option<Bytes32>,
+    /// The value after the execution, `None` if the slot was cleared.
+    pub current: Option<Bytes32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceChange {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub previous: u64,
+    pub current: u64,
+}
+
+// GraphQL Translation
+
+impl TryFrom<schema::tx::DryRunTransactionExecutionStatus>
+    for DryRunTransactionExecutionStatus
+{
+    type Error = ConversionError;
+
+    fn try_from(
+        value: schema::tx::DryRunTransactionExecutionStatus,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id.into(),
+            status: value.status.try_into()?,
+            receipts: value
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            trace: value.trace.map(Into::into),
+            state_diff: value.state_diff.map(Into::into),
+        })
+    }
+}
+
+impl TryFrom<schema::tx::DryRunTransactionStatus> for DryRunTransactionStatus {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::tx::DryRunTransactionStatus) -> Result<Self, Self::Error> {
+        Ok(match value {
+            schema::tx::DryRunTransactionStatus::DryRunSuccessStatus(s) => {
+                DryRunTransactionStatus::Success {
+                    program_state: s.program_state.map(TryInto::try_into).transpose()?,
+                }
+            }
+            schema::tx::DryRunTransactionStatus::DryRunFailureStatus(s) => {
+                DryRunTransactionStatus::Failure {
+                    reason: s.reason,
+                    program_state: s.program_state.map(TryInto::try_into).transpose()?,
+                }
+            }
+            schema::tx::DryRunTransactionStatus::Unknown => {
+                return Err(Self::Error::UnknownVariant("DryRunTransactionStatus"))
+            }
+        })
+    }
+}
+
+impl From<schema::tx::TransactionTrace> for TransactionTrace {
+    fn from(value: schema::tx::TransactionTrace) -> Self {
+        Self {
+            steps: value.steps.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::tx::TraceStep> for TraceStep {
+    fn from(value: schema::tx::TraceStep) -> Self {
+        Self {
+            pc: value.pc.into(),
+            instruction: value.instruction.into(),
+            opcode: value.opcode,
+            gas_used: value.gas_used.into(),
+            changed_registers: value
+                .changed_registers
+                .into_iter()
+                .map(|change| (change.register.into(), change.value.into()))
+                .collect(),
+            call_frames: value.call_frames.into_iter().map(Into::into).collect(),
+            storage_access: value.storage_access.map(Into::into),
+            panic_reason: value.panic_reason,
+        }
+    }
+}
+
+impl From<schema::tx::TraceCallFrame> for TraceCallFrame {
+    fn from(value: schema::tx::TraceCallFrame) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            asset_id: value.asset_id.into(),
+            code_size: value.code_size.into(),
+            param1: value.param1.into(),
+            param2: value.param2.into(),
+        }
+    }
+}
+
+impl From<schema::tx::StorageAccess> for StorageAccess {
+    fn from(value: schema::tx::StorageAccess) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            key: value.key.into(),
+            slots: value.slots.into(),
+            kind: match value.kind {
+                schema::tx::StorageAccessKind::Read => StorageAccessKind::Read,
+                schema::tx::StorageAccessKind::Write => StorageAccessKind::Write,
+                schema::tx::StorageAccessKind::Clear => StorageAccessKind::Clear,
+            },
+        }
+    }
+}
+
+impl From<schema::tx::StateDiff> for StateDiff {
+    fn from(value: schema::tx::StateDiff) -> Self {
+        Self {
+            coins_created: value.coins_created.into_iter().map(Into::into).collect(),
+            coins_spent: value.coins_spent.into_iter().map(Into::into).collect(),
+            messages_consumed: value
+                .messages_consumed
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            storage_changes: value
+                .storage_changes
+                .into_iter()
+                .map(|change| StorageSlotChange {
+                    contract_id: change.contract_id.into(),
+                    key: change.key.into(),
+                    previous: change.previous.map(Into::into),
+                    current: change.current.map(Into::into),
+                })
+                .collect(),
+            balance_changes: value
+                .balance_changes
+                .into_iter()
+                .map(|change| BalanceChange {
+                    contract_id: change.contract_id.into(),
+                    asset_id: change.asset_id.into(),
+                    previous: change.previous.into(),
+                    current: change.current.into(),
+                })
+                .collect(),
+        }
+    }
+}
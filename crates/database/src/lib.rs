@@ -37,6 +37,23 @@ pub enum Error {
         /// the database version expected by this build of fuel-core
         expected: u32,
     },
+    /// The database was created by a newer version of the `fuel-core`.
+    #[display(
+        fmt = "The database version {found:#x} is newer than the supported {supported:#x}, \
+               the `fuel-core` should be upgraded"
+    )]
+    NewerDatabaseVersion {
+        /// the current database version
+        found: u32,
+        /// the latest database version supported by this build of fuel-core
+        supported: u32,
+    },
+    /// There is no migration step to upgrade the database to the version.
+    #[display(fmt = "No migration of the database to the version {version:#x}")]
+    MissingMigration {
+        /// the version without the migration step
+        version: u32,
+    },
 
     /// Not related to database error.
     #[from]
@@ -1,5 +1,8 @@
 use crate::{
-    fuel_core_graphql_api::database::ReadView,
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::TxPoolPort,
+    },
     query::asset_query::{
         AssetQuery,
         AssetSpendTarget,
@@ -7,7 +10,10 @@ use crate::{
     },
 };
 use core::mem::swap;
-use fuel_core_storage::Error as StorageError;
+use fuel_core_storage::{
+    iter::IterDirection,
+    Error as StorageError,
+};
 use fuel_core_types::{
     entities::coins::{
         CoinId,
@@ -21,9 +27,9 @@ use fuel_core_types::{
 };
 use itertools::Itertools;
 use rand::prelude::*;
-use std::{
-    cmp::Reverse,
-    collections::HashSet,
+use std::collections::{
+    HashSet,
+    VecDeque,
 };
 use thiserror::Error;
 
@@ -55,6 +61,8 @@ pub struct SpendQuery {
     query_per_asset: Vec<AssetSpendTarget>,
     exclude: Exclude,
     base_asset_id: AssetId,
+    /// `true` if the coins are read from the index by amount.
+    by_amount_index: bool,
 }
 
 impl SpendQuery {
@@ -86,6 +94,7 @@ impl SpendQuery {
             query_per_asset: query_per_asset.into(),
             exclude,
             base_asset_id,
+            by_amount_index: true,
         })
     }
 
@@ -99,17 +108,34 @@ impl SpendQuery {
         self.query_per_asset
             .iter()
             .map(|asset| {
-                AssetQuery::new(
+                let query = AssetQuery::new(
                     &self.owner,
                     asset,
                     &self.base_asset_id,
                     Some(&self.exclude),
                     db,
-                )
+                );
+                if self.by_amount_index {
+                    query
+                } else {
+                    query.without_by_amount_index()
+                }
             })
             .collect()
     }
 
+    /// Selects the coins without the index by amount, if the index is disabled
+    /// on the node or is not built yet.
+    pub fn without_by_amount_index(&mut self) {
+        self.by_amount_index = false;
+    }
+
+    /// Excludes the coins and messages used as inputs by the pending transactions
+    /// in the `TxPool`, so the selected coins don't collide with them.
+    pub fn exclude_pending_inputs(&mut self, txpool: &dyn TxPoolPort) {
+        self.exclude.extend(txpool.spent_inputs());
+    }
+
     /// Returns exclude that contains information about excluded ids.
     pub fn exclude(&self) -> &Exclude {
         &self.exclude
@@ -125,8 +151,8 @@ impl SpendQuery {
 /// number of inputs for each asset can't exceed `max_inputs`, otherwise throw an error that query
 /// can't be satisfied.
 pub fn largest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryError> {
-    let mut inputs: Vec<_> = query.coins().try_collect()?;
-    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+    // The coins are read from the index from the largest one until the target is reached.
+    let inputs = query.coins_by_amount(IterDirection::Reverse)?;
 
     let mut collected_amount = 0u64;
     let mut coins = vec![];
@@ -143,6 +169,7 @@ pub fn largest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryErro
         }
 
         // Add to list
+        let coin = coin?;
         collected_amount = collected_amount.saturating_add(coin.amount());
         coins.push(coin);
     }
@@ -162,54 +189,254 @@ pub fn random_improve(
     db: &ReadView,
     spend_query: &SpendQuery,
 ) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
-    let mut coins_per_asset = vec![];
+    select_coins(db, spend_query, CoinSelectionStrategy::RandomImprove)
+}
+
+/// The strategy used to select the coins of each asset of the [`SpendQuery`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CoinSelectionStrategy {
+    /// Uses the biggest coins to reach the target, see [`largest_first`].
+    LargestFirst,
+    /// Uses random coins and improves the change amount, see [`random_improve`].
+    #[default]
+    RandomImprove,
+    /// Consolidates the dust by using as many of the smallest coins as allowed
+    /// by `max`, see [`smallest_first`].
+    SmallestFirst,
+    /// Looks for coins that add up exactly to the target to avoid the change output,
+    /// see [`exact_match`].
+    ExactMatch,
+}
+
+/// Selects the coins for each asset of the `spend_query` with the `strategy`.
+pub fn select_coins(
+    db: &ReadView,
+    spend_query: &SpendQuery,
+    strategy: CoinSelectionStrategy,
+) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+    spend_query
+        .asset_queries(db)
+        .iter()
+        .map(|query| match strategy {
+            CoinSelectionStrategy::LargestFirst => largest_first(query),
+            CoinSelectionStrategy::RandomImprove => random_improve_asset(query),
+            CoinSelectionStrategy::SmallestFirst => smallest_first(query),
+            CoinSelectionStrategy::ExactMatch => exact_match(query),
+        })
+        .collect()
+}
 
-    for query in spend_query.asset_queries(db) {
-        let mut inputs: Vec<_> = query.coins().try_collect()?;
-        inputs.shuffle(&mut thread_rng());
-        inputs.truncate(query.asset.max);
+fn random_improve_asset(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryError> {
+    // Only `max` random coins can be used, so only their ids are kept in memory,
+    // and only the selected coins are loaded.
+    // The sampling doesn't preallocate `max` ids because it is `usize::MAX` by default.
+    let mut rng = thread_rng();
+    let max = query.asset.max;
+    let mut ids = vec![];
+    for (index, id) in query.coin_ids_by_amount(IterDirection::Forward)?.enumerate() {
+        let id = id?;
+        if ids.len() < max {
+            ids.push(id);
+        } else {
+            let replaced = rng.gen_range(0..=index);
+            if let Some(slot) = ids.get_mut(replaced) {
+                *slot = id;
+            }
+        }
+    }
+    // The order of the sampled ids is not random
+    ids.shuffle(&mut rng);
+    let inputs: Vec<_> = ids
+        .iter()
+        .filter_map(|id| query.coin(id).transpose())
+        .try_collect()?;
+
+    let mut collected_amount = 0;
+    let mut coins = vec![];
 
-        let mut collected_amount = 0;
-        let mut coins = vec![];
+    // Set parameters according to spec
+    let target = query.asset.target;
+    let upper_target = query.asset.target.saturating_mul(2);
 
-        // Set parameters according to spec
-        let target = query.asset.target;
-        let upper_target = query.asset.target.saturating_mul(2);
+    for coin in inputs {
+        // Try to improve the result by adding dust to the result.
+        if collected_amount >= target {
+            // Break if found coin exceeds max `u64` or the upper limit
+            if collected_amount == u64::MAX || coin.amount() > upper_target {
+                break
+            }
 
-        for coin in inputs {
-            // Try to improve the result by adding dust to the result.
-            if collected_amount >= target {
-                // Break if found coin exceeds max `u64` or the upper limit
-                if collected_amount == u64::MAX || coin.amount() > upper_target {
-                    break
-                }
+            // Break if adding doesn't improve the distance
+            let change_amount = collected_amount
+                .checked_sub(target)
+                .expect("We checked it above");
+            let distance = target.abs_diff(change_amount);
+            let next_distance =
+                target.abs_diff(change_amount.saturating_add(coin.amount()));
+            if next_distance >= distance {
+                break
+            }
+        }
 
-                // Break if adding doesn't improve the distance
-                let change_amount = collected_amount
-                    .checked_sub(target)
-                    .expect("We checked it above");
-                let distance = target.abs_diff(change_amount);
-                let next_distance =
-                    target.abs_diff(change_amount.saturating_add(coin.amount()));
-                if next_distance >= distance {
-                    break
-                }
+        // Add to list
+        collected_amount = collected_amount.saturating_add(coin.amount());
+        coins.push(coin);
+    }
+
+    // Fallback to largest_first if we can't fit more coins
+    if collected_amount < query.asset.target {
+        swap(&mut coins, &mut largest_first(query)?);
+    }
+
+    Ok(coins)
+}
+
+/// Returns the smallest inputs of the `owner` to satisfy the required `target` of the asset.
+/// After the target is reached, the next smallest inputs are added until there are `max_inputs`
+/// of them, so the dust of the `owner` is consolidated into the change output.
+///
+/// If the `max_inputs` smallest inputs can't satisfy the `target`, the smallest inputs are
+/// replaced by the bigger ones until the `target` is reached.
+pub fn smallest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let inputs = query.coins_by_amount(IterDirection::Forward)?;
+
+    let target = u128::from(query.asset.target);
+    let max = query.asset.max;
+    // The sum of `max` coins can exceed `u64::MAX`, and the amount of the replaced coins
+    // is subtracted, so the amount is accumulated in `u128` to stay exact.
+    let mut collected_amount = 0u128;
+    let mut coins = VecDeque::<CoinType>::new();
+    let mut max_reached = false;
+
+    for coin in inputs {
+        if coins.len() >= max {
+            max_reached = true;
+            // Break if we don't need to replace the smallest coin
+            if collected_amount >= target {
+                break
             }
 
-            // Add to list
-            collected_amount = collected_amount.saturating_add(coin.amount());
-            coins.push(coin);
+            let Some(smallest) = coins.pop_front() else {
+                break
+            };
+            collected_amount =
+                collected_amount.saturating_sub(u128::from(smallest.amount()));
         }
 
-        // Fallback to largest_first if we can't fit more coins
-        if collected_amount < query.asset.target {
-            swap(&mut coins, &mut largest_first(&query)?);
+        let coin = coin?;
+        collected_amount = collected_amount.saturating_add(u128::from(coin.amount()));
+        coins.push_back(coin);
+    }
+
+    if collected_amount < target {
+        if max_reached {
+            return Err(CoinsQueryError::MaxCoinsReached)
         }
 
-        coins_per_asset.push(coins);
+        return Err(CoinsQueryError::InsufficientCoins {
+            asset_id: query.asset.id,
+            collected_amount: collected_amount.try_into().unwrap_or(u64::MAX),
+        })
     }
 
-    Ok(coins_per_asset)
+    Ok(coins.into())
+}
+
+/// The maximum number of the biggest coins not exceeding the `target` that are
+/// considered by the [`exact_match`].
+const EXACT_MATCH_MAX_CANDIDATES: usize = 256;
+/// The maximum number of steps of the [`exact_match`] search.
+const EXACT_MATCH_MAX_TRIES: usize = 100_000;
+
+/// Returns the inputs of the `owner` that add up exactly to the `target` of the asset, so the
+/// transaction doesn't need a change output. The inputs are searched with the branch and bound
+/// algorithm among the biggest coins that don't exceed the `target`.
+///
+/// If no exact match is found within the limits, falls back to the [`random_improve`].
+pub fn exact_match(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let target = query.asset.target;
+    let candidates: Vec<_> = query
+        .coins_by_amount(IterDirection::Reverse)?
+        .filter_ok(|coin| coin.amount() <= target && coin.amount() > 0)
+        .take(EXACT_MATCH_MAX_CANDIDATES)
+        .try_collect()?;
+
+    // `remaining[i]` is the total amount of the candidates starting from `i`.
+    let mut remaining = vec![0u64; candidates.len().saturating_add(1)];
+    for (i, coin) in candidates.iter().enumerate().rev() {
+        remaining[i] = remaining[i.saturating_add(1)].saturating_add(coin.amount());
+    }
+
+    let mut search = ExactMatchSearch {
+        candidates: &candidates,
+        remaining: &remaining,
+        target,
+        max: query.asset.max,
+        selected: vec![],
+        tries: 0,
+    };
+
+    if search.find(0, 0) {
+        let selected = search.selected;
+        let coins = candidates
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| selected.contains(i))
+            .map(|(_, coin)| coin)
+            .collect();
+        return Ok(coins)
+    }
+
+    random_improve_asset(query)
+}
+
+/// The depth-first search of the candidates that add up exactly to the `target`.
+struct ExactMatchSearch<'a> {
+    /// The candidates sorted by the amount in descending order.
+    candidates: &'a [CoinType],
+    remaining: &'a [u64],
+    target: u64,
+    max: usize,
+    /// The indexes of the selected candidates.
+    selected: Vec<usize>,
+    tries: usize,
+}
+
+impl ExactMatchSearch<'_> {
+    /// Returns `true` if the `selected` candidates add up to the `target`.
+    fn find(&mut self, index: usize, collected_amount: u64) -> bool {
+        if collected_amount == self.target {
+            return true
+        }
+
+        self.tries = self.tries.saturating_add(1);
+        if self.tries > EXACT_MATCH_MAX_TRIES
+            || self.selected.len() >= self.max
+            || index >= self.candidates.len()
+            || collected_amount.saturating_add(self.remaining[index]) < self.target
+        {
+            return false
+        }
+
+        let amount = self.candidates[index].amount();
+        if let Some(next_amount) = collected_amount.checked_add(amount) {
+            if next_amount <= self.target {
+                self.selected.push(index);
+                if self.find(index.saturating_add(1), next_amount) {
+                    return true
+                }
+                self.selected.pop();
+            }
+        }
+
+        // Skip the candidates with the same amount, because excluding this candidate and
+        // including the next one with the same amount was already tried above.
+        let next = self.candidates[index..]
+            .iter()
+            .position(|coin| coin.amount() != amount)
+            .map_or(self.candidates.len(), |offset| index.saturating_add(offset));
+        self.find(next, collected_amount)
+    }
 }
 
 impl From<StorageError> for CoinsQueryError {
@@ -225,6 +452,8 @@ mod tests {
         coins_query::{
             largest_first,
             random_improve,
+            select_coins,
+            CoinSelectionStrategy,
             CoinsQueryError,
             SpendQuery,
         },
@@ -478,6 +707,28 @@ mod tests {
             let (owner, asset_ids, base_asset_id, db) = setup_coins_and_messages();
             multiple_assets_helper(owner, &asset_ids, &base_asset_id, db);
         }
+
+        #[test]
+        fn spent_coins_are_skipped_until_index_is_updated() {
+            // Setup coins
+            let (owner, asset_ids, base_asset_id, mut db) = setup_coins();
+
+            // Spend the largest coin without updating the index by amount
+            let largest = db
+                .owned_coins(&owner)
+                .into_iter()
+                .find(|coin| coin.asset_id == asset_ids[0] && coin.amount == 5)
+                .unwrap();
+            StorageMutate::<Coins>::remove(&mut db.database, &largest.utxo_id).unwrap();
+
+            let coins = query(
+                &[AssetSpendTarget::new(asset_ids[0], 5, usize::MAX)],
+                &owner,
+                &base_asset_id,
+                &db.service_database(),
+            );
+            assert_matches!(coins, Ok(coins) if coins == vec![vec![(asset_ids[0], 4), (asset_ids[0], 3)]]);
+        }
     }
 
     mod random_improve {
@@ -667,6 +918,109 @@ mod tests {
         }
     }
 
+    mod strategies {
+        use super::*;
+
+        fn select(
+            strategy: CoinSelectionStrategy,
+            target: Word,
+            max: usize,
+        ) -> Result<Vec<Word>, CoinsQueryError> {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let spend_query = SpendQuery::new(
+                owner,
+                &[AssetSpendTarget::new(asset_ids[0], target, max)],
+                None,
+                base_asset_id,
+            )?;
+            let coins =
+                select_coins(&db.service_database().view(), &spend_query, strategy)?;
+
+            assert_eq!(coins.len(), 1);
+            Ok(coins[0].iter().map(|coin| coin.amount()).sorted().collect())
+        }
+
+        #[test]
+        fn smallest_first_consolidates_dust() {
+            use CoinSelectionStrategy::SmallestFirst;
+
+            assert_eq!(select(SmallestFirst, 0, 0), Ok(vec![]));
+            assert_eq!(select(SmallestFirst, 3, 2), Ok(vec![1, 2]));
+            assert_eq!(
+                select(SmallestFirst, 3, usize::MAX),
+                Ok(vec![1, 2, 3, 4, 5])
+            );
+            // The smallest coins are replaced to reach the target
+            assert_eq!(select(SmallestFirst, 8, 2), Ok(vec![4, 5]));
+            assert_eq!(
+                select(SmallestFirst, 10, 2),
+                Err(CoinsQueryError::MaxCoinsReached)
+            );
+            assert_eq!(
+                select(SmallestFirst, 16, usize::MAX),
+                Err(CoinsQueryError::InsufficientCoins {
+                    asset_id: setup_coins().1[0],
+                    collected_amount: 15,
+                })
+            );
+        }
+
+        #[test]
+        fn exact_match_avoids_change() {
+            use CoinSelectionStrategy::ExactMatch;
+
+            assert_eq!(select(ExactMatch, 0, usize::MAX), Ok(vec![]));
+            assert_eq!(select(ExactMatch, 7, usize::MAX), Ok(vec![2, 5]));
+            assert_eq!(select(ExactMatch, 12, 3), Ok(vec![3, 4, 5]));
+            assert_eq!(select(ExactMatch, 15, usize::MAX), Ok(vec![1, 2, 3, 4, 5]));
+            // Falls back to `random_improve` without the exact match
+            assert_eq!(
+                select(ExactMatch, 12, 2),
+                Err(CoinsQueryError::MaxCoinsReached)
+            );
+            assert_eq!(
+                select(ExactMatch, 16, usize::MAX),
+                Err(CoinsQueryError::InsufficientCoins {
+                    asset_id: setup_coins().1[0],
+                    collected_amount: 15,
+                })
+            );
+        }
+
+        #[test]
+        fn largest_first_strategy() {
+            use CoinSelectionStrategy::LargestFirst;
+
+            assert_eq!(select(LargestFirst, 6, usize::MAX), Ok(vec![4, 5]));
+        }
+
+        #[test]
+        fn strategies_work_without_index() {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let mut spend_query = SpendQuery::new(
+                owner,
+                &[AssetSpendTarget::new(asset_ids[0], 7, usize::MAX)],
+                None,
+                base_asset_id,
+            )
+            .unwrap();
+            spend_query.without_by_amount_index();
+
+            for strategy in [
+                CoinSelectionStrategy::LargestFirst,
+                CoinSelectionStrategy::SmallestFirst,
+                CoinSelectionStrategy::ExactMatch,
+            ] {
+                let coins =
+                    select_coins(&db.service_database().view(), &spend_query, strategy)
+                        .unwrap();
+                let amounts: Vec<_> =
+                    coins[0].iter().map(|coin| coin.amount()).sorted().collect();
+                assert_eq!(Ok(amounts), select(strategy, 7, usize::MAX));
+            }
+        }
+    }
+
     mod exclusion {
         use super::*;
         use fuel_core_types::entities::coins::CoinId;
@@ -960,6 +1314,8 @@ mod tests {
 
             let db = &mut self.database;
             StorageMutate::<Coins>::insert(db, &id, &coin).unwrap();
+            db.record_owned_coin_amount(&owner, &asset_id, amount, &id)
+                .unwrap();
 
             coin.uncompress(id)
         }
@@ -1,5 +1,8 @@
 use crate::{
-    database::transaction::DatabaseTransaction,
+    database::{
+        history::StateRewindPolicy,
+        transaction::DatabaseTransaction,
+    },
     state::{
         in_memory::memory_store::MemoryStore,
         DataSource,
@@ -42,6 +45,7 @@ use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     fuel_types::BlockHeight,
 };
+use parking_lot::RwLock;
 use std::{
     fmt::{
         self,
@@ -59,7 +63,10 @@ type DatabaseResult<T> = Result<T>;
 
 // TODO: Extract `Database` and all belongs into `fuel-core-database`.
 #[cfg(feature = "rocksdb")]
-use crate::state::rocks_db::RocksDb;
+use crate::state::rocks_db::{
+    RocksDb,
+    METRICS_EXPORT_INTERVAL,
+};
 #[cfg(feature = "rocksdb")]
 use std::path::Path;
 #[cfg(feature = "rocksdb")]
@@ -74,8 +81,12 @@ mod state;
 
 pub(crate) mod coin;
 
+pub mod assets;
 pub mod balances;
+pub mod history;
+pub mod indexes;
 pub mod metadata;
+pub mod migration;
 pub mod storage;
 pub mod transaction;
 pub mod transactions;
@@ -85,6 +96,11 @@ pub type Column = fuel_core_storage::column::Column;
 #[derive(Clone, Debug)]
 pub struct Database {
     data: StructuredStorage<DataSource>,
+    /// Defines whether the history of the state modifications is recorded on import.
+    state_rewind_policy: StateRewindPolicy,
+    /// Held for writing while a transaction is committed, so the readers that need
+    /// a consistent state, like the historical views, don't observe partial commits.
+    commit_lock: Arc<RwLock<()>>,
     // used for RAII
     _drop: Arc<DropResources>,
 }
@@ -125,6 +141,8 @@ impl Database {
     {
         Self {
             data: StructuredStorage::new(data_source.into()),
+            state_rewind_policy: Default::default(),
+            commit_lock: Default::default(),
             _drop: Default::default(),
         }
     }
@@ -134,13 +152,37 @@ impl Database {
         self
     }
 
+    /// Sets the policy of recording the history of the state modifications.
+    pub fn with_state_rewind_policy(mut self, policy: StateRewindPolicy) -> Self {
+        self.state_rewind_policy = policy;
+        self
+    }
+
+    /// Returns the policy of recording the history of the state modifications.
+    pub fn state_rewind_policy(&self) -> StateRewindPolicy {
+        self.state_rewind_policy
+    }
+
+    /// Opens the RocksDB database at the `path`. If the `metrics` are enabled,
+    /// the internal statistics of the RocksDB are exported in the background.
     #[cfg(feature = "rocksdb")]
-    pub fn open(path: &Path, capacity: impl Into<Option<usize>>) -> DatabaseResult<Self> {
+    pub fn open(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+        metrics: bool,
+    ) -> DatabaseResult<Self> {
         use anyhow::Context;
-        let db = RocksDb::default_open(path, capacity.into()).map_err(Into::<anyhow::Error>::into).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
+        let columns = enum_iterator::all::<Column>().collect::<Vec<_>>();
+        let db = RocksDb::open(path, columns, capacity.into(), metrics).map_err(Into::<anyhow::Error>::into).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
+        let db = Arc::new(db);
+        if metrics {
+            RocksDb::spawn_metrics_exporter(&db, METRICS_EXPORT_INTERVAL)?;
+        }
 
         Ok(Database {
-            data: StructuredStorage::new(Arc::new(db).into()),
+            data: StructuredStorage::new(db.into()),
+            state_rewind_policy: Default::default(),
+            commit_lock: Default::default(),
             _drop: Default::default(),
         })
     }
@@ -148,6 +190,8 @@ impl Database {
     pub fn in_memory() -> Self {
         Self {
             data: StructuredStorage::new(Arc::new(MemoryStore::default()).into()),
+            state_rewind_policy: Default::default(),
+            commit_lock: Default::default(),
             _drop: Default::default(),
         }
     }
@@ -158,6 +202,8 @@ impl Database {
         let db = RocksDb::default_open(tmp_dir.path(), None).unwrap();
         Self {
             data: StructuredStorage::new(Arc::new(db).into()),
+            state_rewind_policy: Default::default(),
+            commit_lock: Default::default(),
             _drop: Arc::new(
                 {
                     move || {
@@ -181,6 +227,11 @@ impl Database {
     pub fn flush(self) -> DatabaseResult<()> {
         self.data.as_ref().flush()
     }
+
+    /// Verifies that the database accepts writes without writing anything.
+    pub fn check_writable(&self) -> DatabaseResult<()> {
+        self.data.as_ref().check_writable()
+    }
 }
 
 impl KeyValueStore for DataSource {
@@ -399,9 +450,8 @@ impl AtomicView for Database {
         self.latest_height().unwrap_or_default()
     }
 
-    fn view_at(&self, _: &BlockHeight) -> StorageResult<Self::View> {
-        // TODO: Unimplemented until of the https://github.com/FuelLabs/fuel-core/issues/451
-        Ok(self.latest_view())
+    fn view_at(&self, height: &BlockHeight) -> StorageResult<Self::View> {
+        self.state_at(height)
     }
 
     fn latest_view(&self) -> Self::View {
@@ -0,0 +1,221 @@
+use crate::database::{
+    Column,
+    Database,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        manual::Manual,
+        postcard::Postcard,
+        raw::Raw,
+        Decode,
+        Encode,
+    },
+    iter::IterDirection,
+    structured_storage::TableWithBlueprint,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::ContractIdExt,
+    fuel_types::{
+        Address,
+        AssetId,
+        Bytes32,
+        ContractId,
+        Word,
+    },
+    services::graphql_api::AssetDetails,
+};
+use std::borrow::Cow;
+
+/// The table stores the information about each asset aggregated from the executed transactions.
+/// It is maintained by the off-chain worker.
+pub struct Assets;
+
+impl Mappable for Assets {
+    type Key = Self::OwnedKey;
+    type OwnedKey = AssetId;
+    type Value = Self::OwnedValue;
+    type OwnedValue = AssetDetails;
+}
+
+impl TableWithBlueprint for Assets {
+    type Blueprint = Plain<Raw, Postcard>;
+
+    fn column() -> Column {
+        Column::Assets
+    }
+}
+
+fuel_core_types::fuel_vm::double_key!(AssetHolderKey, AssetId, asset_id, Address, owner);
+
+/// The table stores the owners with unspent coins of each asset.
+/// It is maintained by the off-chain worker.
+pub struct AssetHolders;
+
+impl Mappable for AssetHolders {
+    type Key = AssetHolderKey;
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl Encode<AssetHolderKey> for Manual<AssetHolderKey> {
+    type Encoder<'a> = Cow<'a, [u8]>;
+
+    fn encode(t: &AssetHolderKey) -> Self::Encoder<'_> {
+        Cow::Borrowed(t.as_ref())
+    }
+}
+
+impl Decode<AssetHolderKey> for Manual<AssetHolderKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<AssetHolderKey> {
+        AssetHolderKey::from_slice(bytes)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
+impl TableWithBlueprint for AssetHolders {
+    type Blueprint = Plain<Manual<AssetHolderKey>, Postcard>;
+
+    fn column() -> Column {
+        Column::AssetHolders
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <AssetHolders as Mappable>::Key {
+        AssetHolderKey::new(&rng.gen(), &rng.gen())
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        Assets,
+        <Assets as Mappable>::Key::default(),
+        AssetDetails {
+            contract_id: Some(ContractId::from([1; 32])),
+            sub_id: Some(Bytes32::from([2; 32])),
+            total_minted: 100,
+            total_burned: 10,
+            holders: 3,
+        }
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        AssetHolders,
+        <AssetHolders as Mappable>::Key::default(),
+        <AssetHolders as Mappable>::Value::default(),
+        <AssetHolders as Mappable>::Value::default(),
+        generate_key
+    );
+}
+
+impl Database {
+    pub fn asset_details(
+        &self,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<AssetDetails>> {
+        self.storage::<Assets>()
+            .get(asset_id)
+            .map(|v| v.map(|v| v.into_owned()))
+    }
+
+    pub fn all_assets(
+        &self,
+        start: Option<AssetId>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(AssetId, AssetDetails)>> + '_ {
+        self.iter_all_by_start::<Assets>(start.as_ref(), direction)
+    }
+
+    /// Adds the `amount` minted by the `contract_id` to the details of the asset with `sub_id`.
+    pub fn record_asset_mint(
+        &mut self,
+        contract_id: &ContractId,
+        sub_id: &Bytes32,
+        amount: Word,
+    ) -> StorageResult<()> {
+        let asset_id = contract_id.asset_id(sub_id);
+        let mut details = self.asset_details(&asset_id)?.unwrap_or_default();
+        details.contract_id = Some(*contract_id);
+        details.sub_id = Some(*sub_id);
+        details.total_minted = details.total_minted.saturating_add(amount.into());
+        self.storage_as_mut::<Assets>()
+            .insert(&asset_id, &details)?;
+        Ok(())
+    }
+
+    /// Adds the `amount` burned by the `contract_id` to the details of the asset with `sub_id`.
+    pub fn record_asset_burn(
+        &mut self,
+        contract_id: &ContractId,
+        sub_id: &Bytes32,
+        amount: Word,
+    ) -> StorageResult<()> {
+        let asset_id = contract_id.asset_id(sub_id);
+        let mut details = self.asset_details(&asset_id)?.unwrap_or_default();
+        details.contract_id = Some(*contract_id);
+        details.sub_id = Some(*sub_id);
+        details.total_burned = details.total_burned.saturating_add(amount.into());
+        self.storage_as_mut::<Assets>()
+            .insert(&asset_id, &details)?;
+        Ok(())
+    }
+
+    /// Adds the `owner` to the holders of the asset if it is not there yet.
+    pub fn record_asset_holder(
+        &mut self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<()> {
+        let key = AssetHolderKey::new(asset_id, owner);
+        if self
+            .storage_as_mut::<AssetHolders>()
+            .insert(&key, &())?
+            .is_some()
+        {
+            return Ok(())
+        }
+
+        let mut details = self.asset_details(asset_id)?.unwrap_or_default();
+        details.holders = details.holders.saturating_add(1);
+        self.storage_as_mut::<Assets>().insert(asset_id, &details)?;
+        Ok(())
+    }
+
+    /// Removes the `owner` from the holders of the asset if it doesn't have unspent coins
+    /// of the asset anymore. Relies on the [`OwnedCoinsByAmount`](super::coin::OwnedCoinsByAmount)
+    /// index being up to date.
+    pub fn remove_asset_holder(
+        &mut self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<()> {
+        let largest_coin = self
+            .owned_coins_ids_by_amount(owner, asset_id, Some(IterDirection::Reverse))
+            .next()
+            .transpose()?;
+        if matches!(largest_coin, Some((amount, _)) if amount > 0) {
+            return Ok(())
+        }
+
+        let key = AssetHolderKey::new(asset_id, owner);
+        if self
+            .storage_as_mut::<AssetHolders>()
+            .remove(&key)?
+            .is_none()
+        {
+            return Ok(())
+        }
+
+        let mut details = self.asset_details(asset_id)?.unwrap_or_default();
+        details.holders = details.holders.saturating_sub(1);
+        self.storage_as_mut::<Assets>().insert(asset_id, &details)?;
+        Ok(())
+    }
+}
@@ -5,8 +5,12 @@ use crate::database::{
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
+        manual::Manual,
+        postcard::Postcard,
         primitive::Primitive,
         raw::Raw,
+        Decode,
+        Encode,
     },
     iter::IterDirection,
     not_found,
@@ -40,6 +44,7 @@ use fuel_core_types::{
     entities::message::MerkleProof,
     fuel_merkle::binary::MerkleTree,
     fuel_types::BlockHeight,
+    tai64::Tai64,
 };
 use itertools::Itertools;
 use std::borrow::{
@@ -75,6 +80,84 @@ fuel_core_storage::basic_storage_tests!(
     <FuelBlockSecondaryKeyBlockHeights as Mappable>::Value::default()
 );
 
+/// The key of the [`FuelBlockHeightsByTime`] table. The time goes first,
+/// so the iteration over the table is ordered by the time of the block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockTimeKey {
+    pub time: Tai64,
+    pub height: BlockHeight,
+}
+
+impl BlockTimeKey {
+    const LEN: usize = 8 + 4;
+
+    pub fn new(time: Tai64, height: BlockHeight) -> Self {
+        Self { time, height }
+    }
+}
+
+impl Encode<BlockTimeKey> for Manual<BlockTimeKey> {
+    type Encoder<'a> = [u8; BlockTimeKey::LEN];
+
+    fn encode(t: &BlockTimeKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; BlockTimeKey::LEN];
+        bytes[..8].copy_from_slice(&t.time.0.to_be_bytes());
+        bytes[8..].copy_from_slice(&t.height.to_bytes());
+        bytes
+    }
+}
+
+impl Decode<BlockTimeKey> for Manual<BlockTimeKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<BlockTimeKey> {
+        let bytes: [u8; BlockTimeKey::LEN] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let mut time = [0u8; 8];
+        time.copy_from_slice(&bytes[..8]);
+        let mut height = [0u8; 4];
+        height.copy_from_slice(&bytes[8..]);
+        Ok(BlockTimeKey::new(
+            Tai64(u64::from_be_bytes(time)),
+            u32::from_be_bytes(height).into(),
+        ))
+    }
+}
+
+/// The table links the time of the block to its height.
+/// It is maintained by the off-chain worker.
+pub struct FuelBlockHeightsByTime;
+
+impl Mappable for FuelBlockHeightsByTime {
+    type Key = BlockTimeKey;
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for FuelBlockHeightsByTime {
+    type Blueprint = Plain<Manual<BlockTimeKey>, Postcard>;
+
+    fn column() -> Column {
+        Column::FuelBlockHeightsByTime
+    }
+}
+
+#[cfg(test)]
+fn generate_block_time_key(
+    rng: &mut impl rand::Rng,
+) -> <FuelBlockHeightsByTime as Mappable>::Key {
+    BlockTimeKey::new(Tai64(rng.gen()), rng.gen::<u32>().into())
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    FuelBlockHeightsByTime,
+    BlockTimeKey::new(Tai64(1), 1u32.into()),
+    <FuelBlockHeightsByTime as Mappable>::Value::default(),
+    <FuelBlockHeightsByTime as Mappable>::Value::default(),
+    generate_block_time_key
+);
+
 impl StorageInspect<FuelBlocks> for Database {
     type Error = StorageError;
 
@@ -187,6 +270,34 @@ impl Database {
             .map(|v| v.map(|v| v.into_owned()))
     }
 
+    /// Returns the height of the first block produced at or after the `time` for the
+    /// forward direction, and the height of the last block produced at or before
+    /// the `time` for the reverse direction.
+    pub fn block_height_by_time(
+        &self,
+        time: Tai64,
+        direction: IterDirection,
+    ) -> StorageResult<Option<BlockHeight>> {
+        let start = match direction {
+            IterDirection::Forward => BlockTimeKey::new(time, 0u32.into()),
+            IterDirection::Reverse => BlockTimeKey::new(time, u32::MAX.into()),
+        };
+        self.iter_all_by_start::<FuelBlockHeightsByTime>(Some(&start), Some(direction))
+            .next()
+            .transpose()
+            .map(|entry| entry.map(|(key, _)| key.height))
+    }
+
+    pub fn record_block_time(
+        &mut self,
+        time: Tai64,
+        height: BlockHeight,
+    ) -> StorageResult<()> {
+        self.storage::<FuelBlockHeightsByTime>()
+            .insert(&BlockTimeKey::new(time, height), &())?;
+        Ok(())
+    }
+
     /// Retrieve the full block and all associated transactions
     pub(crate) fn get_full_block(
         &self,
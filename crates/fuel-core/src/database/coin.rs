@@ -27,10 +27,15 @@ use fuel_core_types::{
     entities::coins::coin::CompressedCoin,
     fuel_tx::{
         Address,
+        AssetId,
         UtxoId,
     },
+    fuel_types::Word,
+};
+use std::{
+    borrow::Cow,
+    mem::size_of,
 };
-use std::borrow::Cow;
 
 // TODO: Reuse `fuel_vm::storage::double_key` macro.
 pub fn owner_coin_id_key(owner: &Address, coin_id: &UtxoId) -> OwnedCoinKey {
@@ -40,6 +45,28 @@ pub fn owner_coin_id_key(owner: &Address, coin_id: &UtxoId) -> OwnedCoinKey {
     default
 }
 
+/// The offset of the amount in the [`OwnedCoinAmountKey`].
+const AMOUNT_OFFSET: usize = Address::LEN + AssetId::LEN;
+/// The offset of the coin id in the [`OwnedCoinAmountKey`].
+const COIN_ID_OFFSET: usize = AMOUNT_OFFSET + size_of::<Word>();
+/// The offset of the output index in the [`OwnedCoinAmountKey`].
+const OUTPUT_INDEX_OFFSET: usize = COIN_ID_OFFSET + TxId::LEN;
+
+pub fn owner_asset_amount_key(
+    owner: &Address,
+    asset_id: &AssetId,
+    amount: Word,
+    coin_id: &UtxoId,
+) -> OwnedCoinAmountKey {
+    let mut default = [0u8; OUTPUT_INDEX_OFFSET + 1];
+    // The big-endian amount keeps the coins of the asset sorted by the amount.
+    default[0..Address::LEN].copy_from_slice(owner.as_ref());
+    default[Address::LEN..AMOUNT_OFFSET].copy_from_slice(asset_id.as_ref());
+    default[AMOUNT_OFFSET..COIN_ID_OFFSET].copy_from_slice(&amount.to_be_bytes());
+    default[COIN_ID_OFFSET..].copy_from_slice(utxo_id_to_bytes(coin_id).as_ref());
+    default
+}
+
 /// The storage table of owned coin ids. Maps addresses to owned coins.
 pub struct OwnedCoins;
 /// The storage key for owned coins: `Address ++ UtxoId`
@@ -60,6 +87,27 @@ impl TableWithBlueprint for OwnedCoins {
     }
 }
 
+/// The storage table of owned coin ids ordered by the amount for each asset.
+/// It is maintained by the off-chain worker.
+pub struct OwnedCoinsByAmount;
+/// The storage key for owned coins by amount: `Address ++ AssetId ++ Amount ++ UtxoId`
+pub type OwnedCoinAmountKey = [u8; OUTPUT_INDEX_OFFSET + 1];
+
+impl Mappable for OwnedCoinsByAmount {
+    type Key = Self::OwnedKey;
+    type OwnedKey = OwnedCoinAmountKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = ();
+}
+
+impl TableWithBlueprint for OwnedCoinsByAmount {
+    type Blueprint = Plain<Raw, Postcard>;
+
+    fn column() -> Column {
+        Column::OwnedCoinsByAmount
+    }
+}
+
 impl StorageInspect<Coins> for Database {
     type Error = StorageError;
 
@@ -123,6 +171,57 @@ impl Database {
         })
     }
 
+    /// Iterates over the coins of the `owner` with the `asset_id` ordered by the amount.
+    pub fn owned_coins_ids_by_amount(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(Word, UtxoId)>> + '_ {
+        let mut prefix = [0u8; AMOUNT_OFFSET];
+        prefix[0..Address::LEN].copy_from_slice(owner.as_ref());
+        prefix[Address::LEN..].copy_from_slice(asset_id.as_ref());
+        self.iter_all_filtered::<OwnedCoinsByAmount, _>(Some(prefix), None, direction)
+            // Safety: key is always 105 bytes
+            .map(|res| {
+                res.map(|(key, _)| {
+                    let amount = Word::from_be_bytes(
+                        key[AMOUNT_OFFSET..COIN_ID_OFFSET]
+                            .try_into()
+                            .expect("The slice has size 8"),
+                    );
+                    let tx_id = TxId::try_from(&key[COIN_ID_OFFSET..OUTPUT_INDEX_OFFSET])
+                        .expect("The slice has size 32");
+                    (amount, UtxoId::new(tx_id, key[OUTPUT_INDEX_OFFSET]))
+                })
+            })
+    }
+
+    pub fn record_owned_coin_amount(
+        &mut self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: Word,
+        utxo_id: &UtxoId,
+    ) -> StorageResult<()> {
+        let key = owner_asset_amount_key(owner, asset_id, amount, utxo_id);
+        self.storage_as_mut::<OwnedCoinsByAmount>()
+            .insert(&key, &())?;
+        Ok(())
+    }
+
+    pub fn remove_owned_coin_amount(
+        &mut self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: Word,
+        utxo_id: &UtxoId,
+    ) -> StorageResult<()> {
+        let key = owner_asset_amount_key(owner, asset_id, amount, utxo_id);
+        self.storage_as_mut::<OwnedCoinsByAmount>().remove(&key)?;
+        Ok(())
+    }
+
     pub fn coin(&self, utxo_id: &UtxoId) -> StorageResult<CompressedCoin> {
         let coin = self
             .storage_as_ref::<Coins>()
@@ -159,6 +258,7 @@ impl Database {
 #[cfg(test)]
 mod test {
     use super::*;
+    use itertools::Itertools;
 
     fn generate_key(rng: &mut impl rand::Rng) -> <OwnedCoins as Mappable>::Key {
         let mut bytes = [0u8; 65];
@@ -173,4 +273,59 @@ mod test {
         <OwnedCoins as Mappable>::Value::default(),
         generate_key
     );
+
+    fn generate_amount_key(
+        rng: &mut impl rand::Rng,
+    ) -> <OwnedCoinsByAmount as Mappable>::Key {
+        let mut bytes = [0u8; 105];
+        rng.fill(bytes.as_mut());
+        bytes
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        OwnedCoinsByAmount,
+        [0u8; 105],
+        <OwnedCoinsByAmount as Mappable>::Value::default(),
+        <OwnedCoinsByAmount as Mappable>::Value::default(),
+        generate_amount_key
+    );
+
+    #[test]
+    fn owned_coins_by_amount_are_sorted_by_amount() {
+        let mut database = Database::default();
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let coins = [
+            (300, UtxoId::new(TxId::from([1; 32]), 0)),
+            (1, UtxoId::new(TxId::from([2; 32]), 1)),
+            (256, UtxoId::new(TxId::from([3; 32]), 2)),
+        ];
+        for (amount, utxo_id) in &coins {
+            database
+                .record_owned_coin_amount(&owner, &asset_id, *amount, utxo_id)
+                .unwrap();
+        }
+        // The coins of other owners and assets are not visible
+        database
+            .record_owned_coin_amount(&owner, &AssetId::from([3; 32]), 2, &coins[0].1)
+            .unwrap();
+        database
+            .record_owned_coin_amount(&Address::from([2; 32]), &asset_id, 2, &coins[0].1)
+            .unwrap();
+
+        let ids: Vec<_> = database
+            .owned_coins_ids_by_amount(&owner, &asset_id, Some(IterDirection::Reverse))
+            .try_collect()
+            .unwrap();
+        assert_eq!(ids, vec![coins[0], coins[2], coins[1]]);
+
+        database
+            .remove_owned_coin_amount(&owner, &asset_id, coins[2].0, &coins[2].1)
+            .unwrap();
+        let ids: Vec<_> = database
+            .owned_coins_ids_by_amount(&owner, &asset_id, Some(IterDirection::Forward))
+            .try_collect()
+            .unwrap();
+        assert_eq!(ids, vec![coins[1], coins[0]]);
+    }
 }
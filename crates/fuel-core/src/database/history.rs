@@ -0,0 +1,445 @@
+use crate::{
+    database::{
+        Column,
+        Database,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        in_memory::transaction::MemoryTransactionView,
+        TransactableStorage,
+    },
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IteratorableStore,
+    },
+    kv_store::{
+        BatchOperations,
+        KVItem,
+        KeyValueStore,
+        Value,
+    },
+    structured_storage::TableWithBlueprint,
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use parking_lot::Mutex;
+use std::{
+    collections::HashSet,
+    sync::Arc,
+};
+
+/// The policy of recording the history of the state modifications made by blocks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StateRewindPolicy {
+    /// The history is not recorded, and the state can't be rewound.
+    #[default]
+    NoRewind,
+    /// The history of the latest `size` blocks is kept, so the state can be rewound
+    /// to any of the `size` previous heights. The older history is pruned on import.
+    RewindRange { size: u32 },
+}
+
+/// The id of the column, the key, and the value of the key before the block,
+/// or `None` if the key didn't exist.
+pub type Modification = (u32, Vec<u8>, Option<Vec<u8>>);
+
+/// The table stores the previous values of the keys modified by each block.
+/// It allows rewinding the state of the database to any height since the
+/// history was recorded.
+pub struct ModificationsHistory;
+
+impl Mappable for ModificationsHistory {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<Modification>;
+}
+
+impl TableWithBlueprint for ModificationsHistory {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+
+    fn column() -> Column {
+        Column::ModificationsHistory
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    ModificationsHistory,
+    <ModificationsHistory as Mappable>::Key::default(),
+    vec![
+        (Column::Coins.as_u32(), vec![1, 2, 3], None),
+        (
+            Column::ContractsState.as_u32(),
+            vec![4, 5, 6],
+            Some(vec![7, 8, 9])
+        ),
+    ]
+);
+
+impl Database {
+    /// Records the previous values of the keys modified by the uncommitted block at `height`
+    /// and prunes the history that falls outside of the rewind window.
+    /// The database should be the transaction that contains all changes of the block.
+    pub fn record_modifications_history(
+        &mut self,
+        height: &BlockHeight,
+    ) -> StorageResult<()> {
+        let StateRewindPolicy::RewindRange { size } = self.state_rewind_policy else {
+            return Ok(())
+        };
+
+        let modifications: Vec<_> = self
+            .data
+            .as_ref()
+            .previous_values()?
+            .into_iter()
+            .filter(|(column, _, _)| *column != Column::ModificationsHistory)
+            .map(|(column, key, previous)| {
+                (
+                    column.as_u32(),
+                    key,
+                    previous.map(|value| value.as_ref().clone()),
+                )
+            })
+            .collect();
+        self.storage_as_mut::<ModificationsHistory>()
+            .insert(height, &modifications)?;
+
+        // The history of the block at `height - size` is needed to rewind the state
+        // below the window, so it is the newest entry to prune.
+        let Some(last_pruned) = u32::from(*height).checked_sub(size) else {
+            return Ok(())
+        };
+        let pruned = self
+            .iter_all::<ModificationsHistory>(Some(IterDirection::Forward))
+            .map(|entry| entry.map(|(height, _)| height))
+            .take_while(|entry| match entry {
+                Ok(height) => u32::from(*height) <= last_pruned,
+                Err(_) => true,
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+        for height in pruned {
+            self.storage_as_mut::<ModificationsHistory>()
+                .remove(&height)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the view of the state at the `height` built by reverting the modifications
+    /// of all blocks above it. Fails if the `height` is outside of the rewind window,
+    /// or if the history of any of these blocks is missing, e.g. the blocks were imported
+    /// by the node without the history.
+    ///
+    /// The view is not affected by the blocks imported after its creation.
+    pub fn state_at(&self, height: &BlockHeight) -> StorageResult<Database> {
+        // The commits are excluded, so the latest height matches the reverted history.
+        let _guard = self.commit_lock.read();
+        let latest_height = self.latest_height()?;
+        if *height > latest_height {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The block at height {height} doesn't exist"
+            )))
+        }
+        if *height == latest_height {
+            return Ok(self.clone())
+        }
+
+        let StateRewindPolicy::RewindRange { size } = self.state_rewind_policy else {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The node doesn't keep the history of the state, \
+                 so it can't be rewound to the height {height}"
+            )))
+        };
+        let depth = u32::from(latest_height).saturating_sub(u32::from(*height));
+        if depth > size {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The height {height} is outside of the rewind window of the node: \
+                 the state can be rewound at most {size} blocks below the latest height {latest_height}"
+            )))
+        }
+
+        let view = HistoricalView::new(self.clone(), *height);
+        view.revert_up_to(latest_height)?;
+        Ok(Database::new(Arc::new(view))
+            .with_state_rewind_policy(self.state_rewind_policy))
+    }
+}
+
+/// The read-only view of the state of the `database` at the `height`. The keys modified
+/// by the blocks above the `height` have the values from the history of these blocks,
+/// other keys are read from the `database`. The blocks imported into the `database`
+/// after the creation of the view are reverted before each read.
+#[derive(Debug)]
+pub struct HistoricalView {
+    database: Database,
+    /// The `database` with the reverted modifications of the blocks above the `height`.
+    overlay: MemoryTransactionView,
+    reverted: Mutex<RevertedBlocks>,
+}
+
+#[derive(Debug)]
+struct RevertedBlocks {
+    /// The height of the latest block which modifications are reverted in the `overlay`.
+    latest_height: BlockHeight,
+    /// The keys with the reverted values. Only the first reverted value of the key is
+    /// kept, since it belongs to the earliest block that modified the key.
+    keys: HashSet<(Column, Vec<u8>)>,
+}
+
+impl HistoricalView {
+    fn new(database: Database, height: BlockHeight) -> Self {
+        let overlay = MemoryTransactionView::new(database.data.as_ref().clone());
+        Self {
+            database,
+            overlay,
+            reverted: Mutex::new(RevertedBlocks {
+                latest_height: height,
+                keys: Default::default(),
+            }),
+        }
+    }
+
+    /// Reverts the modifications of the blocks up to the `latest_height` in the `overlay`.
+    /// The caller should exclude the commits into the `database`.
+    fn revert_up_to(&self, latest_height: BlockHeight) -> StorageResult<()> {
+        let mut reverted = self.reverted.lock();
+        while reverted.latest_height < latest_height {
+            let Some(height) = reverted.latest_height.succ() else {
+                break
+            };
+            let modifications = self
+                .database
+                .storage::<ModificationsHistory>()
+                .get(&height)?
+                .ok_or_else(|| {
+                    StorageError::Other(anyhow::anyhow!(
+                        "The history of the state at height {height} is not available"
+                    ))
+                })?
+                .into_owned();
+
+            for (column, key, previous) in modifications {
+                let column = enum_iterator::all::<Column>()
+                    .find(|known| known.as_u32() == column)
+                    .ok_or_else(|| {
+                        StorageError::Other(anyhow::anyhow!(
+                            "Unknown column {column} in the history"
+                        ))
+                    })?;
+                if !reverted.keys.insert((column, key.clone())) {
+                    continue
+                }
+                match previous {
+                    Some(value) => self.overlay.put(&key, column, Arc::new(value))?,
+                    None => self.overlay.delete(&key, column)?,
+                }
+            }
+            reverted.latest_height = height;
+        }
+        Ok(())
+    }
+
+    /// Reverts the blocks imported since the last read and reads from the `overlay`.
+    fn read_consistent<T>(
+        &self,
+        read: impl FnOnce(&MemoryTransactionView) -> StorageResult<T>,
+    ) -> StorageResult<T> {
+        let _guard = self.database.commit_lock.read();
+        self.revert_up_to(self.database.latest_height()?)?;
+        read(&self.overlay)
+    }
+
+    fn read_only_error() -> StorageError {
+        StorageError::Other(anyhow::anyhow!("The historical view is read-only"))
+    }
+}
+
+impl KeyValueStore for HistoricalView {
+    type Column = Column;
+
+    fn write(&self, _: &[u8], _: Column, _: &[u8]) -> StorageResult<usize> {
+        Err(Self::read_only_error())
+    }
+
+    fn delete(&self, _: &[u8], _: Column) -> StorageResult<()> {
+        Err(Self::read_only_error())
+    }
+
+    fn size_of_value(&self, key: &[u8], column: Column) -> StorageResult<Option<usize>> {
+        self.read_consistent(|overlay| overlay.size_of_value(key, column))
+    }
+
+    fn get(&self, key: &[u8], column: Column) -> StorageResult<Option<Value>> {
+        self.read_consistent(|overlay| overlay.get(key, column))
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        self.read_consistent(|overlay| overlay.read(key, column, buf))
+    }
+}
+
+impl IteratorableStore for HistoricalView {
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        // The iterators of the underlying storages take the snapshot on creation,
+        // so it is enough to create them under the lock.
+        let _guard = self.database.commit_lock.read();
+        let reverted = self
+            .database
+            .latest_height()
+            .and_then(|latest_height| self.revert_up_to(latest_height));
+        match reverted {
+            Ok(()) => self.overlay.iter_all(column, prefix, start, direction),
+            Err(error) => core::iter::once(Err(error)).into_boxed(),
+        }
+    }
+}
+
+impl BatchOperations for HistoricalView {}
+
+impl TransactableStorage for HistoricalView {
+    fn flush(&self) -> DatabaseResult<()> {
+        Ok(())
+    }
+
+    fn check_writable(&self) -> DatabaseResult<()> {
+        Err(DatabaseError::Other(anyhow::anyhow!(
+            "The historical view is read-only"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::FuelBlocks,
+        transactional::Transaction,
+    };
+    use fuel_core_types::blockchain::block::CompressedBlock;
+
+    fn database_with_blocks(policy: StateRewindPolicy, blocks: u32) -> Database {
+        let database = Database::default().with_state_rewind_policy(policy);
+        for height in 0..=blocks {
+            import_block(&database, height.into());
+        }
+        database
+    }
+
+    fn import_block(database: &Database, height: BlockHeight) {
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(height);
+        block.header_mut().recalculate_metadata();
+
+        let mut transaction = database.transaction();
+        transaction
+            .as_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height, &block)
+            .unwrap();
+        transaction
+            .as_mut()
+            .record_modifications_history(&height)
+            .unwrap();
+        transaction.commit().unwrap();
+    }
+
+    fn recorded_heights(database: &Database) -> Vec<u32> {
+        database
+            .iter_all::<ModificationsHistory>(None)
+            .map(|entry| u32::from(entry.unwrap().0))
+            .collect()
+    }
+
+    #[test]
+    fn history_is_not_recorded_without_rewind() {
+        let database = database_with_blocks(StateRewindPolicy::NoRewind, 5);
+
+        assert!(recorded_heights(&database).is_empty());
+        assert!(database.state_at(&4u32.into()).is_err());
+    }
+
+    #[test]
+    fn history_outside_of_the_window_is_pruned() {
+        let database =
+            database_with_blocks(StateRewindPolicy::RewindRange { size: 2 }, 5);
+
+        assert_eq!(recorded_heights(&database), vec![4, 5]);
+    }
+
+    #[test]
+    fn state_is_rewound_only_within_the_window() {
+        let database =
+            database_with_blocks(StateRewindPolicy::RewindRange { size: 2 }, 5);
+
+        let view = database.state_at(&3u32.into()).unwrap();
+        assert_eq!(view.latest_height().unwrap(), 3u32.into());
+        assert!(database.state_at(&2u32.into()).is_err());
+    }
+
+    #[test]
+    fn state_is_not_affected_by_blocks_imported_later() {
+        let database =
+            database_with_blocks(StateRewindPolicy::RewindRange { size: 10 }, 5);
+
+        let view = database.state_at(&3u32.into()).unwrap();
+        import_block(&database, 6u32.into());
+
+        assert_eq!(view.latest_height().unwrap(), 3u32.into());
+        assert!(!view
+            .storage::<FuelBlocks>()
+            .contains_key(&6u32.into())
+            .unwrap());
+        assert!(view
+            .storage::<FuelBlocks>()
+            .contains_key(&3u32.into())
+            .unwrap());
+    }
+
+    #[test]
+    fn state_is_rewound_consistently_while_blocks_are_imported() {
+        let database =
+            database_with_blocks(StateRewindPolicy::RewindRange { size: 1000 }, 5);
+
+        let importer = std::thread::spawn({
+            let database = database.clone();
+            move || {
+                for height in 6..=200u32 {
+                    import_block(&database, height.into());
+                }
+            }
+        });
+
+        while !importer.is_finished() {
+            let height = database.latest_height().unwrap().pred().unwrap();
+            let view = database.state_at(&height).unwrap();
+            assert_eq!(view.latest_height().unwrap(), height);
+        }
+        importer.join().unwrap();
+    }
+}
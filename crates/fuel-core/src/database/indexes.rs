@@ -0,0 +1,151 @@
+use crate::database::{
+    assets::{
+        AssetHolders,
+        Assets,
+    },
+    coin::OwnedCoinsByAmount,
+    Column,
+    Database,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::postcard::Postcard,
+    iter::IterDirection,
+    structured_storage::TableWithBlueprint,
+    tables::FuelBlocks,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::services::graphql_api::{
+    AssetDetails,
+    IndexState,
+    OffChainIndex,
+};
+use itertools::Itertools;
+
+/// The table stores the state of each off-chain index enabled on the node.
+/// It is maintained by the off-chain worker.
+pub struct OffChainIndexes;
+
+impl Mappable for OffChainIndexes {
+    type Key = Self::OwnedKey;
+    type OwnedKey = OffChainIndex;
+    type Value = Self::OwnedValue;
+    type OwnedValue = IndexState;
+}
+
+impl TableWithBlueprint for OffChainIndexes {
+    type Blueprint = Plain<Postcard, Postcard>;
+
+    fn column() -> Column {
+        Column::OffChainIndexes
+    }
+}
+
+/// The migration step that marks the indexes maintained by the previous versions
+/// as ready. The index of transactions by owners was built on import from the first
+/// stored block, so the worker keeps it instead of backfilling it from scratch.
+pub(crate) fn seed_index_states(db: &mut Database) -> StorageResult<()> {
+    let first_block = db
+        .iter_all::<FuelBlocks>(Some(IterDirection::Forward))
+        .next()
+        .transpose()?;
+    if let Some((since, _)) = first_block {
+        db.record_index_state(OffChainIndex::Owners, &IndexState::Ready { since })?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <OffChainIndexes as Mappable>::Key {
+        OffChainIndex::ALL[rng.gen_range(0..OffChainIndex::ALL.len())]
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        OffChainIndexes,
+        OffChainIndex::Owners,
+        IndexState::Ready {
+            since: 10u32.into(),
+        },
+        IndexState::Ready {
+            since: 10u32.into(),
+        },
+        generate_key
+    );
+}
+
+impl Database {
+    pub fn index_state(&self, index: OffChainIndex) -> StorageResult<Option<IndexState>> {
+        self.storage::<OffChainIndexes>()
+            .get(&index)
+            .map(|v| v.map(|v| v.into_owned()))
+    }
+
+    pub fn record_index_state(
+        &mut self,
+        index: OffChainIndex,
+        state: &IndexState,
+    ) -> StorageResult<()> {
+        self.storage_as_mut::<OffChainIndexes>()
+            .insert(&index, state)?;
+        Ok(())
+    }
+
+    pub fn remove_index_state(&mut self, index: OffChainIndex) -> StorageResult<()> {
+        self.storage_as_mut::<OffChainIndexes>().remove(&index)?;
+        Ok(())
+    }
+
+    /// Removes the entries of the index that can't be rebuilt on top of the existing ones.
+    /// The indexes of transactions and block times are idempotent, so their entries are kept.
+    pub fn reset_index(&mut self, index: OffChainIndex) -> StorageResult<()> {
+        match index {
+            OffChainIndex::CoinsByAmount => {
+                let coins: Vec<_> = self
+                    .iter_all::<OwnedCoinsByAmount>(None)
+                    .map_ok(|(key, _)| key)
+                    .try_collect()?;
+                for key in coins {
+                    self.storage_as_mut::<OwnedCoinsByAmount>().remove(&key)?;
+                }
+
+                let holders: Vec<_> = self
+                    .iter_all::<AssetHolders>(None)
+                    .map_ok(|(key, _)| key)
+                    .try_collect()?;
+                for key in holders {
+                    self.storage_as_mut::<AssetHolders>().remove(&key)?;
+                }
+
+                self.update_all_assets(|details| details.holders = 0)
+            }
+            OffChainIndex::Assets => self.update_all_assets(|details| {
+                details.total_minted = 0;
+                details.total_burned = 0;
+            }),
+            OffChainIndex::Owners
+            | OffChainIndex::Contracts
+            | OffChainIndex::PredicateRoots
+            | OffChainIndex::ScriptHashes
+            | OffChainIndex::BlockTimes => Ok(()),
+        }
+    }
+
+    fn update_all_assets(
+        &mut self,
+        update: impl Fn(&mut AssetDetails),
+    ) -> StorageResult<()> {
+        let assets: Vec<_> = self.iter_all::<Assets>(None).try_collect()?;
+        for (asset_id, mut details) in assets {
+            update(&mut details);
+            self.storage_as_mut::<Assets>()
+                .insert(&asset_id, &details)?;
+        }
+        Ok(())
+    }
+}
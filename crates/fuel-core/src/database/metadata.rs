@@ -56,8 +56,9 @@ pub(crate) const CHAIN_NAME_KEY: &str = "chain_name";
 /// It's useful for analyzing TPS or other metrics.
 pub(crate) const TX_COUNT: &str = "total_tx_count";
 
-/// Can be used to perform migrations in the future.
-pub(crate) const DB_VERSION: u32 = 0x00;
+/// The version of the database layout. It should be increased along with adding
+/// the step to the [`MIGRATIONS`](crate::database::migration::MIGRATIONS).
+pub(crate) const DB_VERSION: u32 = 0x01;
 
 impl Database {
     /// Ensures the database is initialized and that the database version is correct
@@ -76,6 +77,9 @@ impl Database {
                 })?;
         }
 
+        // Upgrade the database created by the previous versions
+        self.migrate(false)?;
+
         // Ensure the database version is correct
         if let Some(version) = self.storage::<MetadataTable<u32>>().get(DB_VERSION_KEY)? {
             let version = version.into_owned();
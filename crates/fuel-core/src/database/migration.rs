@@ -0,0 +1,435 @@
+//! The migrations of the database between the versions of the column layout.
+//!
+//! Each [`Migration`] upgrades the database from `version - 1` to `version` by rewriting
+//! the entries of the affected columns. The migrations are applied in order on startup,
+//! and the version stored in the metadata is updated atomically with the changes of the step.
+
+use crate::database::{
+    indexes::seed_index_states,
+    metadata::{
+        MetadataTable,
+        DB_VERSION,
+        DB_VERSION_KEY,
+    },
+    Column,
+    Database,
+    Error as DatabaseError,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    kv_store::{
+        KeyValueStore,
+        StorageColumn,
+    },
+    transactional::Transaction,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use std::sync::Arc;
+
+/// The number of processed entries between the progress logs.
+const PROGRESS_LOG_INTERVAL: u64 = 100_000;
+
+/// The migrations ordered by the version. The last one upgrades the database to the [`DB_VERSION`].
+pub(crate) const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    description: "mark the off-chain indexes built by the previous versions as ready",
+    columns: &[],
+    migrate: keep_entry,
+    finalize: Some(seed_index_states),
+}];
+
+/// The change of the entry made by the migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryChange {
+    /// The entry remains as is.
+    Keep,
+    /// The entry is removed from the column.
+    Remove,
+    /// The entry is replaced with the new key and value in the same column.
+    Replace { key: Vec<u8>, value: Vec<u8> },
+}
+
+/// The step that upgrades the database from `version - 1` to `version`.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// The version of the database after the migration.
+    pub version: u32,
+    /// The human-readable description of the changes.
+    pub description: &'static str,
+    /// The columns affected by the migration.
+    pub columns: &'static [Column],
+    /// Returns the change of the entry with the `key` and `value` in the column.
+    pub migrate: fn(Column, &[u8], &[u8]) -> StorageResult<EntryChange>,
+    /// Updates the database after the entries of the columns are migrated,
+    /// in the same transaction.
+    pub finalize: Option<fn(&mut Database) -> StorageResult<()>>,
+}
+
+/// The migration of the entry that doesn't change it.
+fn keep_entry(_: Column, _: &[u8], _: &[u8]) -> StorageResult<EntryChange> {
+    Ok(EntryChange::Keep)
+}
+
+/// The outcome of the migration step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationSummary {
+    /// The version of the database after the migration.
+    pub version: u32,
+    /// The description of the migration.
+    pub description: &'static str,
+    /// The number of entries visited in the affected columns.
+    pub visited_entries: u64,
+    /// The number of entries removed or replaced by the migration.
+    pub changed_entries: u64,
+}
+
+impl Database {
+    /// Upgrades the database to the [`DB_VERSION`] supported by this build.
+    /// The changes are not written in the `dry_run` mode.
+    ///
+    /// Returns an error if the database was created by a newer version of the `fuel-core`.
+    pub fn migrate(&self, dry_run: bool) -> StorageResult<Vec<MigrationSummary>> {
+        self.migrate_with(MIGRATIONS, DB_VERSION, dry_run)
+    }
+
+    fn migrate_with(
+        &self,
+        migrations: &[Migration],
+        target: u32,
+        dry_run: bool,
+    ) -> StorageResult<Vec<MigrationSummary>> {
+        let Some(found) = self
+            .storage_as_ref::<MetadataTable<u32>>()
+            .get(DB_VERSION_KEY)?
+            .map(|version| version.into_owned())
+        else {
+            // The database is not initialized yet, so there is nothing to migrate.
+            return Ok(vec![])
+        };
+
+        if found > target {
+            return Err(DatabaseError::NewerDatabaseVersion {
+                found,
+                supported: target,
+            }
+            .into())
+        }
+
+        if found < target {
+            tracing::info!(
+                "Migrating the database from the version {found:#x} to {target:#x}{}",
+                if dry_run { " (dry run)" } else { "" }
+            );
+        }
+
+        // All steps are checked before applying any of them to not leave
+        // the database partially migrated.
+        let steps = (found.saturating_add(1)..=target)
+            .map(|version| {
+                migrations
+                    .iter()
+                    .find(|migration| migration.version == version)
+                    .ok_or(DatabaseError::MissingMigration { version })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // In the dry run mode, the steps are committed into the transaction that is never
+        // committed into the database, so each step sees the changes of the previous ones.
+        let dry_run_view = dry_run.then(|| self.transaction());
+        let base: &Database = dry_run_view.as_deref().unwrap_or(self);
+
+        steps
+            .into_iter()
+            .map(|migration| base.apply_migration(migration))
+            .collect()
+    }
+
+    /// Applies the migration in one transaction along with the update of the version.
+    /// All changes of the step are accumulated in memory until the commit.
+    fn apply_migration(&self, migration: &Migration) -> StorageResult<MigrationSummary> {
+        tracing::info!(
+            "Applying the migration to the version {:#x}: {}",
+            migration.version,
+            migration.description
+        );
+        let mut transaction = self.transaction();
+        let mut summary = MigrationSummary {
+            version: migration.version,
+            description: migration.description,
+            visited_entries: 0,
+            changed_entries: 0,
+        };
+
+        for column in migration.columns.iter().copied() {
+            let mut visited: u64 = 0;
+            // Reads the state before the migration, while the changes go into the transaction.
+            for entry in
+                self.data
+                    .as_ref()
+                    .iter_all(column, None, None, IterDirection::Forward)
+            {
+                let (key, value) = entry?;
+                match (migration.migrate)(column, &key, &value)? {
+                    EntryChange::Keep => {}
+                    EntryChange::Remove => {
+                        transaction.data.as_ref().delete(&key, column)?;
+                        summary.changed_entries =
+                            summary.changed_entries.saturating_add(1);
+                    }
+                    EntryChange::Replace {
+                        key: new_key,
+                        value: new_value,
+                    } => {
+                        if new_key != key {
+                            transaction.data.as_ref().delete(&key, column)?;
+                        }
+                        transaction.data.as_ref().put(
+                            &new_key,
+                            column,
+                            Arc::new(new_value),
+                        )?;
+                        summary.changed_entries =
+                            summary.changed_entries.saturating_add(1);
+                    }
+                }
+
+                visited = visited.saturating_add(1);
+                if visited.checked_rem(PROGRESS_LOG_INTERVAL) == Some(0) {
+                    tracing::info!(
+                        "Migrated {visited} entries of the column `{}`",
+                        column.name()
+                    );
+                }
+            }
+            tracing::info!(
+                "Migrated all {visited} entries of the column `{}`",
+                column.name()
+            );
+            summary.visited_entries = summary.visited_entries.saturating_add(visited);
+        }
+
+        if let Some(finalize) = migration.finalize {
+            finalize(transaction.as_mut())?;
+        }
+
+        transaction
+            .as_mut()
+            .storage_as_mut::<MetadataTable<u32>>()
+            .insert(DB_VERSION_KEY, &migration.version)?;
+        transaction.commit()?;
+
+        tracing::info!(
+            "The database is migrated to the version {:#x}, {} of {} entries changed",
+            migration.version,
+            summary.changed_entries,
+            summary.visited_entries
+        );
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn database_with_version(version: u32) -> Database {
+        let mut database = Database::default();
+        database
+            .storage_as_mut::<MetadataTable<u32>>()
+            .insert(DB_VERSION_KEY, &version)
+            .unwrap();
+        database
+    }
+
+    fn version(database: &Database) -> u32 {
+        database
+            .storage_as_ref::<MetadataTable<u32>>()
+            .get(DB_VERSION_KEY)
+            .unwrap()
+            .unwrap()
+            .into_owned()
+    }
+
+    fn put(database: &Database, key: &[u8], value: &[u8]) {
+        database
+            .data
+            .as_ref()
+            .put(key, Column::ContractsRawCode, Arc::new(value.to_vec()))
+            .unwrap();
+    }
+
+    fn get(database: &Database, key: &[u8]) -> Option<Vec<u8>> {
+        database
+            .data
+            .as_ref()
+            .get(key, Column::ContractsRawCode)
+            .unwrap()
+            .map(|value| value.as_ref().clone())
+    }
+
+    /// Doubles each byte of the value.
+    fn double_values(_: Column, key: &[u8], value: &[u8]) -> StorageResult<EntryChange> {
+        Ok(EntryChange::Replace {
+            key: key.to_vec(),
+            value: value.iter().map(|byte| byte.wrapping_mul(2)).collect(),
+        })
+    }
+
+    /// Removes the entries with the empty value.
+    fn remove_empty(_: Column, _: &[u8], value: &[u8]) -> StorageResult<EntryChange> {
+        if value.is_empty() {
+            Ok(EntryChange::Remove)
+        } else {
+            Ok(EntryChange::Keep)
+        }
+    }
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 2,
+            description: "double the values",
+            columns: &[Column::ContractsRawCode],
+            migrate: double_values,
+            finalize: None,
+        },
+        Migration {
+            version: 1,
+            description: "remove empty values",
+            columns: &[Column::ContractsRawCode],
+            migrate: remove_empty,
+            finalize: None,
+        },
+    ];
+
+    #[test]
+    fn migrations_end_at_the_current_version() {
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version as usize, index.checked_add(1).unwrap());
+        }
+        assert_eq!(MIGRATIONS.len(), DB_VERSION as usize);
+    }
+
+    #[test]
+    fn pending_migrations_are_applied_in_order() {
+        let database = database_with_version(0);
+        put(&database, &[1], &[1, 2]);
+        put(&database, &[2], &[]);
+
+        let summaries = database.migrate_with(TEST_MIGRATIONS, 2, false).unwrap();
+
+        assert_eq!(
+            summaries,
+            vec![
+                MigrationSummary {
+                    version: 1,
+                    description: "remove empty values",
+                    visited_entries: 2,
+                    changed_entries: 1,
+                },
+                MigrationSummary {
+                    version: 2,
+                    description: "double the values",
+                    visited_entries: 1,
+                    changed_entries: 1,
+                },
+            ]
+        );
+        assert_eq!(get(&database, &[1]), Some(vec![2, 4]));
+        assert_eq!(get(&database, &[2]), None);
+        assert_eq!(version(&database), 2);
+    }
+
+    #[test]
+    fn only_newer_migrations_are_applied() {
+        let database = database_with_version(1);
+        put(&database, &[2], &[]);
+
+        let summaries = database.migrate_with(TEST_MIGRATIONS, 2, false).unwrap();
+
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(get(&database, &[2]), Some(vec![]));
+        assert_eq!(version(&database), 2);
+    }
+
+    #[test]
+    fn dry_run_does_not_change_the_database() {
+        let database = database_with_version(0);
+        put(&database, &[1], &[1, 2]);
+        put(&database, &[2], &[]);
+
+        let summaries = database.migrate_with(TEST_MIGRATIONS, 2, true).unwrap();
+
+        // The second step sees the changes of the first one
+        assert_eq!(summaries[1].visited_entries, 1);
+        assert_eq!(get(&database, &[1]), Some(vec![1, 2]));
+        assert_eq!(get(&database, &[2]), Some(vec![]));
+        assert_eq!(version(&database), 0);
+    }
+
+    #[test]
+    fn newer_database_is_refused() {
+        let database = database_with_version(3);
+
+        let result = database.migrate_with(TEST_MIGRATIONS, 2, false);
+
+        assert!(result.is_err());
+        assert_eq!(version(&database), 3);
+    }
+
+    #[test]
+    fn missing_migration_is_an_error() {
+        let database = database_with_version(0);
+
+        let result = database.migrate_with(TEST_MIGRATIONS, 3, false);
+
+        assert!(result.is_err());
+        // None of the steps are applied
+        assert_eq!(version(&database), 0);
+    }
+
+    #[test]
+    fn existing_owners_index_is_ready() {
+        use fuel_core_storage::tables::FuelBlocks;
+        use fuel_core_types::{
+            blockchain::block::CompressedBlock,
+            services::graphql_api::{
+                IndexState,
+                OffChainIndex,
+            },
+        };
+
+        let mut database = database_with_version(0);
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(5u32.into());
+        block.header_mut().recalculate_metadata();
+        database
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&5u32.into(), &block)
+            .unwrap();
+
+        database.migrate_with(&MIGRATIONS[..1], 1, false).unwrap();
+
+        assert_eq!(
+            database.index_state(OffChainIndex::Owners).unwrap(),
+            Some(IndexState::Ready {
+                since: 5u32.into()
+            })
+        );
+        // The indexes added by this version are backfilled by the off-chain worker
+        assert_eq!(
+            database.index_state(OffChainIndex::CoinsByAmount).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn uninitialized_database_is_not_migrated() {
+        let database = Database::default();
+
+        let summaries = database.migrate_with(TEST_MIGRATIONS, 2, false).unwrap();
+
+        assert!(summaries.is_empty());
+    }
+}
@@ -1,10 +1,26 @@
 use crate::{
     database::{
-        block::FuelBlockSecondaryKeyBlockHeights,
-        coin::OwnedCoins,
+        assets::{
+            AssetHolders,
+            Assets,
+        },
+        block::{
+            FuelBlockHeightsByTime,
+            FuelBlockSecondaryKeyBlockHeights,
+        },
+        coin::{
+            OwnedCoins,
+            OwnedCoinsByAmount,
+        },
+        history::ModificationsHistory,
+        indexes::OffChainIndexes,
         message::OwnedMessageIds,
         transactions::{
+            ContractTransactions,
             OwnedTransactions,
+            PredicateTransactions,
+            ScriptTransactions,
+            TransactionStateDiffs,
             TransactionStatuses,
         },
         Database,
@@ -84,10 +100,20 @@ use_structured_implementation!(
     ContractsAssetsMerkleMetadata,
     ContractsAssetsMerkleData,
     OwnedCoins,
+    OwnedCoinsByAmount,
     OwnedMessageIds,
     OwnedTransactions,
+    ContractTransactions,
+    PredicateTransactions,
+    ScriptTransactions,
+    Assets,
+    AssetHolders,
     TransactionStatuses,
+    TransactionStateDiffs,
+    OffChainIndexes,
+    ModificationsHistory,
     FuelBlockSecondaryKeyBlockHeights,
+    FuelBlockHeightsByTime,
     FuelBlockMerkleData,
     FuelBlockMerkleMetadata
 );
@@ -57,6 +57,7 @@ impl Default for DatabaseTransaction {
 
 impl Transaction<Database> for DatabaseTransaction {
     fn commit(&mut self) -> StorageResult<()> {
+        let _guard = self.database.commit_lock.write();
         // TODO: should commit be fallible if this api is meant to be atomic?
         self.changes.commit()
     }
@@ -65,9 +66,13 @@ impl Transaction<Database> for DatabaseTransaction {
 impl From<&Database> for DatabaseTransaction {
     fn from(source: &Database) -> Self {
         let data = Arc::new(MemoryTransactionView::new(source.data.as_ref().clone()));
+        let mut database = Database::new(data.clone())
+            .with_state_rewind_policy(source.state_rewind_policy);
+        // The transaction writes into the `source`, so it shares the lock of the `source`.
+        database.commit_lock = source.commit_lock.clone();
         Self {
-            changes: data.clone(),
-            database: Database::new(data),
+            changes: data,
+            database,
         }
     }
 }
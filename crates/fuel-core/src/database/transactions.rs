@@ -31,8 +31,12 @@ use fuel_core_types::{
     fuel_types::{
         Address,
         BlockHeight,
+        ContractId,
+    },
+    services::{
+        executor::StateDiff,
+        txpool::TransactionStatus,
     },
-    services::txpool::TransactionStatus,
 };
 
 /// These tables allow iteration over all transactions owned by an address.
@@ -53,6 +57,64 @@ impl TableWithBlueprint for OwnedTransactions {
     }
 }
 
+/// These tables allow iteration over all transactions that use a contract as an input.
+pub struct ContractTransactions;
+
+impl Mappable for ContractTransactions {
+    type Key = ContractTransactionIndexKey;
+    type OwnedKey = Self::Key;
+    type Value = Bytes32;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractTransactions {
+    type Blueprint = Plain<Manual<ContractTransactionIndexKey>, Raw>;
+
+    fn column() -> Column {
+        Column::TransactionsByContractBlockIdx
+    }
+}
+
+/// These tables allow iteration over all transactions that spend from a predicate
+/// with the root. The owner of the key is the root of the predicate.
+/// It is populated only if the index of predicate roots is enabled.
+pub struct PredicateTransactions;
+
+impl Mappable for PredicateTransactions {
+    type Key = OwnedTransactionIndexKey;
+    type OwnedKey = Self::Key;
+    type Value = Bytes32;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for PredicateTransactions {
+    type Blueprint = Plain<Manual<OwnedTransactionIndexKey>, Raw>;
+
+    fn column() -> Column {
+        Column::TransactionsByPredicateRootBlockIdx
+    }
+}
+
+/// These tables allow iteration over all transactions with the script or that deploy
+/// the bytecode. The owner of the key is the hash of the script or the root of the bytecode.
+/// It is populated only if the index of script hashes is enabled.
+pub struct ScriptTransactions;
+
+impl Mappable for ScriptTransactions {
+    type Key = OwnedTransactionIndexKey;
+    type OwnedKey = Self::Key;
+    type Value = Bytes32;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ScriptTransactions {
+    type Blueprint = Plain<Manual<OwnedTransactionIndexKey>, Raw>;
+
+    fn column() -> Column {
+        Column::TransactionsByScriptHashBlockIdx
+    }
+}
+
 /// The table stores the status of each transaction.
 pub struct TransactionStatuses;
 
@@ -71,6 +133,25 @@ impl TableWithBlueprint for TransactionStatuses {
     }
 }
 
+/// The table stores the changes of the state made by each transaction.
+/// It is populated only if the recording of the state diff is enabled.
+pub struct TransactionStateDiffs;
+
+impl Mappable for TransactionStateDiffs {
+    type Key = Bytes32;
+    type OwnedKey = Self::Key;
+    type Value = StateDiff;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TransactionStateDiffs {
+    type Blueprint = Plain<Raw, Postcard>;
+
+    fn column() -> Column {
+        Column::TransactionStateDiffs
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -81,6 +162,14 @@ mod test {
         bytes.into()
     }
 
+    fn generate_contract_key(
+        rng: &mut impl rand::Rng,
+    ) -> <ContractTransactions as Mappable>::Key {
+        let mut bytes = [0u8; INDEX_SIZE];
+        rng.fill(bytes.as_mut());
+        bytes.into()
+    }
+
     fuel_core_storage::basic_storage_tests!(
         OwnedTransactions,
         [1u8; INDEX_SIZE].into(),
@@ -89,6 +178,30 @@ mod test {
         generate_key
     );
 
+    fuel_core_storage::basic_storage_tests!(
+        ContractTransactions,
+        [1u8; INDEX_SIZE].into(),
+        <ContractTransactions as Mappable>::Value::default(),
+        <ContractTransactions as Mappable>::Value::default(),
+        generate_contract_key
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        PredicateTransactions,
+        [1u8; INDEX_SIZE].into(),
+        <PredicateTransactions as Mappable>::Value::default(),
+        <PredicateTransactions as Mappable>::Value::default(),
+        generate_key
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        ScriptTransactions,
+        [1u8; INDEX_SIZE].into(),
+        <ScriptTransactions as Mappable>::Value::default(),
+        <ScriptTransactions as Mappable>::Value::default(),
+        generate_key
+    );
+
     fuel_core_storage::basic_storage_tests!(
         TransactionStatuses,
         <TransactionStatuses as Mappable>::Key::default(),
@@ -96,6 +209,12 @@ mod test {
             time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
         }
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        TransactionStateDiffs,
+        <TransactionStateDiffs as Mappable>::Key::default(),
+        StateDiff::default()
+    );
 }
 
 impl Database {
@@ -145,6 +264,130 @@ impl Database {
         )
     }
 
+    /// Iterates over a KV mapping of `[contract id + block height + tx idx] => transaction id`.
+    /// The same as [`Database::owned_transactions`], but for transactions that use the contract.
+    pub fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(TxPointer, Bytes32)>> + '_ {
+        let start = start.map(|cursor| {
+            ContractTransactionIndexKey::new(
+                &contract_id,
+                cursor.block_height,
+                cursor.tx_idx,
+            )
+        });
+        self.iter_all_filtered::<ContractTransactions, _>(
+            Some(contract_id),
+            start.as_ref(),
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, tx_id)| (TxPointer::new(key.block_height, key.tx_idx), tx_id))
+        })
+    }
+
+    pub fn record_tx_id_contract(
+        &mut self,
+        contract_id: &ContractId,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+        tx_id: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<ContractTransactions>().insert(
+            &ContractTransactionIndexKey::new(contract_id, block_height, tx_idx),
+            tx_id,
+        )
+    }
+
+    /// Iterates over a KV mapping of `[predicate root + block height + tx idx] => transaction id`.
+    /// The same as [`Database::owned_transactions`], but for transactions that spend
+    /// from the predicate.
+    pub fn predicate_transactions(
+        &self,
+        predicate_root: Address,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(TxPointer, Bytes32)>> + '_ {
+        let start = start.map(|cursor| {
+            OwnedTransactionIndexKey::new(
+                &predicate_root,
+                cursor.block_height,
+                cursor.tx_idx,
+            )
+        });
+        self.iter_all_filtered::<PredicateTransactions, _>(
+            Some(predicate_root),
+            start.as_ref(),
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, tx_id)| (TxPointer::new(key.block_height, key.tx_idx), tx_id))
+        })
+    }
+
+    pub fn record_tx_id_predicate_root(
+        &mut self,
+        predicate_root: &Address,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+        tx_id: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<PredicateTransactions>().insert(
+            &OwnedTransactionIndexKey::new(predicate_root, block_height, tx_idx),
+            tx_id,
+        )
+    }
+
+    /// Iterates over a KV mapping of `[script hash + block height + tx idx] => transaction id`.
+    /// The same as [`Database::owned_transactions`], but for transactions with the script
+    /// or that deploy the bytecode with the root.
+    pub fn script_transactions(
+        &self,
+        script_hash: Bytes32,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(TxPointer, Bytes32)>> + '_ {
+        let script_hash = Address::new(*script_hash);
+        let start = start.map(|cursor| {
+            OwnedTransactionIndexKey::new(
+                &script_hash,
+                cursor.block_height,
+                cursor.tx_idx,
+            )
+        });
+        self.iter_all_filtered::<ScriptTransactions, _>(
+            Some(script_hash),
+            start.as_ref(),
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, tx_id)| (TxPointer::new(key.block_height, key.tx_idx), tx_id))
+        })
+    }
+
+    pub fn record_tx_id_script_hash(
+        &mut self,
+        script_hash: &Bytes32,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+        tx_id: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<ScriptTransactions>().insert(
+            &OwnedTransactionIndexKey::new(
+                &Address::new(**script_hash),
+                block_height,
+                tx_idx,
+            ),
+            tx_id,
+        )
+    }
+
     pub fn update_tx_status(
         &mut self,
         id: &Bytes32,
@@ -163,6 +406,23 @@ impl Database {
             .get(id)
             .map(|v| v.map(|v| v.into_owned()))
     }
+
+    pub fn update_tx_state_diff(
+        &mut self,
+        id: &Bytes32,
+        state_diff: &StateDiff,
+    ) -> StorageResult<Option<StateDiff>> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<TransactionStateDiffs>()
+            .insert(id, state_diff)
+    }
+
+    pub fn get_tx_state_diff(&self, id: &Bytes32) -> StorageResult<Option<StateDiff>> {
+        use fuel_core_storage::StorageAsRef;
+        self.storage::<TransactionStateDiffs>()
+            .get(id)
+            .map(|v| v.map(|v| v.into_owned()))
+    }
 }
 
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
@@ -170,7 +430,7 @@ const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
 
 fn owned_tx_index_key(
-    owner: &Address,
+    owner: &[u8; Address::LEN],
     height: BlockHeight,
     tx_idx: TransactionIndex,
 ) -> [u8; INDEX_SIZE] {
@@ -250,6 +510,61 @@ impl Decode<OwnedTransactionIndexKey> for Manual<OwnedTransactionIndexKey> {
     }
 }
 
+/// The key of the [`ContractTransactions`] table. It has the same layout as
+/// the [`OwnedTransactionIndexKey`], where the contract id is the owner.
+#[derive(Clone)]
+pub struct ContractTransactionIndexKey {
+    contract_id: ContractId,
+    block_height: BlockHeight,
+    tx_idx: TransactionIndex,
+}
+
+impl ContractTransactionIndexKey {
+    pub fn new(
+        contract_id: &ContractId,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+    ) -> Self {
+        Self {
+            contract_id: *contract_id,
+            block_height,
+            tx_idx,
+        }
+    }
+}
+
+impl From<OwnedTransactionIndexKey> for ContractTransactionIndexKey {
+    fn from(key: OwnedTransactionIndexKey) -> Self {
+        Self {
+            contract_id: ContractId::new(*key.owner),
+            block_height: key.block_height,
+            tx_idx: key.tx_idx,
+        }
+    }
+}
+
+impl From<[u8; INDEX_SIZE]> for ContractTransactionIndexKey {
+    fn from(bytes: [u8; INDEX_SIZE]) -> Self {
+        OwnedTransactionIndexKey::from(bytes).into()
+    }
+}
+
+impl Encode<ContractTransactionIndexKey> for Manual<ContractTransactionIndexKey> {
+    type Encoder<'a> = [u8; INDEX_SIZE];
+
+    fn encode(t: &ContractTransactionIndexKey) -> Self::Encoder<'_> {
+        owned_tx_index_key(&t.contract_id, t.block_height, t.tx_idx)
+    }
+}
+
+impl Decode<ContractTransactionIndexKey> for Manual<ContractTransactionIndexKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractTransactionIndexKey> {
+        OwnedTransactionIndexKey::try_from(bytes)
+            .map(Into::into)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
 #[derive(Clone, Debug, PartialOrd, Eq, PartialEq)]
 pub struct OwnedTransactionIndexCursor {
     pub block_height: BlockHeight,
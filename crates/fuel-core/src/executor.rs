@@ -19,6 +19,7 @@ mod tests {
         tables::{
             Coins,
             ContractsRawCode,
+            FuelBlocks,
             Messages,
         },
         transactional::AtomicView,
@@ -1087,6 +1088,8 @@ mod tests {
                 )),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -1106,6 +1109,8 @@ mod tests {
                 )),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -1118,6 +1123,8 @@ mod tests {
             )),
             ExecutionOptions {
                 utxo_validation: true,
+                trace: false,
+                state_diff: false,
             },
         );
         assert!(matches!(
@@ -1239,6 +1246,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -1326,6 +1335,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -1390,6 +1401,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -1446,6 +1459,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2017,6 +2032,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2331,6 +2348,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .expect("block execution failed unexpectedly");
@@ -2340,6 +2359,8 @@ mod tests {
                 ExecutionBlock::Validation(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .expect("block validation failed unexpectedly");
@@ -2382,6 +2403,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2436,6 +2459,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2466,6 +2491,8 @@ mod tests {
                 ExecutionBlock::Production(block.clone().into()),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2483,6 +2510,8 @@ mod tests {
                 ExecutionBlock::Validation(block.clone()),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2495,6 +2524,8 @@ mod tests {
                 ExecutionBlock::Validation(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             );
         assert!(matches!(
@@ -2523,6 +2554,8 @@ mod tests {
                 ExecutionBlock::Production(block.clone().into()),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2540,6 +2573,8 @@ mod tests {
                 ExecutionBlock::Validation(block.clone()),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2551,6 +2586,8 @@ mod tests {
             ExecutionBlock::Validation(block),
             ExecutionOptions {
                 utxo_validation: true,
+                trace: false,
+                state_diff: false,
             },
         );
         assert!(matches!(
@@ -2581,6 +2618,8 @@ mod tests {
                 ExecutionBlock::Production(block.clone().into()),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2619,6 +2658,8 @@ mod tests {
                 )),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .unwrap();
@@ -2640,6 +2681,8 @@ mod tests {
             )),
             ExecutionOptions {
                 utxo_validation: true,
+                trace: false,
+                state_diff: false,
             },
         )
         .unwrap();
@@ -2653,6 +2696,8 @@ mod tests {
             )),
             ExecutionOptions {
                 utxo_validation: true,
+                trace: false,
+                state_diff: false,
             },
         );
         assert!(matches!(
@@ -2726,6 +2771,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .expect("Should execute the block");
@@ -2797,6 +2844,8 @@ mod tests {
                 ExecutionBlock::Production(block),
                 ExecutionOptions {
                     utxo_validation: true,
+                    trace: false,
+                    state_diff: false,
                 },
             )
             .expect("Should execute the block");
@@ -2805,6 +2854,811 @@ mod tests {
         assert_eq!(time.0, receipts[0].val().unwrap());
     }
 
+    #[test]
+    fn dry_run_executes_dependent_transactions_in_order() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let first_owner = SecretKey::random(&mut rng);
+        let second_owner = SecretKey::random(&mut rng);
+        let second_owner_address = Input::owner(&second_owner.public_key());
+
+        let first = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .script_gas_limit(10000)
+        .add_unsigned_coin_input(
+            first_owner,
+            rng.gen(),
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(second_owner_address, 400, AssetId::BASE))
+        .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+        .finalize();
+        // The second transaction spends the coin created by the first one
+        let second = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .script_gas_limit(10000)
+        .add_unsigned_coin_input(
+            second_owner,
+            UtxoId::new(first.id(&ChainId::default()), 0),
+            400,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+        .finalize();
+
+        let mut database = Database::default();
+        // The dry run at the height `1` is executed on top of the genesis block
+        database
+            .storage::<FuelBlocks>()
+            .insert(&0.into(), &Block::default())
+            .unwrap();
+        let coin_input = first.inputs()[0].clone();
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(*coin_input.input_owner().unwrap());
+        coin.set_amount(coin_input.amount().unwrap());
+        database
+            .storage::<Coins>()
+            .insert(coin_input.utxo_id().unwrap(), &coin)
+            .unwrap();
+        let executor = create_executor(database.clone(), Default::default());
+
+        let component = Components {
+            header_to_produce: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: 1.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions_source: vec![first.into(), second.into()],
+            gas_limit: u64::MAX,
+        };
+        let tx_status = executor
+            .dry_run(component, Some(0.into()), Some(true), false, false)
+            .expect("Should execute both transactions");
+
+        assert_eq!(tx_status.len(), 2);
+        for status in tx_status {
+            assert!(matches!(
+                status.result,
+                TransactionExecutionResult::Success { .. }
+            ));
+            assert!(matches!(status.receipts[0], Receipt::Return { val: 1, .. }));
+        }
+        // The dry run doesn't change the state
+        assert!(database
+            .storage::<Coins>()
+            .contains_key(coin_input.utxo_id().unwrap())
+            .unwrap());
+    }
+
+    #[test]
+    fn dry_run_of_next_block_succeeds_while_blocks_are_imported() {
+        use fuel_core_storage::transactional::Transaction as _;
+
+        // The node doesn't keep the history, so the state can't be rewound
+        let mut database = Database::default();
+        database
+            .storage::<FuelBlocks>()
+            .insert(&0.into(), &Block::default())
+            .unwrap();
+        let executor = create_executor(database.clone(), Default::default());
+
+        let importer = std::thread::spawn({
+            let database = database.clone();
+            move || {
+                for height in 1..=100u32 {
+                    let mut transaction = database.transaction();
+                    transaction
+                        .as_mut()
+                        .storage::<FuelBlocks>()
+                        .insert(&height.into(), &Block::default())
+                        .unwrap();
+                    transaction.commit().unwrap();
+                }
+            }
+        });
+
+        while !importer.is_finished() {
+            // The next block may be imported after its height is chosen for the dry run
+            let next_height = database.latest_height().unwrap().succ().unwrap();
+            let component = Components {
+                header_to_produce: PartialBlockHeader {
+                    consensus: ConsensusHeader {
+                        height: next_height,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                transactions_source: vec![Transaction::default_test_tx()],
+                gas_limit: u64::MAX,
+            };
+            executor
+                .dry_run(component, None, Some(false), false, false)
+                .expect("The dry run of the next block uses the latest state");
+        }
+        importer.join().unwrap();
+
+        // The state of the existing block can't be rewound without the history
+        let component = Components {
+            header_to_produce: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: 50.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions_source: vec![Transaction::default_test_tx()],
+            gas_limit: u64::MAX,
+        };
+        assert!(executor
+            .dry_run(component, Some(49.into()), Some(false), false, false)
+            .is_err());
+    }
+
+    #[test]
+    fn block_prefix_is_executed_the_same_way_as_the_block() {
+        use fuel_core_storage::{
+            tables::ContractsLatestUtxo,
+            StorageAsRef,
+        };
+
+        let (deploy, script) = setup_executable_script();
+        let contract_id = *script.inputs().iter().find_map(Input::contract_id).unwrap();
+        let script_id = script.id(&ChainId::default());
+        let transactions: Vec<Transaction> = vec![deploy.into(), script.into()];
+
+        let mut database = Database::default();
+        database
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&0.into(), &Block::default())
+            .unwrap();
+        let executor = create_executor(database, Default::default());
+        let header = PartialBlockHeader {
+            consensus: ConsensusHeader {
+                height: 1.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let (_, block_changes) = executor
+            .execute_without_commit(ExecutionTypes::Production(Components {
+                header_to_produce: header.clone(),
+                transactions_source: OnceTransactionsSource::new(transactions.clone()),
+                gas_limit: u64::MAX,
+            }))
+            .unwrap()
+            .into();
+        let prefix_changes = executor
+            .execute_block_prefix(header, transactions, Default::default())
+            .unwrap();
+
+        let block_state = block_changes.as_ref();
+        let prefix_state = prefix_changes.as_ref();
+        let latest_utxo = prefix_state
+            .storage_as_ref::<ContractsLatestUtxo>()
+            .get(&contract_id)
+            .unwrap();
+        assert!(latest_utxo.is_some());
+        assert_eq!(
+            latest_utxo,
+            block_state
+                .storage_as_ref::<ContractsLatestUtxo>()
+                .get(&contract_id)
+                .unwrap()
+        );
+        for index in 0..4 {
+            let utxo_id = UtxoId::new(script_id, index);
+            assert_eq!(
+                prefix_state
+                    .storage_as_ref::<Coins>()
+                    .get(&utxo_id)
+                    .unwrap(),
+                block_state.storage_as_ref::<Coins>().get(&utxo_id).unwrap()
+            );
+        }
+    }
+
+    mod parallel {
+        use super::*;
+
+        fn parallel_config() -> Config {
+            Config {
+                parallel_execution: true,
+                ..Default::default()
+            }
+        }
+
+        fn assert_same_results(sequential: &ExecutionResult, parallel: &ExecutionResult) {
+            assert_eq!(sequential.block, parallel.block);
+            assert_eq!(
+                sequential.skipped_transactions.len(),
+                parallel.skipped_transactions.len()
+            );
+            for (sequential, parallel) in sequential
+                .skipped_transactions
+                .iter()
+                .zip(parallel.skipped_transactions.iter())
+            {
+                assert_eq!(sequential.0, parallel.0);
+            }
+            assert_eq!(sequential.tx_status.len(), parallel.tx_status.len());
+            for (sequential, parallel) in
+                sequential.tx_status.iter().zip(parallel.tx_status.iter())
+            {
+                assert_eq!(sequential.id, parallel.id);
+                assert_eq!(sequential.receipts, parallel.receipts);
+            }
+        }
+
+        fn independent_transactions(count: u64) -> Vec<Transaction> {
+            (1..=count)
+                .map(|i| {
+                    TxBuilder::new(i)
+                        .script_gas_limit(10)
+                        .coin_input(AssetId::default(), i * 100)
+                        .coin_output(AssetId::default(), i * 50)
+                        .change_output(AssetId::default())
+                        .build()
+                        .transaction()
+                        .clone()
+                        .into()
+                })
+                .collect()
+        }
+
+        fn produce_in_both_modes(transactions: Vec<Transaction>) -> ExecutionResult {
+            let sequential = create_executor(Default::default(), Default::default());
+            let parallel = create_executor(Default::default(), parallel_config());
+            let block = PartialFuelBlock {
+                header: Default::default(),
+                transactions,
+            };
+
+            let sequential_result = sequential
+                .execute_and_commit(
+                    ExecutionBlock::Production(block.clone()),
+                    Default::default(),
+                )
+                .unwrap();
+            let parallel_result = parallel
+                .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+                .unwrap();
+
+            assert_same_results(&sequential_result, &parallel_result);
+            parallel_result
+        }
+
+        #[test]
+        fn independent_transactions_produce_the_same_block() {
+            // Given
+            let transactions = independent_transactions(10);
+
+            // When
+            let result = produce_in_both_modes(transactions);
+
+            // Then
+            assert!(result.skipped_transactions.is_empty());
+            assert_eq!(result.block.transactions().len(), 11);
+        }
+
+        #[test]
+        fn dependent_transactions_produce_the_same_block() {
+            // Given
+            let (create, script) = setup_executable_script();
+            let mut transactions = independent_transactions(5);
+            transactions.insert(1, create.into());
+            transactions.insert(4, script.into());
+
+            // When
+            let result = produce_in_both_modes(transactions);
+
+            // Then
+            assert!(result.skipped_transactions.is_empty());
+            assert_eq!(result.block.transactions().len(), 8);
+        }
+
+        #[test]
+        fn transaction_spending_output_of_previous_transaction_produces_the_same_block() {
+            // Given
+            let mut rng = StdRng::seed_from_u64(2322);
+            let owner: Address = rng.gen();
+            let first: Transaction = TxBuilder::new(2322u64)
+                .coin_input(AssetId::default(), 1000)
+                .coin_output(AssetId::default(), 500)
+                .change_output(AssetId::default())
+                .build()
+                .transaction()
+                .clone()
+                .into();
+            let second = TransactionBuilder::script(vec![], vec![])
+                .script_gas_limit(10)
+                .add_input(Input::coin_signed(
+                    UtxoId::new(first.id(&ChainId::default()), 0),
+                    owner,
+                    500,
+                    AssetId::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ))
+                .add_output(Output::change(owner, 0, AssetId::default()))
+                .add_witness(Default::default())
+                .finalize_as_transaction();
+
+            // When
+            let result = produce_in_both_modes(vec![first, second]);
+
+            // Then
+            assert!(result.skipped_transactions.is_empty());
+            assert_eq!(result.block.transactions().len(), 3);
+        }
+
+        #[test]
+        fn skipped_transactions_produce_the_same_block() {
+            // Given
+            let invalid = TransactionBuilder::script(vec![], vec![])
+                .add_random_fee_input()
+                .script_gas_limit(1000000)
+                .gas_price(1000000)
+                .finalize_as_transaction();
+            let (create, script) = setup_executable_script();
+            let mut transactions = vec![invalid, create.into(), script.into()];
+            transactions.extend(independent_transactions(3));
+
+            // When
+            let result = produce_in_both_modes(transactions);
+
+            // Then
+            assert_eq!(result.skipped_transactions.len(), 1);
+            assert_eq!(result.block.transactions().len(), 6);
+        }
+
+        #[test]
+        fn block_produced_sequentially_is_valid_in_parallel_mode() {
+            // Given
+            let producer = create_executor(Default::default(), Default::default());
+            let verifier = create_executor(Default::default(), parallel_config());
+            let (create, script) = setup_executable_script();
+            let mut transactions = independent_transactions(10);
+            transactions.push(create.into());
+            transactions.push(script.into());
+            let block = PartialFuelBlock {
+                header: Default::default(),
+                transactions,
+            };
+            let ExecutionResult { block, .. } = producer
+                .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+                .unwrap();
+
+            // When
+            let validation_result = verifier.execute_and_commit(
+                ExecutionBlock::Validation(block),
+                Default::default(),
+            );
+
+            // Then
+            assert!(validation_result.is_ok());
+        }
+    }
+
+    mod trace {
+        use super::*;
+        use fuel_core_types::{
+            fuel_asm::Instruction,
+            services::executor::{
+                StorageAccess,
+                StorageAccessKind,
+                TransactionTrace,
+            },
+        };
+
+        fn trace_options() -> ExecutionOptions {
+            ExecutionOptions {
+                utxo_validation: false,
+                trace: true,
+                state_diff: false,
+            }
+        }
+
+        /// Returns the block that deploys the contract and calls it. The contract writes
+        /// to the storage slot with the key equal to its own id, taken from the call frame.
+        fn contract_writing_storage_block() -> (PartialFuelBlock, ContractId) {
+            let mut rng = StdRng::seed_from_u64(2322u64);
+            let (create, contract_id) = create_contract(
+                vec![op::sww(RegId::FP, 0x29, RegId::ONE), op::ret(1)]
+                    .into_iter()
+                    .collect::<Vec<u8>>(),
+                &mut rng,
+            );
+
+            let (script, _) = script_with_data_offset!(
+                data_offset,
+                vec![
+                    op::movi(0x10, data_offset),
+                    op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                    op::ret(RegId::ONE),
+                ],
+                TxParameters::DEFAULT.tx_offset()
+            );
+            let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+            let script = TxBuilder::new(2322)
+                .script_gas_limit(10000)
+                .start_script(script, script_data)
+                .contract_input(contract_id)
+                .coin_input(AssetId::zeroed(), 10000)
+                .contract_output(&contract_id)
+                .build()
+                .transaction()
+                .clone();
+
+            let block = PartialFuelBlock {
+                header: PartialBlockHeader {
+                    consensus: ConsensusHeader {
+                        height: 1.into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                transactions: vec![create.into(), script.into()],
+            };
+            (block, contract_id)
+        }
+
+        fn opcodes(trace: &TransactionTrace) -> Vec<String> {
+            trace
+                .steps
+                .iter()
+                .map(|step| {
+                    format!(
+                        "{:?}",
+                        Instruction::try_from(step.instruction).unwrap().opcode()
+                    )
+                })
+                .collect()
+        }
+
+        #[test]
+        fn trace_is_not_collected_by_default() {
+            // Given
+            let (block, _) = contract_writing_storage_block();
+            let executor = create_executor(Database::default(), Default::default());
+
+            // When
+            let ExecutionResult { tx_status, .. } = executor
+                .execute_and_commit(
+                    ExecutionBlock::Production(block),
+                    ExecutionOptions {
+                        utxo_validation: false,
+                        trace: false,
+                        state_diff: false,
+                    },
+                )
+                .unwrap();
+
+            // Then
+            assert!(tx_status.iter().all(|status| status.trace.is_none()));
+        }
+
+        #[test]
+        fn trace_contains_all_executed_instructions() {
+            // Given
+            let (block, _) = contract_writing_storage_block();
+            let executor = create_executor(Database::default(), Default::default());
+
+            // When
+            let ExecutionResult { tx_status, .. } = executor
+                .execute_and_commit(ExecutionBlock::Production(block), trace_options())
+                .unwrap();
+
+            // Then
+            let create_trace = tx_status[0].trace.as_ref().unwrap();
+            assert!(create_trace.steps.is_empty());
+            let script_trace = tx_status[1].trace.as_ref().unwrap();
+            assert_eq!(
+                opcodes(script_trace),
+                vec!["MOVI", "CALL", "SWW", "RET", "RET"]
+            );
+            assert!(script_trace.steps.iter().all(|step| step.gas_used > 0));
+            assert!(script_trace
+                .steps
+                .iter()
+                .all(|step| step.panic_reason.is_none()));
+            // `MOVI` changes the program counter and the register `0x10`.
+            let movi = &script_trace.steps[0];
+            assert_eq!(movi.changed_registers.len(), 4);
+            assert!(movi
+                .changed_registers
+                .iter()
+                .any(|(register, _)| *register == 0x10));
+        }
+
+        #[test]
+        fn trace_contains_call_frames_and_storage_access() {
+            // Given
+            let (block, contract_id) = contract_writing_storage_block();
+            let executor = create_executor(Database::default(), Default::default());
+
+            // When
+            let ExecutionResult { tx_status, .. } = executor
+                .execute_and_commit(ExecutionBlock::Production(block), trace_options())
+                .unwrap();
+
+            // Then
+            let steps = &tx_status[1].trace.as_ref().unwrap().steps;
+            let frames = steps
+                .iter()
+                .map(|step| step.call_frames.len())
+                .collect_vec();
+            assert_eq!(frames, vec![0, 0, 1, 1, 0]);
+            assert_eq!(steps[2].call_frames[0].contract_id, contract_id);
+            assert_eq!(
+                steps[2].storage_access,
+                Some(StorageAccess {
+                    contract_id,
+                    key: Bytes32::new(*contract_id),
+                    slots: 1,
+                    kind: StorageAccessKind::Write,
+                })
+            );
+            assert!(steps
+                .iter()
+                .enumerate()
+                .all(|(i, step)| (i == 2) == step.storage_access.is_some()));
+        }
+
+        #[test]
+        fn trace_does_not_affect_the_result_of_execution() {
+            // Given
+            let (block, _) = contract_writing_storage_block();
+            let executor = create_executor(Database::default(), Default::default());
+            let tracing_executor =
+                create_executor(Database::default(), Default::default());
+
+            // When
+            let result = executor
+                .execute_and_commit(
+                    ExecutionBlock::Production(block.clone()),
+                    ExecutionOptions {
+                        utxo_validation: false,
+                        trace: false,
+                        state_diff: false,
+                    },
+                )
+                .unwrap();
+            let traced_result = tracing_executor
+                .execute_and_commit(ExecutionBlock::Production(block), trace_options())
+                .unwrap();
+
+            // Then
+            assert_eq!(result.block, traced_result.block);
+            assert_eq!(
+                result.tx_status[1].receipts,
+                traced_result.tx_status[1].receipts
+            );
+        }
+
+        #[test]
+        fn trace_longer_than_the_limit_fails_the_execution() {
+            // Given
+            let (block, _) = contract_writing_storage_block();
+            let script_id = block.transactions[1].id(&ChainId::default());
+            let executor = create_executor(
+                Database::default(),
+                Config {
+                    max_trace_steps: 3,
+                    ..Default::default()
+                },
+            );
+
+            // When
+            let result = executor
+                .execute_and_commit(ExecutionBlock::Production(block), trace_options())
+                .unwrap();
+
+            // Then
+            let [(skipped_id, error)] = result.skipped_transactions.as_slice() else {
+                panic!("The script should be skipped");
+            };
+            assert_eq!(*skipped_id, script_id);
+            assert!(matches!(
+                error,
+                ExecutorError::TraceTruncated { max_steps: 3, .. }
+            ));
+        }
+    }
+
+    mod state_diff {
+        use super::*;
+        use fuel_core_types::{
+            fuel_asm::Instruction,
+            services::executor::{
+                BalanceChange,
+                StorageSlotChange,
+            },
+        };
+
+        const FORWARDED_AMOUNT: Word = 100;
+
+        fn state_diff_options() -> ExecutionOptions {
+            ExecutionOptions {
+                utxo_validation: false,
+                trace: false,
+                state_diff: true,
+            }
+        }
+
+        /// Returns the block that deploys the contract and calls it with coins. The contract
+        /// writes `1` to the storage slot with the key equal to its own id and runs `last_op`.
+        fn contract_call_block(last_op: Instruction) -> (PartialFuelBlock, ContractId) {
+            let mut rng = StdRng::seed_from_u64(2322u64);
+            let (create, contract_id) = create_contract(
+                vec![op::sww(RegId::FP, 0x29, RegId::ONE), last_op]
+                    .into_iter()
+                    .collect::<Vec<u8>>(),
+                &mut rng,
+            );
+
+            let (script, _) = script_with_data_offset!(
+                data_offset,
+                vec![
+                    op::movi(0x10, data_offset + AssetId::LEN as u32),
+                    op::movi(0x11, data_offset),
+                    op::movi(0x12, FORWARDED_AMOUNT as u32),
+                    op::call(0x10, 0x12, 0x11, RegId::CGAS),
+                    op::ret(RegId::ONE),
+                ],
+                TxParameters::DEFAULT.tx_offset()
+            );
+            let script_data: Vec<u8> = AssetId::zeroed()
+                .iter()
+                .copied()
+                .chain(Call::new(contract_id, 0, 0).to_bytes())
+                .collect();
+
+            let script = TxBuilder::new(2322)
+                .script_gas_limit(10000)
+                .start_script(script, script_data)
+                .contract_input(contract_id)
+                .coin_input(AssetId::zeroed(), 10000)
+                .contract_output(&contract_id)
+                .change_output(AssetId::zeroed())
+                .build()
+                .transaction()
+                .clone();
+
+            let block = PartialFuelBlock {
+                header: PartialBlockHeader {
+                    consensus: ConsensusHeader {
+                        height: 1.into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                transactions: vec![create.into(), script.into()],
+            };
+            (block, contract_id)
+        }
+
+        #[test]
+        fn state_diff_is_not_recorded_by_default() {
+            // Given
+            let (block, _) = contract_call_block(op::ret(1));
+            let executor = create_executor(Database::default(), Default::default());
+
+            // When
+            let ExecutionResult { tx_status, .. } = executor
+                .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+                .unwrap();
+
+            // Then
+            assert!(tx_status.iter().all(|status| status.state_diff.is_none()));
+        }
+
+        #[test]
+        fn state_diff_contains_spent_and_created_coins() {
+            // Given
+            let (block, _) = contract_call_block(op::ret(1));
+            let executor = create_executor(Database::default(), Default::default());
+
+            // When
+            let ExecutionResult {
+                block, tx_status, ..
+            } = executor
+                .execute_and_commit(
+                    ExecutionBlock::Production(block),
+                    state_diff_options(),
+                )
+                .unwrap();
+
+            // Then
+            let script = block.transactions()[1].as_script().unwrap();
+            let state_diff = tx_status[1].state_diff.as_ref().unwrap();
+            assert_eq!(
+                state_diff.coins_spent,
+                vec![*script.inputs()[1].utxo_id().unwrap()]
+            );
+            assert_eq!(state_diff.coins_created.len(), 1);
+            let change = &state_diff.coins_created[0];
+            assert_eq!(change.utxo_id, UtxoId::new(tx_status[1].id, 1));
+            assert_eq!(change.amount, 10000 - FORWARDED_AMOUNT);
+            assert!(state_diff.messages_consumed.is_empty());
+        }
+
+        #[test]
+        fn state_diff_contains_contract_storage_and_balance_changes() {
+            // Given
+            let (block, contract_id) = contract_call_block(op::ret(1));
+            let executor = create_executor(Database::default(), Default::default());
+
+            // When
+            let ExecutionResult { tx_status, .. } = executor
+                .execute_and_commit(
+                    ExecutionBlock::Production(block),
+                    state_diff_options(),
+                )
+                .unwrap();
+
+            // Then
+            let state_diff = tx_status[1].state_diff.as_ref().unwrap();
+            let mut value = Bytes32::zeroed();
+            value[..8].copy_from_slice(&1u64.to_be_bytes());
+            assert_eq!(
+                state_diff.storage_changes,
+                vec![StorageSlotChange {
+                    contract_id,
+                    key: Bytes32::new(*contract_id),
+                    previous: None,
+                    current: Some(value),
+                }]
+            );
+            assert_eq!(
+                state_diff.balance_changes,
+                vec![BalanceChange {
+                    contract_id,
+                    asset_id: AssetId::zeroed(),
+                    previous: 0,
+                    current: FORWARDED_AMOUNT,
+                }]
+            );
+        }
+
+        #[test]
+        fn state_diff_of_reverted_transaction_contains_only_spent_inputs() {
+            // Given
+            let (block, _) = contract_call_block(op::rvrt(RegId::ONE));
+            let executor = create_executor(Database::default(), Default::default());
+
+            // When
+            let ExecutionResult { tx_status, .. } = executor
+                .execute_and_commit(
+                    ExecutionBlock::Production(block),
+                    state_diff_options(),
+                )
+                .unwrap();
+
+            // Then
+            assert!(matches!(
+                tx_status[1].result,
+                TransactionExecutionResult::Failed { .. }
+            ));
+            let state_diff = tx_status[1].state_diff.as_ref().unwrap();
+            assert_eq!(state_diff.coins_spent.len(), 1);
+            assert!(state_diff.storage_changes.is_empty());
+            assert!(state_diff.balance_changes.is_empty());
+        }
+    }
+
     #[cfg(feature = "relayer")]
     mod relayer {
         use super::*;
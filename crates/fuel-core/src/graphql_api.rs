@@ -1,3 +1,18 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::OffChainDatabase,
+    },
+    service::config::{
+        ApiLimitsConfig,
+        IndexesConfig,
+    },
+};
+use async_graphql::{
+    Context,
+    ErrorExtensions,
+};
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
@@ -6,13 +21,19 @@ use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_tx::ConsensusParameters,
     secrecy::Secret,
+    services::graphql_api::{
+        IndexState,
+        OffChainIndex,
+    },
 };
 use std::net::SocketAddr;
 
 pub mod api_service;
 pub mod database;
+pub(crate) mod limits_extension;
 pub(crate) mod metrics_extension;
 pub mod ports;
+pub(crate) mod rate_limit;
 pub(crate) mod view_extension;
 pub mod worker_service;
 
@@ -27,6 +48,8 @@ pub struct Config {
     pub max_depth: usize,
     pub consensus_parameters: ConsensusParameters,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    pub limits: ApiLimitsConfig,
+    pub indexes: IndexesConfig,
 }
 
 pub trait IntoApiResult<T> {
@@ -49,3 +72,61 @@ impl<T> IntoApiResult<T> for Result<T, StorageError> {
         }
     }
 }
+
+/// Creates the error returned when the request exceeds one of the API limits.
+/// The `code` is available in the extensions of the error and used as a label of the metric.
+pub(crate) fn limit_exceeded(
+    code: &'static str,
+    message: String,
+) -> async_graphql::Error {
+    graphql_metrics().graphql_reject(code);
+    async_graphql::Error::new(message).extend_with(|_, e| e.set("code", code))
+}
+
+/// Returns an error if the off-chain `index` used by the query is disabled on the node
+/// or is not built yet.
+pub(crate) fn require_index(
+    ctx: &Context<'_>,
+    index: OffChainIndex,
+) -> async_graphql::Result<()> {
+    let config: &Config = ctx.data_unchecked();
+    if !config.indexes.is_enabled(index) {
+        return Err(anyhow::anyhow!("The `{index}` index is disabled on the node").into())
+    }
+
+    let query: &ReadView = ctx.data_unchecked();
+    match query
+        .index_state(index)
+        .into_api_result::<IndexState, StorageError>()?
+    {
+        Some(IndexState::Ready { .. }) => Ok(()),
+        Some(IndexState::Backfilling { next_height }) => Err(anyhow::anyhow!(
+            "The `{index}` index is being backfilled from the stored blocks, \
+            the blocks below the height {next_height} are indexed"
+        )
+        .into()),
+        Some(IndexState::ScanningCoins { .. }) => Err(anyhow::anyhow!(
+            "The `{index}` index is being built from the unspent coins"
+        )
+        .into()),
+        None => Err(anyhow::anyhow!("The `{index}` index is not built yet").into()),
+    }
+}
+
+/// Returns `true` if the off-chain `index` is enabled on the node and is built,
+/// so the query can use it instead of the slower fallback.
+pub(crate) fn is_index_ready(
+    ctx: &Context<'_>,
+    index: OffChainIndex,
+) -> async_graphql::Result<bool> {
+    let config: &Config = ctx.data_unchecked();
+    if !config.indexes.is_enabled(index) {
+        return Ok(false)
+    }
+
+    let query: &ReadView = ctx.data_unchecked();
+    let state = query
+        .index_state(index)
+        .into_api_result::<IndexState, StorageError>()?;
+    Ok(matches!(state, Some(IndexState::Ready { .. })))
+}
@@ -1,15 +1,19 @@
 use crate::{
     fuel_core_graphql_api::{
+        limits_extension::LimitsExtension,
         metrics_extension::MetricsExtension,
         ports::{
             BlockProducerPort,
             ConsensusModulePort,
+            HealthPort,
             OffChainDatabase,
             OnChainDatabase,
             P2pPort,
             TxPoolPort,
         },
+        rate_limit::RateLimiter,
         view_extension::ViewExtension,
+        worker_service,
         Config,
     },
     schema::{
@@ -27,18 +31,22 @@ use async_graphql::{
     Response,
 };
 use axum::{
+    body::Body,
     extract::{
         DefaultBodyLimit,
         Extension,
     },
     http::{
+        self,
         header::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
         },
         HeaderValue,
+        StatusCode,
     },
+    middleware,
     response::{
         sse::Event,
         Html,
@@ -68,6 +76,7 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
     time::Duration,
 };
 use tokio_stream::StreamExt;
@@ -87,6 +96,8 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+pub type HealthCheck = Arc<dyn HealthPort>;
+pub type OffChainWorker = worker_service::SharedState;
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -131,7 +142,7 @@ impl RunnableService for GraphqlService {
 
         let server = axum::Server::from_tcp(listener)
             .unwrap()
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .with_graceful_shutdown(async move {
                 state
                     .while_started()
@@ -173,6 +184,8 @@ pub fn new_service<OnChain, OffChain>(
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
+    health_check: HealthCheck,
+    off_chain_worker: OffChainWorker,
     log_threshold_ms: Duration,
     request_timeout: Duration,
 ) -> anyhow::Result<Service>
@@ -183,6 +196,7 @@ where
     OffChain::View: OffChainDatabase,
 {
     let network_addr = config.addr;
+    let limits = config.limits.clone();
     let combined_read_database = ReadDatabase::new(on_database, off_database);
 
     let schema = schema
@@ -192,21 +206,40 @@ where
         .data(producer)
         .data(consensus_module)
         .data(p2p_service)
+        .data(health_check.clone())
+        .data(off_chain_worker)
         .extension(async_graphql::extensions::Tracing)
         .extension(MetricsExtension::new(log_threshold_ms))
+        .extension(LimitsExtension::new(
+            limits.max_depth,
+            limits.max_complexity,
+        ))
         .extension(ViewExtension::new())
         .finish();
 
-    let router = Router::new()
+    let mut router = Router::new()
         .route("/playground", get(graphql_playground))
         .route("/graphql", post(graphql_handler).options(ok))
         .route(
             "/graphql-sub",
             post(graphql_subscription_handler).options(ok),
-        )
+        );
+    if let Some(rate_limit) = limits.rate_limit {
+        // Only GraphQL requests are limited, the probes and metrics are always available.
+        let rate_limiter = RateLimiter::new(rate_limit);
+        router = router.route_layer(middleware::from_fn(
+            move |request: http::Request<Body>, next: middleware::Next<Body>| {
+                rate_limiter.clone().handle(request, next)
+            },
+        ));
+    }
+
+    let router = router
         .route("/metrics", get(metrics))
         .route("/health", get(health))
+        .route("/health/ready", get(readiness))
         .layer(Extension(schema))
+        .layer(Extension(health_check))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -242,6 +275,19 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+async fn readiness(health_check: Extension<HealthCheck>) -> impl IntoResponse {
+    let report = health_check.readiness().await;
+    let status = if report.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        status,
+        Json(json!({ "ready": report.is_ready(), "report": report })),
+    )
+}
+
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
     req: Json<Request>,
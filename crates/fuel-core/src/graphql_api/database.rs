@@ -43,17 +43,26 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         AssetId,
+        Bytes32,
         TxPointer,
         UtxoId,
     },
     fuel_types::{
         BlockHeight,
         Nonce,
+        Word,
     },
     services::{
-        graphql_api::ContractBalance,
+        executor::StateDiff,
+        graphql_api::{
+            AssetDetails,
+            ContractBalance,
+            IndexState,
+            OffChainIndex,
+        },
         txpool::TransactionStatus,
     },
+    tai64::Tai64,
 };
 use std::{
     borrow::Cow,
@@ -228,10 +237,24 @@ impl OffChainDatabase for ReadView {
         self.off_chain.owned_coins_ids(owner, start_coin, direction)
     }
 
+    fn owned_coins_ids_by_amount(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Word, UtxoId)>> {
+        self.off_chain
+            .owned_coins_ids_by_amount(owner, asset_id, direction)
+    }
+
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
         self.off_chain.tx_status(tx_id)
     }
 
+    fn tx_state_diff(&self, tx_id: &TxId) -> StorageResult<Option<StateDiff>> {
+        self.off_chain.tx_state_diff(tx_id)
+    }
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
@@ -241,4 +264,58 @@ impl OffChainDatabase for ReadView {
         self.off_chain
             .owned_transactions_ids(owner, start, direction)
     }
+
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        self.off_chain
+            .contract_transactions_ids(contract_id, start, direction)
+    }
+
+    fn predicate_transactions_ids(
+        &self,
+        predicate_root: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        self.off_chain
+            .predicate_transactions_ids(predicate_root, start, direction)
+    }
+
+    fn script_transactions_ids(
+        &self,
+        script_hash: Bytes32,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        self.off_chain
+            .script_transactions_ids(script_hash, start, direction)
+    }
+
+    fn asset_details(&self, asset_id: &AssetId) -> StorageResult<AssetDetails> {
+        self.off_chain.asset_details(asset_id)
+    }
+
+    fn assets(
+        &self,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, AssetDetails)>> {
+        self.off_chain.assets(start_asset, direction)
+    }
+
+    fn block_height_by_time(
+        &self,
+        time: Tai64,
+        direction: IterDirection,
+    ) -> StorageResult<BlockHeight> {
+        self.off_chain.block_height_by_time(time, direction)
+    }
+
+    fn index_state(&self, index: OffChainIndex) -> StorageResult<IndexState> {
+        self.off_chain.index_state(index)
+    }
 }
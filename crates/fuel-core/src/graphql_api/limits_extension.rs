@@ -0,0 +1,74 @@
+use crate::fuel_core_graphql_api::limit_exceeded;
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextValidation,
+    },
+    Pos,
+    ServerError,
+    ValidationResult,
+};
+use std::sync::Arc;
+
+pub(crate) const QUERY_TOO_DEEP: &str = "QUERY_TOO_DEEP";
+pub(crate) const QUERY_TOO_COMPLEX: &str = "QUERY_TOO_COMPLEX";
+
+/// The extension rejects queries that exceed the depth or complexity limits
+/// before their execution.
+#[derive(Clone, Copy)]
+pub(crate) struct LimitsExtension {
+    max_depth: usize,
+    max_complexity: usize,
+}
+
+impl LimitsExtension {
+    pub fn new(max_depth: usize, max_complexity: usize) -> Self {
+        Self {
+            max_depth,
+            max_complexity,
+        }
+    }
+}
+
+impl ExtensionFactory for LimitsExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(*self)
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for LimitsExtension {
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let result = next.run(ctx).await?;
+
+        if result.depth > self.max_depth {
+            let error = limit_exceeded(
+                QUERY_TOO_DEEP,
+                format!(
+                    "The query depth {} exceeds the limit {}",
+                    result.depth, self.max_depth
+                ),
+            );
+            return Err(vec![error.into_server_error(Pos::default())])
+        }
+
+        if result.complexity > self.max_complexity {
+            let error = limit_exceeded(
+                QUERY_TOO_COMPLEX,
+                format!(
+                    "The query complexity {} exceeds the limit {}",
+                    result.complexity, self.max_complexity
+                ),
+            );
+            return Err(vec![error.into_server_error(Pos::default())])
+        }
+
+        Ok(result)
+    }
+}
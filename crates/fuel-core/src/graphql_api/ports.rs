@@ -29,12 +29,14 @@ use fuel_core_types::{
             DaBlockHeight,
         },
     },
-    entities::message::{
-        MerkleProof,
-        Message,
+    entities::{
+        coins::CoinId,
+        message::{
+            MerkleProof,
+            Message,
+        },
     },
     fuel_tx::{
-        Receipt,
         Transaction,
         TxId,
         TxPointer,
@@ -44,11 +46,23 @@ use fuel_core_types::{
         Address,
         AssetId,
         BlockHeight,
+        Bytes32,
         ContractId,
         Nonce,
+        Word,
     },
     services::{
-        graphql_api::ContractBalance,
+        executor::{
+            StateDiff,
+            TransactionExecutionStatus,
+        },
+        graphql_api::{
+            AssetDetails,
+            ContractBalance,
+            IndexState,
+            OffChainIndex,
+        },
+        health::ReadinessReport,
         p2p::PeerInfo,
         txpool::{
             InsertionResult,
@@ -76,14 +90,74 @@ pub trait OffChainDatabase:
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<UtxoId>>;
 
+    /// Returns the amounts and ids of the `owner`'s coins with the `asset_id`
+    /// ordered by the amount in the `direction`.
+    fn owned_coins_ids_by_amount(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Word, UtxoId)>>;
+
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
+    /// Returns the changes of the state made by the transaction, if they were recorded.
+    fn tx_state_diff(&self, tx_id: &TxId) -> StorageResult<Option<StateDiff>>;
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the ids of transactions that use the contract as an input,
+    /// ordered by the position of the transaction in the chain.
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the ids of transactions that spend from the predicate with the root,
+    /// ordered by the position of the transaction in the chain.
+    fn predicate_transactions_ids(
+        &self,
+        predicate_root: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the ids of transactions with the script of the hash or that deploy
+    /// the bytecode with the root, ordered by the position of the transaction in the chain.
+    fn script_transactions_ids(
+        &self,
+        script_hash: Bytes32,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the information about the asset aggregated by the off-chain worker.
+    fn asset_details(&self, asset_id: &AssetId) -> StorageResult<AssetDetails>;
+
+    fn assets(
+        &self,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, AssetDetails)>>;
+
+    /// Returns the height of the first block produced at or after the `time`
+    /// for the forward `direction`, or the last block produced at or before
+    /// the `time` for the reverse `direction`.
+    fn block_height_by_time(
+        &self,
+        time: Tai64,
+        direction: IterDirection,
+    ) -> StorageResult<BlockHeight>;
+
+    /// Returns the state of the off-chain `index` recorded by the worker.
+    fn index_state(&self, index: OffChainIndex) -> StorageResult<IndexState>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -165,16 +239,21 @@ pub trait TxPoolPort: Send + Sync {
         &self,
         tx_id: TxId,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>>;
+
+    /// Returns the ids of coins and messages used as inputs by the pending transactions.
+    fn spent_inputs(&self) -> Vec<CoinId>;
 }
 
 #[async_trait]
 pub trait BlockProducerPort: Send + Sync {
-    async fn dry_run_tx(
+    async fn dry_run_txs(
         &self,
-        transaction: Transaction,
+        transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
-    ) -> anyhow::Result<Vec<Receipt>>;
+        trace: bool,
+        state_diff: bool,
+    ) -> anyhow::Result<Vec<TransactionExecutionStatus>>;
 }
 
 #[async_trait::async_trait]
@@ -202,9 +281,16 @@ pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
 }
 
+#[async_trait::async_trait]
+pub trait HealthPort: Send + Sync {
+    /// Collects the readiness report of the node.
+    async fn readiness(&self) -> ReadinessReport;
+}
+
 pub mod worker {
     use fuel_core_services::stream::BoxStream;
     use fuel_core_storage::{
+        iter::BoxedIter,
         tables::Receipts,
         transactional::Transactional,
         Error as StorageError,
@@ -212,20 +298,37 @@ pub mod worker {
         StorageMutate,
     };
     use fuel_core_types::{
+        blockchain::block::Block,
+        entities::coins::coin::CompressedCoin,
         fuel_tx::{
             Address,
+            AssetId,
             Bytes32,
+            ContractId,
+            UtxoId,
+        },
+        fuel_types::{
+            BlockHeight,
+            ChainId,
+            Word,
         },
-        fuel_types::BlockHeight,
         services::{
             block_importer::SharedImportResult,
+            executor::StateDiff,
+            graphql_api::{
+                IndexState,
+                OffChainIndex,
+            },
             txpool::TransactionStatus,
         },
+        tai64::Tai64,
     };
 
     pub trait OffChainDatabase:
-        Send
+        Clone
+        + Send
         + Sync
+        + 'static
         + StorageMutate<Receipts, Error = StorageError>
         + Transactional<Storage = Self>
     {
@@ -237,11 +340,142 @@ pub mod worker {
             tx_id: &Bytes32,
         ) -> StorageResult<Option<Bytes32>>;
 
+        /// Associates the transaction with the contract used by it as an input.
+        fn record_tx_id_contract(
+            &mut self,
+            contract_id: &ContractId,
+            block_height: BlockHeight,
+            tx_idx: u16,
+            tx_id: &Bytes32,
+        ) -> StorageResult<Option<Bytes32>>;
+
+        /// Associates the transaction with the root of the predicate it spends from.
+        fn record_tx_id_predicate_root(
+            &mut self,
+            predicate_root: &Address,
+            block_height: BlockHeight,
+            tx_idx: u16,
+            tx_id: &Bytes32,
+        ) -> StorageResult<Option<Bytes32>>;
+
+        /// Associates the transaction with the hash of its script
+        /// or the root of the bytecode deployed by it.
+        fn record_tx_id_script_hash(
+            &mut self,
+            script_hash: &Bytes32,
+            block_height: BlockHeight,
+            tx_idx: u16,
+            tx_id: &Bytes32,
+        ) -> StorageResult<Option<Bytes32>>;
+
+        /// Adds the coin to the index of the `owner`'s coins ordered by the amount.
+        fn record_owned_coin_amount(
+            &mut self,
+            owner: &Address,
+            asset_id: &AssetId,
+            amount: Word,
+            utxo_id: &UtxoId,
+        ) -> StorageResult<()>;
+
+        /// Removes the spent coin from the index of the `owner`'s coins ordered by the amount.
+        fn remove_owned_coin_amount(
+            &mut self,
+            owner: &Address,
+            asset_id: &AssetId,
+            amount: Word,
+            utxo_id: &UtxoId,
+        ) -> StorageResult<()>;
+
+        /// Adds the amount minted by the contract to the details of the asset.
+        fn record_asset_mint(
+            &mut self,
+            contract_id: &ContractId,
+            sub_id: &Bytes32,
+            amount: Word,
+        ) -> StorageResult<()>;
+
+        /// Adds the amount burned by the contract to the details of the asset.
+        fn record_asset_burn(
+            &mut self,
+            contract_id: &ContractId,
+            sub_id: &Bytes32,
+            amount: Word,
+        ) -> StorageResult<()>;
+
+        /// Adds the `owner` of the created coin to the holders of the asset.
+        fn record_asset_holder(
+            &mut self,
+            owner: &Address,
+            asset_id: &AssetId,
+        ) -> StorageResult<()>;
+
+        /// Removes the `owner` of the spent coin from the holders of the asset
+        /// if it doesn't have other coins of the asset.
+        fn remove_asset_holder(
+            &mut self,
+            owner: &Address,
+            asset_id: &AssetId,
+        ) -> StorageResult<()>;
+
+        /// Links the time of the block to its height.
+        fn record_block_time(
+            &mut self,
+            time: Tai64,
+            height: BlockHeight,
+        ) -> StorageResult<()>;
+
         fn update_tx_status(
             &mut self,
             id: &Bytes32,
             status: TransactionStatus,
         ) -> StorageResult<Option<TransactionStatus>>;
+
+        fn update_tx_state_diff(
+            &mut self,
+            id: &Bytes32,
+            state_diff: &StateDiff,
+        ) -> StorageResult<Option<StateDiff>>;
+
+        /// Returns the recorded status of the transaction.
+        fn get_tx_status(&self, id: &Bytes32)
+            -> StorageResult<Option<TransactionStatus>>;
+
+        /// Returns the state of the off-chain `index`.
+        fn index_state(&self, index: OffChainIndex) -> StorageResult<Option<IndexState>>;
+
+        /// Records the state of the off-chain `index`.
+        fn record_index_state(
+            &mut self,
+            index: OffChainIndex,
+            state: &IndexState,
+        ) -> StorageResult<()>;
+
+        /// Removes the state of the disabled off-chain `index`.
+        fn remove_index_state(&mut self, index: OffChainIndex) -> StorageResult<()>;
+
+        /// Removes the entries of the `index` that can't be rebuilt on top of the existing ones.
+        fn reset_index(&mut self, index: OffChainIndex) -> StorageResult<()>;
+
+        /// Returns the height of the first block stored on the chain.
+        fn first_block_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+        /// Returns the height of the latest block stored on the chain.
+        fn latest_block_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+        /// Returns the block stored on the chain at the `height` with its transactions.
+        /// The ids of transactions are cached for the `chain_id`.
+        fn full_block(
+            &self,
+            height: &BlockHeight,
+            chain_id: &ChainId,
+        ) -> StorageResult<Option<Block>>;
+
+        /// Returns the unspent coins stored on the chain ordered by the id,
+        /// starting from the `start` id.
+        fn unspent_coins(
+            &self,
+            start: &UtxoId,
+        ) -> BoxedIter<'_, StorageResult<(UtxoId, CompressedCoin)>>;
     }
 
     pub trait BlockImporter {
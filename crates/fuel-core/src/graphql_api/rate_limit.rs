@@ -0,0 +1,284 @@
+use crate::{
+    fuel_core_graphql_api::limit_exceeded,
+    service::config::RateLimitConfig,
+};
+use async_graphql::Response as GraphQLResponse;
+use axum::{
+    extract::ConnectInfo,
+    http::{
+        header::RETRY_AFTER,
+        HeaderValue,
+        Request,
+        StatusCode,
+    },
+    middleware::Next,
+    response::{
+        IntoResponse,
+        Response,
+    },
+    Json,
+};
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::{
+    net::{
+        IpAddr,
+        SocketAddr,
+    },
+    num::NonZeroUsize,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+pub(crate) const RATE_LIMITED: &str = "RATE_LIMITED";
+
+/// The header with the API key of the client.
+pub(crate) const API_KEY_HEADER: &str = "x-api-key";
+
+/// The max number of tracked clients. The least recently seen client is forgotten
+/// when a new one arrives, so it starts again with the full bucket.
+const MAX_TRACKED_CLIENTS: usize = 100_000;
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+enum ClientKey {
+    ApiKey(String),
+    Ip(IpAddr),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// The token bucket rate limiter. Each client has a bucket with `requests` tokens
+/// that is refilled evenly during the `period`. Each request takes one token.
+#[derive(Clone)]
+pub(crate) struct RateLimiter {
+    config: Arc<RateLimitConfig>,
+    buckets: Arc<Mutex<LruCache<ClientKey, Bucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self::with_capacity(config, MAX_TRACKED_CLIENTS)
+    }
+
+    fn with_capacity(config: RateLimitConfig, max_clients: usize) -> Self {
+        let max_clients =
+            NonZeroUsize::new(max_clients).expect("The capacity is not zero; qed");
+        Self {
+            config: Arc::new(config),
+            buckets: Arc::new(Mutex::new(LruCache::new(max_clients))),
+        }
+    }
+
+    /// Rejects the request with `429 Too Many Requests` if the client exceeded the limit.
+    pub async fn handle<B>(self, request: Request<B>, next: Next<B>) -> Response {
+        if let Some(key) = self.client_key(&request) {
+            if let Err(retry_after) = self.check(key, Instant::now()) {
+                return self.reject(retry_after)
+            }
+        }
+        next.run(request).await
+    }
+
+    fn client_key<B>(&self, request: &Request<B>) -> Option<ClientKey> {
+        let api_key = request
+            .headers()
+            .get(API_KEY_HEADER)
+            .and_then(|key| key.to_str().ok())
+            .filter(|key| self.config.api_keys.contains(*key));
+
+        if let Some(api_key) = api_key {
+            return Some(ClientKey::ApiKey(api_key.to_string()))
+        }
+
+        let forwarded_ip = self
+            .config
+            .forwarded_for_header
+            .as_ref()
+            .and_then(|header| request.headers().get(header))
+            .and_then(|value| value.to_str().ok())
+            .and_then(forwarded_ip);
+
+        if let Some(ip) = forwarded_ip {
+            return Some(ClientKey::Ip(ip))
+        }
+
+        request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(address)| ClientKey::Ip(address.ip()))
+    }
+
+    fn refill_rate(&self) -> f64 {
+        f64::from(self.config.requests) / self.config.period.as_secs_f64()
+    }
+
+    /// Takes the token from the bucket of the client.
+    /// Returns the time after which the token is available if the bucket is empty.
+    fn check(&self, key: ClientKey, now: Instant) -> Result<(), Duration> {
+        let capacity = f64::from(self.config.requests);
+        let rate = self.refill_rate();
+        let refill = |bucket: &mut Bucket| {
+            let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * rate).min(capacity);
+            bucket.updated = now;
+        };
+
+        let mut buckets = self.buckets.lock();
+        let bucket = buckets.get_or_insert_mut(key, || Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        refill(bucket);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / rate))
+        }
+    }
+
+    fn reject(&self, retry_after: Duration) -> Response {
+        let error = limit_exceeded(
+            RATE_LIMITED,
+            format!(
+                "The rate limit of {} requests per {:?} is exceeded, retry after {:?}",
+                self.config.requests, self.config.period, retry_after
+            ),
+        );
+        let body = GraphQLResponse::from_errors(vec![
+            error.into_server_error(Default::default())
+        ]);
+
+        let mut response = (StatusCode::TOO_MANY_REQUESTS, Json(body)).into_response();
+        let retry_after_secs = retry_after.as_secs().saturating_add(1);
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(retry_after_secs));
+        response
+    }
+}
+
+/// Returns the last address in the forwarded-for header, the one appended by the
+/// trusted proxy. The addresses before it are set by the client and can't be trusted.
+fn forwarded_ip(value: &str) -> Option<IpAddr> {
+    value.rsplit(',').next()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(requests: u32, period: Duration) -> RateLimitConfig {
+        RateLimitConfig {
+            requests,
+            period,
+            api_keys: Default::default(),
+            forwarded_for_header: None,
+        }
+    }
+
+    fn limiter(requests: u32, period: Duration) -> RateLimiter {
+        RateLimiter::new(config(requests, period))
+    }
+
+    fn ip(last: u8) -> ClientKey {
+        ClientKey::Ip(IpAddr::from([127, 0, 0, last]))
+    }
+
+    #[test]
+    fn rejects_requests_above_the_limit() {
+        let limiter = limiter(2, Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(limiter.check(ip(1), now).is_ok());
+        assert!(limiter.check(ip(1), now).is_ok());
+        let retry_after = limiter.check(ip(1), now).unwrap_err();
+        // One token is refilled every 30 seconds
+        assert!(retry_after > Duration::from_secs(29));
+        assert!(retry_after < Duration::from_secs(31));
+    }
+
+    #[test]
+    fn clients_are_limited_separately() {
+        let limiter = limiter(1, Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(limiter.check(ip(1), now).is_ok());
+        assert!(limiter.check(ip(1), now).is_err());
+        assert!(limiter.check(ip(2), now).is_ok());
+        assert!(limiter
+            .check(ClientKey::ApiKey("key".to_string()), now)
+            .is_ok());
+    }
+
+    #[test]
+    fn bucket_is_refilled_over_time() {
+        let limiter = limiter(2, Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(limiter.check(ip(1), now).is_ok());
+        assert!(limiter.check(ip(1), now).is_ok());
+        assert!(limiter.check(ip(1), now).is_err());
+
+        let later = now.checked_add(Duration::from_secs(31)).unwrap();
+        assert!(limiter.check(ip(1), later).is_ok());
+        assert!(limiter.check(ip(1), later).is_err());
+    }
+
+    #[test]
+    fn least_recently_seen_client_is_forgotten() {
+        let limiter = RateLimiter::with_capacity(config(1, Duration::from_secs(60)), 2);
+        let now = Instant::now();
+
+        assert!(limiter.check(ip(1), now).is_ok());
+        assert!(limiter.check(ip(2), now).is_ok());
+        assert!(limiter.check(ip(1), now).is_err());
+        // The third client evicts the second one, which was seen the least recently
+        assert!(limiter.check(ip(3), now).is_ok());
+        assert_eq!(limiter.buckets.lock().len(), 2);
+
+        assert!(limiter.check(ip(1), now).is_err());
+        assert!(limiter.check(ip(2), now).is_ok());
+    }
+
+    #[test]
+    fn client_ip_is_taken_from_the_trusted_forwarded_header() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            forwarded_for_header: Some("x-forwarded-for".to_string()),
+            ..config(1, Duration::from_secs(60))
+        });
+        let proxy = SocketAddr::from(([10, 0, 0, 1], 4000));
+        let request = |forwarded_for: &str| {
+            let mut request = Request::new(());
+            request.extensions_mut().insert(ConnectInfo(proxy));
+            if !forwarded_for.is_empty() {
+                request.headers_mut().insert(
+                    "x-forwarded-for",
+                    HeaderValue::from_str(forwarded_for).unwrap(),
+                );
+            }
+            request
+        };
+
+        assert_eq!(
+            limiter.client_key(&request("1.1.1.1, 127.0.0.7")),
+            Some(ip(7))
+        );
+        assert_eq!(
+            limiter.client_key(&request("not an ip")),
+            Some(ClientKey::Ip(proxy.ip()))
+        );
+        assert_eq!(
+            limiter.client_key(&request("")),
+            Some(ClientKey::Ip(proxy.ip()))
+        );
+    }
+}
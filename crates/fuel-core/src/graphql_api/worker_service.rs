@@ -1,7 +1,9 @@
-use crate::fuel_core_graphql_api::ports;
+use crate::{
+    fuel_core_graphql_api::ports,
+    service::config::IndexesConfig,
+};
 use fuel_core_services::{
     stream::BoxStream,
-    EmptyShared,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -14,45 +16,107 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::block::Block,
+    fuel_crypto::Hasher,
     fuel_tx::{
         field::{
+            BytecodeWitnessIndex,
             Inputs,
             Outputs,
+            Script,
+            Witnesses,
         },
-        input::coin::{
-            CoinPredicate,
-            CoinSigned,
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            contract::Contract,
+            message::{
+                MessageCoinPredicate,
+                MessageDataPredicate,
+            },
         },
+        Contract as ContractCode,
         Input,
         Output,
         Receipt,
         Transaction,
         TxId,
         UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::{
         BlockHeight,
         Bytes32,
+        ChainId,
     },
     services::{
         block_importer::{
             ImportResult,
             SharedImportResult,
         },
-        executor::TransactionExecutionStatus,
-        txpool::from_executor_to_status,
+        executor::{
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+        graphql_api::{
+            IndexState,
+            OffChainIndex,
+        },
+        txpool::{
+            from_executor_to_status,
+            TransactionStatus,
+        },
     },
 };
 use futures::{
     FutureExt,
     StreamExt,
 };
+use std::{
+    collections::BTreeMap,
+    time::Duration,
+};
+use tokio::sync::broadcast;
+
+/// The number of processed blocks notifications kept for the slow subscribers.
+const BLOCK_HEIGHT_CHANNEL_CAPACITY: usize = 1024;
+
+/// The number of stored blocks indexed by one step of the backfill.
+const BACKFILL_BATCH_SIZE: usize = 100;
+
+/// The number of unspent coins indexed by one step of the backfill.
+const BACKFILL_COINS_BATCH_SIZE: usize = 10_000;
+
+/// The delay before the next step of the backfill if the previous one didn't make progress.
+const BACKFILL_RETRY_INTERVAL: Duration = Duration::from_millis(100);
 
 /// The off-chain GraphQL API worker task processes the imported blocks
 /// and actualize the information used by the GraphQL service.
 pub struct Task<D> {
     block_importer: BoxStream<SharedImportResult>,
     database: D,
+    chain_id: ChainId,
+    indexes: IndexesConfig,
+    /// The states of the enabled indexes.
+    index_states: BTreeMap<OffChainIndex, IndexState>,
+    /// `true` if the last step of the backfill didn't make progress.
+    backfill_stalled: bool,
+    block_height_sender: broadcast::Sender<BlockHeight>,
+}
+
+/// The shared state of the off-chain GraphQL API worker.
+#[derive(Clone)]
+pub struct SharedState {
+    block_height_sender: broadcast::Sender<BlockHeight>,
+}
+
+impl SharedState {
+    /// Subscribes to the heights of the blocks processed by the worker. The height is sent
+    /// after the off-chain database is updated, so the block is visible to the GraphQL queries.
+    pub fn block_height_subscribe(&self) -> broadcast::Receiver<BlockHeight> {
+        self.block_height_sender.subscribe()
+    }
 }
 
 impl<D> Task<D>
@@ -67,16 +131,66 @@ where
         // save the status for every transaction using the finalized block id
         self.persist_transaction_status(&result, transaction.as_mut())?;
 
-        // save the associated owner for each transaction in the block
-        self.index_tx_owners_for_block(
-            &result.sealed_block.entity,
-            transaction.as_mut(),
-        )?;
+        let block = &result.sealed_block.entity;
+        let height = *block.header().height();
+        // The receipts of the failed transactions are skipped because their changes are reverted.
+        let receipts: Vec<_> = result
+            .tx_status
+            .iter()
+            .filter(|status| {
+                !matches!(status.result, TransactionExecutionResult::Failed { .. })
+            })
+            .map(|status| status.receipts.as_slice())
+            .collect();
+        for (index, state) in self.index_states.iter() {
+            // The blocks below `since` are already indexed by the backfill
+            if state.is_indexed_on_import(height) {
+                self.index_block(*index, block, &receipts, transaction.as_mut())?;
+            }
+        }
         transaction.commit()?;
 
+        // It is fine if nobody is subscribed to the processed blocks.
+        let _ = self.block_height_sender.send(height);
+
         Ok(())
     }
 
+    /// Updates the `index` with the block and the receipts of its successful transactions.
+    fn index_block(
+        &self,
+        index: OffChainIndex,
+        block: &Block,
+        receipts: &[&[Receipt]],
+        db: &mut D,
+    ) -> anyhow::Result<()> {
+        match index {
+            // save the associated owner for each transaction in the block
+            OffChainIndex::Owners => self.index_tx_owners_for_block(block, db),
+            // save the associated contracts for each transaction in the block
+            OffChainIndex::Contracts => self.index_tx_contracts_for_block(block, db),
+            // save the associated predicate roots for each transaction in the block
+            OffChainIndex::PredicateRoots => {
+                self.index_tx_predicates_for_block(block, db)
+            }
+            // save the associated script hashes for each transaction in the block
+            OffChainIndex::ScriptHashes => self.index_tx_scripts_for_block(block, db),
+            // keep the index of the coins by amount and the holders of assets
+            // in sync with the spent and created coins
+            OffChainIndex::CoinsByAmount => {
+                self.index_coins_by_amount_for_block(block, db)
+            }
+            // aggregate the minted and burned amounts of assets
+            OffChainIndex::Assets => Ok(self.persist_assets_supply(receipts, db)?),
+            // link the time of the block to its height
+            OffChainIndex::BlockTimes => {
+                let header = block.header();
+                db.record_block_time(header.time(), *header.height())?;
+                Ok(())
+            }
+        }
+    }
+
     /// Associate all transactions within a block to their respective UTXO owners
     fn index_tx_owners_for_block(
         &self,
@@ -158,6 +272,265 @@ where
         Ok(())
     }
 
+    /// Associate all transactions within a block to the contracts used by them as inputs
+    fn index_tx_contracts_for_block(
+        &self,
+        block: &Block,
+        block_st_transaction: &mut D,
+    ) -> anyhow::Result<()> {
+        let block_height = *block.header().height();
+        for (tx_idx, tx) in block.transactions().iter().enumerate() {
+            let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+            })?;
+            let tx_id = tx.cached_id().expect(
+                "The imported block should contains only transactions with cached id",
+            );
+            let inputs = match tx {
+                Transaction::Script(tx) => tx.inputs().as_slice(),
+                Transaction::Create(tx) => tx.inputs().as_slice(),
+                _ => continue,
+            };
+
+            let mut contracts: Vec<_> = inputs
+                .iter()
+                .filter_map(|input| match input {
+                    Input::Contract(Contract { contract_id, .. }) => Some(contract_id),
+                    _ => None,
+                })
+                .collect();
+            contracts.sort();
+            contracts.dedup();
+
+            for contract_id in contracts {
+                block_st_transaction.record_tx_id_contract(
+                    contract_id,
+                    block_height,
+                    tx_idx,
+                    &tx_id,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Associate all transactions within a block to the roots of the predicates they spend from
+    fn index_tx_predicates_for_block(
+        &self,
+        block: &Block,
+        block_st_transaction: &mut D,
+    ) -> anyhow::Result<()> {
+        let block_height = *block.header().height();
+        for (tx_idx, tx) in block.transactions().iter().enumerate() {
+            let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+            })?;
+            let tx_id = tx.cached_id().expect(
+                "The imported block should contains only transactions with cached id",
+            );
+            let inputs = match tx {
+                Transaction::Script(tx) => tx.inputs().as_slice(),
+                Transaction::Create(tx) => tx.inputs().as_slice(),
+                _ => continue,
+            };
+
+            // The owner of the predicate input is the root of the predicate
+            let mut roots: Vec<_> = inputs
+                .iter()
+                .filter_map(|input| match input {
+                    Input::CoinPredicate(CoinPredicate { owner, .. }) => Some(owner),
+                    Input::MessageCoinPredicate(MessageCoinPredicate {
+                        recipient,
+                        ..
+                    })
+                    | Input::MessageDataPredicate(MessageDataPredicate {
+                        recipient,
+                        ..
+                    }) => Some(recipient),
+                    _ => None,
+                })
+                .collect();
+            roots.sort();
+            roots.dedup();
+
+            for root in roots {
+                block_st_transaction.record_tx_id_predicate_root(
+                    root,
+                    block_height,
+                    tx_idx,
+                    &tx_id,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Associate all transactions within a block to the hash of their script
+    /// or the root of the bytecode deployed by them
+    fn index_tx_scripts_for_block(
+        &self,
+        block: &Block,
+        block_st_transaction: &mut D,
+    ) -> anyhow::Result<()> {
+        let block_height = *block.header().height();
+        for (tx_idx, tx) in block.transactions().iter().enumerate() {
+            let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+            })?;
+            let tx_id = tx.cached_id().expect(
+                "The imported block should contains only transactions with cached id",
+            );
+            let script_hash = match tx {
+                Transaction::Script(tx) => Hasher::hash(tx.script()),
+                Transaction::Create(tx) => {
+                    let bytecode = tx
+                        .witnesses()
+                        .get(usize::from(*tx.bytecode_witness_index()))
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "The bytecode witness of the transaction {} is missing",
+                                tx_id
+                            )
+                        })?;
+                    ContractCode::from(bytecode.as_ref()).root()
+                }
+                _ => continue,
+            };
+
+            block_st_transaction.record_tx_id_script_hash(
+                &script_hash,
+                block_height,
+                tx_idx,
+                &tx_id,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Removes the spent coins and adds the created coins to the index by amount,
+    /// following the order of transactions in the block as the executor does.
+    /// The holders of assets are updated along with the index.
+    fn index_coins_by_amount_for_block(
+        &self,
+        block: &Block,
+        block_st_transaction: &mut D,
+    ) -> anyhow::Result<()> {
+        for tx in block.transactions() {
+            let inputs;
+            let outputs;
+            let tx_id = tx.cached_id().expect(
+                "The imported block should contains only transactions with cached id",
+            );
+            match tx {
+                Transaction::Script(tx) => {
+                    inputs = tx.inputs().as_slice();
+                    outputs = tx.outputs().as_slice();
+                }
+                Transaction::Create(tx) => {
+                    inputs = tx.inputs().as_slice();
+                    outputs = tx.outputs().as_slice();
+                }
+                _ => continue,
+            }
+            self.persist_coins_by_amount(inputs, outputs, &tx_id, block_st_transaction)?;
+        }
+        Ok(())
+    }
+
+    fn persist_coins_by_amount(
+        &self,
+        inputs: &[Input],
+        outputs: &[Output],
+        tx_id: &Bytes32,
+        db: &mut D,
+    ) -> anyhow::Result<()> {
+        for input in inputs {
+            if let Input::CoinSigned(CoinSigned {
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                ..
+            })
+            | Input::CoinPredicate(CoinPredicate {
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                ..
+            }) = input
+            {
+                db.remove_owned_coin_amount(owner, asset_id, *amount, utxo_id)?;
+                db.remove_asset_holder(owner, asset_id)?;
+            }
+        }
+
+        for (output_index, output) in outputs.iter().enumerate() {
+            match output {
+                Output::Coin {
+                    to,
+                    amount,
+                    asset_id,
+                }
+                | Output::Change {
+                    to,
+                    amount,
+                    asset_id,
+                }
+                | Output::Variable {
+                    to,
+                    amount,
+                    asset_id,
+                } => {
+                    // The executor doesn't create coins without amount
+                    if *amount == 0 {
+                        continue
+                    }
+                    let output_index = u8::try_from(output_index).map_err(|e| {
+                        anyhow::anyhow!(
+                            "The transaction has more than `u8::MAX` outputs, {}",
+                            e
+                        )
+                    })?;
+                    let utxo_id = UtxoId::new(*tx_id, output_index);
+                    db.record_owned_coin_amount(to, asset_id, *amount, &utxo_id)?;
+                    db.record_asset_holder(to, asset_id)?;
+                }
+                Output::Contract(_) | Output::ContractCreated { .. } => {}
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Aggregates the `Mint` and `Burn` receipts of the successful transactions.
+    fn persist_assets_supply(
+        &self,
+        receipts: &[&[Receipt]],
+        db: &mut D,
+    ) -> StorageResult<()> {
+        for receipts in receipts {
+            for receipt in receipts.iter() {
+                match receipt {
+                    Receipt::Mint {
+                        sub_id,
+                        contract_id,
+                        val,
+                        ..
+                    } => db.record_asset_mint(contract_id, sub_id, *val)?,
+                    Receipt::Burn {
+                        sub_id,
+                        contract_id,
+                        val,
+                        ..
+                    } => db.record_asset_burn(contract_id, sub_id, *val)?,
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn persist_transaction_status(
         &self,
         import_result: &ImportResult,
@@ -167,6 +540,8 @@ where
             id,
             result,
             receipts,
+            state_diff,
+            ..
         } in import_result.tx_status.iter()
         {
             let status = from_executor_to_status(
@@ -183,6 +558,16 @@ where
             }
 
             self.persist_receipts(id, receipts, db)?;
+
+            if let Some(state_diff) = state_diff {
+                if db.update_tx_state_diff(id, state_diff)?.is_some() {
+                    return Err(anyhow::anyhow!(
+                        "State diff already exists for tx {}",
+                        id
+                    )
+                    .into());
+                }
+            }
         }
         Ok(())
     }
@@ -198,6 +583,242 @@ where
         }
         Ok(())
     }
+
+    fn is_backfilling(&self) -> bool {
+        self.index_states
+            .values()
+            .any(|state| !matches!(state, IndexState::Ready { .. }))
+    }
+
+    /// Loads the states of the enabled indexes. The index enabled for the first time
+    /// is reset and scheduled for the backfill from the stored blocks or the unspent
+    /// coins. The state of the disabled index is removed, so enabling it later rebuilds
+    /// it from scratch.
+    fn init_index_states(&mut self) -> anyhow::Result<()> {
+        let mut transaction = self.database.transaction();
+        let db = transaction.as_mut();
+        // The first stored block is the genesis block, which may be above zero.
+        let first_height = db.first_block_height()?.unwrap_or_default();
+        let latest_height = db.latest_block_height()?;
+        for index in OffChainIndex::ALL {
+            let state = db.index_state(index)?;
+            if !self.indexes.is_enabled(index) {
+                if state.is_some() {
+                    tracing::info!("The `{index}` index is disabled and not maintained");
+                    db.remove_index_state(index)?;
+                }
+                continue
+            }
+
+            let state = match state {
+                Some(state) => state,
+                None => {
+                    tracing::info!("The `{index}` index is enabled, backfilling it");
+                    let state = match index {
+                        // The index depends on the order of the spent and created coins,
+                        // so it is built from the unspent coins instead of the stored blocks.
+                        // The blocks imported after the start are indexed on import
+                        // while the unspent coins are scanned.
+                        OffChainIndex::CoinsByAmount => IndexState::ScanningCoins {
+                            since: match latest_height {
+                                Some(height) => height.succ().ok_or_else(|| {
+                                    anyhow::anyhow!(
+                                        "The block height overflowed during the backfill"
+                                    )
+                                })?,
+                                None => first_height,
+                            },
+                            next_coin: UtxoId::default(),
+                        },
+                        _ => IndexState::Backfilling {
+                            next_height: first_height,
+                        },
+                    };
+                    db.reset_index(index)?;
+                    db.record_index_state(index, &state)?;
+                    state
+                }
+            };
+            self.index_states.insert(index, state);
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Indexes the next batch of stored blocks or unspent coins for each index being
+    /// backfilled. Returns `false` if the backfill didn't make progress, because it waits
+    /// for the worker to process the imported blocks.
+    async fn backfill(&mut self) -> anyhow::Result<bool> {
+        let backfilling: Vec<_> = self
+            .index_states
+            .iter()
+            .filter(|(_, state)| !matches!(state, IndexState::Ready { .. }))
+            .map(|(index, state)| (*index, *state))
+            .collect();
+
+        let mut progressed = false;
+        for (index, state) in backfilling {
+            progressed |= self.backfill_index(index, state).await?;
+        }
+        Ok(progressed)
+    }
+
+    async fn backfill_index(
+        &mut self,
+        index: OffChainIndex,
+        state: IndexState,
+    ) -> anyhow::Result<bool> {
+        let new_state = match state {
+            IndexState::Backfilling { next_height } => {
+                self.backfill_blocks(index, next_height)?
+            }
+            IndexState::ScanningCoins { since, next_coin } => {
+                // The scan reads many coins, so it doesn't block the async runtime.
+                let database = self.database.clone();
+                tokio::task::spawn_blocking(move || {
+                    Self::backfill_coins_by_amount(&database, since, &next_coin)
+                })
+                .await??
+            }
+            IndexState::Ready { .. } => return Ok(false),
+        };
+        if new_state == state {
+            return Ok(false)
+        }
+
+        match new_state {
+            IndexState::Backfilling { next_height } => {
+                tracing::debug!(
+                    "The `{index}` index is backfilled up to the height {next_height}"
+                );
+            }
+            IndexState::ScanningCoins { next_coin, .. } => {
+                tracing::debug!(
+                    "The `{index}` index is backfilled up to the coin {next_coin:#x}"
+                );
+            }
+            IndexState::Ready { since } => {
+                tracing::info!(
+                    "The `{index}` index is backfilled, the blocks from the height {since} \
+                    are indexed on import"
+                );
+            }
+        }
+        self.index_states.insert(index, new_state);
+        Ok(true)
+    }
+
+    /// Indexes the stored blocks starting from the `next_height`. The index becomes ready
+    /// when all stored blocks are indexed, and the next blocks are indexed on import.
+    fn backfill_blocks(
+        &self,
+        index: OffChainIndex,
+        next_height: BlockHeight,
+    ) -> anyhow::Result<IndexState> {
+        let mut transaction = self.database.transaction();
+        let db = transaction.as_mut();
+        let state = self.index_blocks(index, next_height, db)?;
+        // Nothing is indexed if the worker didn't process the next block yet
+        if state != (IndexState::Backfilling { next_height }) {
+            db.record_index_state(index, &state)?;
+            transaction.commit()?;
+        }
+        Ok(state)
+    }
+
+    fn index_blocks(
+        &self,
+        index: OffChainIndex,
+        mut next_height: BlockHeight,
+        db: &mut D,
+    ) -> anyhow::Result<IndexState> {
+        for _ in 0..BACKFILL_BATCH_SIZE {
+            let Some(block) = db.full_block(&next_height, &self.chain_id)? else {
+                return Ok(IndexState::Ready { since: next_height })
+            };
+
+            let receipts = if index == OffChainIndex::Assets {
+                match self.stored_receipts(&block, db)? {
+                    Some(receipts) => receipts,
+                    // The worker didn't process the imported block yet.
+                    None => break,
+                }
+            } else {
+                vec![]
+            };
+            let receipts: Vec<_> = receipts.iter().map(Vec::as_slice).collect();
+            self.index_block(index, &block, &receipts, db)?;
+
+            next_height = next_height.succ().ok_or_else(|| {
+                anyhow::anyhow!("The block height overflowed during the backfill")
+            })?;
+        }
+        Ok(IndexState::Backfilling { next_height })
+    }
+
+    /// Returns the stored receipts of the successful transactions of the block,
+    /// or `None` if the worker didn't record the statuses of its transactions yet.
+    fn stored_receipts(
+        &self,
+        block: &Block,
+        db: &mut D,
+    ) -> anyhow::Result<Option<Vec<Vec<Receipt>>>> {
+        let mut receipts = vec![];
+        for tx in block.transactions() {
+            let tx_id = tx.cached_id().expect(
+                "The stored block should contains only transactions with cached id",
+            );
+            match db.get_tx_status(&tx_id)? {
+                None => return Ok(None),
+                Some(TransactionStatus::Failed { .. }) => continue,
+                Some(_) => {}
+            }
+            let tx_receipts = db
+                .storage::<Receipts>()
+                .get(&tx_id)?
+                .map(|receipts| receipts.into_owned())
+                .unwrap_or_default();
+            receipts.push(tx_receipts);
+        }
+        Ok(Some(receipts))
+    }
+
+    /// Indexes the next batch of the unspent coins starting from the `next_coin`. The index
+    /// becomes ready when all unspent coins are indexed. The blocks imported during the scan
+    /// are indexed on import, and indexing the same coin twice doesn't change the index.
+    fn backfill_coins_by_amount(
+        database: &D,
+        since: BlockHeight,
+        next_coin: &UtxoId,
+    ) -> anyhow::Result<IndexState> {
+        let mut coins = database
+            .unspent_coins(next_coin)
+            .take(BACKFILL_COINS_BATCH_SIZE.saturating_add(1))
+            .collect::<StorageResult<Vec<_>>>()?;
+        let state = if coins.len() > BACKFILL_COINS_BATCH_SIZE {
+            let (next_coin, _) = coins.pop().expect("The batch is not empty");
+            IndexState::ScanningCoins { since, next_coin }
+        } else {
+            IndexState::Ready { since }
+        };
+
+        let mut transaction = database.transaction();
+        let db = transaction.as_mut();
+        for (utxo_id, coin) in coins {
+            db.record_owned_coin_amount(
+                coin.owner(),
+                coin.asset_id(),
+                *coin.amount(),
+                &utxo_id,
+            )?;
+            if *coin.amount() != 0 {
+                db.record_asset_holder(coin.owner(), coin.asset_id())?;
+            }
+        }
+        db.record_index_state(OffChainIndex::CoinsByAmount, &state)?;
+        transaction.commit()?;
+        Ok(state)
+    }
 }
 
 #[async_trait::async_trait]
@@ -206,16 +827,18 @@ where
     D: ports::worker::OffChainDatabase,
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
-    type SharedData = EmptyShared;
+    type SharedData = SharedState;
     type Task = Self;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
-        EmptyShared
+        SharedState {
+            block_height_sender: self.block_height_sender.clone(),
+        }
     }
 
     async fn into_task(
-        self,
+        mut self,
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
@@ -226,6 +849,7 @@ where
         //  of the blockchain. When `AtomicView<Storage>::view_at` is implemented, we can
         //  process all missed blocks and actualize the database here.
         //  https://github.com/FuelLabs/fuel-core/issues/1584
+        self.init_index_states()?;
         Ok(self)
     }
 }
@@ -253,6 +877,12 @@ where
                     should_continue = false
                 }
             }
+
+            // The backfill runs only while there are no imported blocks to process
+            _ = backfill_delay(self.backfill_stalled), if self.is_backfilling() => {
+                self.backfill_stalled = !self.backfill().await?;
+                should_continue = true
+            }
         }
         Ok(should_continue)
     }
@@ -272,14 +902,31 @@ where
     }
 }
 
-pub fn new_service<I, D>(block_importer: I, database: D) -> ServiceRunner<Task<D>>
+async fn backfill_delay(stalled: bool) {
+    if stalled {
+        tokio::time::sleep(BACKFILL_RETRY_INTERVAL).await
+    }
+}
+
+pub fn new_service<I, D>(
+    block_importer: I,
+    database: D,
+    chain_id: ChainId,
+    indexes: IndexesConfig,
+) -> ServiceRunner<Task<D>>
 where
     I: ports::worker::BlockImporter,
     D: ports::worker::OffChainDatabase,
 {
     let block_importer = block_importer.block_events();
+    let (block_height_sender, _) = broadcast::channel(BLOCK_HEIGHT_CHANNEL_CAPACITY);
     ServiceRunner::new(Task {
         block_importer,
         database,
+        chain_id,
+        indexes,
+        index_states: BTreeMap::new(),
+        backfill_stalled: false,
+        block_height_sender,
     })
 }
@@ -1,3 +1,4 @@
+mod asset;
 mod balance;
 mod block;
 mod chain;
@@ -8,6 +9,7 @@ mod subscriptions;
 mod tx;
 
 // TODO: Remove reexporting of everything
+pub use asset::*;
 pub use balance::*;
 pub use block::*;
 pub use chain::*;
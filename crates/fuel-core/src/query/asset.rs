@@ -0,0 +1,36 @@
+use crate::fuel_core_graphql_api::ports::OffChainDatabase;
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IterDirection,
+    },
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_types::AssetId,
+    services::graphql_api::AssetDetails,
+};
+
+pub trait AssetQueryData: Send + Sync {
+    fn asset(&self, asset_id: &AssetId) -> StorageResult<AssetDetails>;
+
+    fn assets(
+        &self,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(AssetId, AssetDetails)>>;
+}
+
+impl<D: OffChainDatabase + ?Sized> AssetQueryData for D {
+    fn asset(&self, asset_id: &AssetId) -> StorageResult<AssetDetails> {
+        self.asset_details(asset_id)
+    }
+
+    fn assets(
+        &self,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(AssetId, AssetDetails)>> {
+        OffChainDatabase::assets(self, start_asset, direction)
+    }
+}
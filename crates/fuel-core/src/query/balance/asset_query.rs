@@ -1,5 +1,8 @@
 use crate::{
-    graphql_api::database::ReadView,
+    graphql_api::{
+        database::ReadView,
+        ports,
+    },
     query::{
         CoinQueryData,
         MessageQueryData,
@@ -8,6 +11,7 @@ use crate::{
 use fuel_core_storage::{
     iter::IterDirection,
     Error as StorageError,
+    IsNotFound,
     Result as StorageResult,
 };
 use fuel_core_types::{
@@ -18,9 +22,13 @@ use fuel_core_types::{
     fuel_types::{
         Address,
         AssetId,
+        Word,
     },
 };
-use itertools::Itertools;
+use itertools::{
+    Either,
+    Itertools,
+};
 use std::collections::HashSet;
 
 /// At least required `target` of the query per asset's `id` with `max` coins.
@@ -52,6 +60,11 @@ impl Exclude {
 
         instance
     }
+
+    /// Adds the `ids` to the excluded ones.
+    pub fn extend(&mut self, ids: impl IntoIterator<Item = CoinId>) {
+        self.coin_ids.extend(ids);
+    }
 }
 
 pub struct AssetsQuery<'a> {
@@ -173,6 +186,8 @@ pub struct AssetQuery<'a> {
     pub exclude: Option<&'a Exclude>,
     pub database: &'a ReadView,
     query: AssetsQuery<'a>,
+    /// `true` if the coins are read from the index by amount.
+    by_amount_index: bool,
 }
 
 impl<'a> AssetQuery<'a> {
@@ -197,12 +212,118 @@ impl<'a> AssetQuery<'a> {
                 database,
                 base_asset_id,
             ),
+            by_amount_index: true,
         }
     }
 
+    /// Reads the coins without the index by amount, if the index is disabled on the node
+    /// or is not built yet. All coins of the `owner` are loaded and sorted in memory.
+    pub fn without_by_amount_index(mut self) -> Self {
+        self.by_amount_index = false;
+        self
+    }
+
     /// Returns the iterator over all valid(spendable, allowed by `exclude`) coins of the `owner`
     /// for the `asset_id`.
     pub fn coins(&self) -> impl Iterator<Item = StorageResult<CoinType>> + '_ {
         self.query.coins()
     }
+
+    /// Returns the iterator over the ids of all valid(spendable, allowed by `exclude`) coins
+    /// of the `owner` for the `asset_id` ordered by the amount in the `direction`.
+    ///
+    /// The coins are read lazily from the index by amount, so the caller can stop early
+    /// without loading all coins of the `owner`. The messages are not indexed and are
+    /// loaded at once.
+    pub fn coin_ids_by_amount(
+        &self,
+        direction: IterDirection,
+    ) -> StorageResult<impl Iterator<Item = StorageResult<CoinId>> + '_> {
+        if !self.by_amount_index {
+            let mut coins: Vec<(Word, CoinId)> = self
+                .coins()
+                .map_ok(|coin| (coin.amount(), coin.coin_id()))
+                .try_collect()?;
+            coins.sort_by_key(|(amount, _)| *amount);
+            if direction == IterDirection::Reverse {
+                coins.reverse();
+            }
+            return Ok(Either::Left(coins.into_iter().map(|(_, id)| Ok(id))))
+        }
+
+        let mut messages: Vec<(Word, CoinId)> =
+            if self.query.has_asset(self.query.base_asset_id) {
+                self.query
+                    .messages_iter()
+                    .map_ok(|message| (message.amount(), message.coin_id()))
+                    .try_collect()?
+            } else {
+                vec![]
+            };
+        messages.sort_by_key(|(amount, _)| *amount);
+        if direction == IterDirection::Reverse {
+            messages.reverse();
+        }
+
+        let coins = ports::OffChainDatabase::owned_coins_ids_by_amount(
+            self.database,
+            self.owner,
+            &self.asset.id,
+            direction,
+        )
+            .map_ok(|(amount, id)| (amount, CoinId::from(id)))
+            .filter_ok(|(_, id)| {
+                if let Some(exclude) = self.exclude {
+                    !exclude.coin_ids.contains(id)
+                } else {
+                    true
+                }
+            });
+
+        let ids = coins
+            .merge_by(messages.into_iter().map(Ok), move |a, b| match (a, b) {
+                (Ok((a, _)), Ok((b, _))) => match direction {
+                    IterDirection::Forward => a <= b,
+                    IterDirection::Reverse => a >= b,
+                },
+                // Return errors as soon as possible
+                _ => a.is_err(),
+            })
+            .map_ok(|(_, id)| id);
+        Ok(Either::Right(ids))
+    }
+
+    /// Returns the iterator over all valid(spendable, allowed by `exclude`) coins of the `owner`
+    /// for the `asset_id` ordered by the amount in the `direction`.
+    pub fn coins_by_amount(
+        &self,
+        direction: IterDirection,
+    ) -> StorageResult<impl Iterator<Item = StorageResult<CoinType>> + '_> {
+        let coins = self
+            .coin_ids_by_amount(direction)?
+            .filter_map(|id| id.and_then(|id| self.coin(&id)).transpose());
+        Ok(coins)
+    }
+
+    /// Returns the spendable coin with the `id`, or `None` if it is already spent.
+    ///
+    /// The index by amount is updated by the off-chain worker after the block is
+    /// committed, so it can still contain the coins spent by the latest block.
+    pub fn coin(&self, id: &CoinId) -> StorageResult<Option<CoinType>> {
+        let coin = match id {
+            CoinId::Utxo(utxo_id) => self.database.coin(*utxo_id).map(CoinType::Coin),
+            CoinId::Message(nonce) => self.database.message(nonce).map(|message| {
+                CoinType::MessageCoin(
+                    message
+                        .try_into()
+                        .expect("Only the messages without data are returned as coins."),
+                )
+            }),
+        };
+
+        match coin {
+            Err(error) if error.is_not_found() => Ok(None),
+            coin => coin.map(Some),
+        }
+    }
 }
@@ -27,7 +27,11 @@ use fuel_core_storage::{
     StorageAsRef,
 };
 use fuel_core_types::{
-    blockchain::block::CompressedBlock,
+    blockchain::{
+        block::CompressedBlock,
+        header::BlockHeader,
+        primitives::BlockId,
+    },
     entities::message::{
         MerkleProof,
         Message,
@@ -50,7 +54,10 @@ use fuel_core_types::{
     services::txpool::TransactionStatus,
 };
 use itertools::Itertools;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+};
 
 #[cfg(test)]
 mod test;
@@ -147,137 +154,241 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     desired_nonce: Nonce,
     commit_block_height: BlockHeight,
 ) -> StorageResult<Option<MessageProof>> {
-    // Check if the receipts for this transaction actually contain this message id or exit.
-    let receipt = database
-        .receipts(&transaction_id)?
+    MessageProofs::new(database, commit_block_height).proof(transaction_id, desired_nonce)
+}
+
+/// Generate the output proofs of several messages relatively to the same commit block.
+/// The proofs are returned in the same order as the `messages`.
+///
+/// The commit block, the message blocks with their receipts, and the block history proofs
+/// are loaded only once and shared between the proofs.
+pub fn message_proofs<T: MessageProofData + ?Sized>(
+    database: &T,
+    messages: impl IntoIterator<Item = (Bytes32, Nonce)>,
+    commit_block_height: BlockHeight,
+) -> StorageResult<Vec<Option<MessageProof>>> {
+    let mut proofs = MessageProofs::new(database, commit_block_height);
+    messages
         .into_iter()
-        .find_map(|r| match r {
-            Receipt::MessageOut {
-                sender,
-                recipient,
-                nonce,
-                amount,
-                data,
-                ..
-            } if r.nonce() == Some(&desired_nonce) => {
-                Some((sender, recipient, nonce, amount, data))
-            }
-            _ => None,
-        });
-
-    let (sender, recipient, nonce, amount, data) = match receipt {
-        Some(r) => r,
-        None => return Ok(None),
-    };
-    let data =
-        data.ok_or(anyhow::anyhow!("Output message doesn't contain any `data`"))?;
-
-    // Get the block id from the transaction status if it's ready.
-    let message_block_id = match database
-        .transaction_status(&transaction_id)
-        .into_api_result::<TransactionStatus, StorageError>()?
-    {
-        Some(TransactionStatus::Success { block_id, .. }) => block_id,
-        _ => return Ok(None),
-    };
-
-    // Get the message fuel block header.
-    let (message_block_header, message_block_txs) = match database
-        .block_by_id(&message_block_id)
-        .into_api_result::<CompressedBlock, StorageError>()?
-    {
-        Some(t) => t.into_inner(),
-        None => return Ok(None),
-    };
-
-    let message_id = compute_message_id(&sender, &recipient, &nonce, amount, &data);
-
-    let message_proof =
-        match message_receipts_proof(database, message_id, &message_block_txs)? {
+        .map(|(transaction_id, nonce)| proofs.proof(transaction_id, nonce))
+        .collect()
+}
+
+/// The generator of the output proofs that caches the data shared between the proofs.
+struct MessageProofs<'a, T: ?Sized> {
+    database: &'a T,
+    commit_block_height: BlockHeight,
+    /// The header of the commit block, `None` if it is not loaded yet.
+    commit_block_header: Option<Option<BlockHeader>>,
+    /// The loaded message blocks, `None` if the block is not found.
+    message_blocks: HashMap<BlockId, Option<MessageBlock>>,
+    /// The block history proofs by the height of the message block.
+    block_proofs: HashMap<BlockHeight, MerkleProof>,
+}
+
+/// The block with the merkle tree of its output messages.
+struct MessageBlock {
+    header: BlockHeader,
+    tree: MerkleTree,
+    /// The index of each message in the `tree`.
+    leaves: HashMap<MessageId, u64>,
+}
+
+impl<'a, T: MessageProofData + ?Sized> MessageProofs<'a, T> {
+    fn new(database: &'a T, commit_block_height: BlockHeight) -> Self {
+        Self {
+            database,
+            commit_block_height,
+            commit_block_header: None,
+            message_blocks: HashMap::new(),
+            block_proofs: HashMap::new(),
+        }
+    }
+
+    fn proof(
+        &mut self,
+        transaction_id: Bytes32,
+        desired_nonce: Nonce,
+    ) -> StorageResult<Option<MessageProof>> {
+        // Check if the receipts for this transaction actually contain this message id or exit.
+        let receipt = self
+            .database
+            .receipts(&transaction_id)?
+            .into_iter()
+            .find_map(|r| match r {
+                Receipt::MessageOut {
+                    sender,
+                    recipient,
+                    nonce,
+                    amount,
+                    data,
+                    ..
+                } if r.nonce() == Some(&desired_nonce) => {
+                    Some((sender, recipient, nonce, amount, data))
+                }
+                _ => None,
+            });
+
+        let (sender, recipient, nonce, amount, data) = match receipt {
+            Some(r) => r,
+            None => return Ok(None),
+        };
+        let data =
+            data.ok_or(anyhow::anyhow!("Output message doesn't contain any `data`"))?;
+
+        // Get the block id from the transaction status if it's ready.
+        let status = self
+            .database
+            .transaction_status(&transaction_id)
+            .into_api_result::<TransactionStatus, StorageError>()?;
+        let message_block_id = match status {
+            Some(TransactionStatus::Success { block_id, .. }) => block_id,
+            _ => return Ok(None),
+        };
+
+        // Get the message fuel block.
+        let message_block = match self.message_block(&message_block_id)? {
+            Some(message_block) => message_block,
+            None => return Ok(None),
+        };
+
+        let message_id = compute_message_id(&sender, &recipient, &nonce, amount, &data);
+
+        let message_proof = match message_block.prove(&message_id) {
             Some(proof) => proof,
             None => return Ok(None),
         };
+        let message_block_header = message_block.header.clone();
+
+        // Get the commit fuel block header.
+        let commit_block_header = match self.commit_block_header()? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+
+        let block_height = *commit_block_header.height();
+        if block_height == 0u32.into() {
+            // Cannot look beyond the genesis block
+            return Ok(None)
+        }
+        let verifiable_commit_block_height =
+            block_height.pred().expect("We checked the height above");
+        let block_proof = self.block_proof(
+            message_block_header.height(),
+            &verifiable_commit_block_height,
+        )?;
+
+        Ok(Some(MessageProof {
+            message_proof,
+            block_proof,
+            message_block_header,
+            commit_block_header,
+            sender,
+            recipient,
+            nonce,
+            amount,
+            data,
+        }))
+    }
+
+    fn message_block(
+        &mut self,
+        message_block_id: &BlockId,
+    ) -> StorageResult<Option<&MessageBlock>> {
+        if !self.message_blocks.contains_key(message_block_id) {
+            let block = self
+                .database
+                .block_by_id(message_block_id)
+                .into_api_result::<CompressedBlock, StorageError>()?;
+            let message_block = match block {
+                Some(block) => Some(MessageBlock::new(self.database, block)?),
+                None => None,
+            };
+            self.message_blocks.insert(*message_block_id, message_block);
+        }
+
+        Ok(self
+            .message_blocks
+            .get(message_block_id)
+            .and_then(Option::as_ref))
+    }
 
-    // Get the commit fuel block header.
-    let commit_block_header = match database
-        .block(&commit_block_height)
-        .into_api_result::<CompressedBlock, StorageError>()?
-    {
-        Some(t) => t.into_inner().0,
-        None => return Ok(None),
-    };
-
-    let block_height = *commit_block_header.height();
-    if block_height == 0u32.into() {
-        // Cannot look beyond the genesis block
-        return Ok(None)
+    fn commit_block_header(&mut self) -> StorageResult<Option<BlockHeader>> {
+        if self.commit_block_header.is_none() {
+            let header = self
+                .database
+                .block(&self.commit_block_height)
+                .into_api_result::<CompressedBlock, StorageError>()?
+                .map(|block| block.into_inner().0);
+            self.commit_block_header = Some(header);
+        }
+
+        Ok(self.commit_block_header.clone().flatten())
+    }
+
+    fn block_proof(
+        &mut self,
+        message_block_height: &BlockHeight,
+        commit_block_height: &BlockHeight,
+    ) -> StorageResult<MerkleProof> {
+        if let Some(proof) = self.block_proofs.get(message_block_height) {
+            return Ok(proof.clone())
+        }
+
+        let proof = self
+            .database
+            .block_history_proof(message_block_height, commit_block_height)?;
+        self.block_proofs.insert(*message_block_height, proof.clone());
+        Ok(proof)
     }
-    let verifiable_commit_block_height =
-        block_height.pred().expect("We checked the height above");
-    let block_proof = database.block_history_proof(
-        message_block_header.height(),
-        &verifiable_commit_block_height,
-    )?;
-
-    Ok(Some(MessageProof {
-        message_proof,
-        block_proof,
-        message_block_header,
-        commit_block_header,
-        sender,
-        recipient,
-        nonce,
-        amount,
-        data,
-    }))
 }
 
-fn message_receipts_proof<T: MessageProofData + ?Sized>(
-    database: &T,
-    message_id: MessageId,
-    message_block_txs: &[Bytes32],
-) -> StorageResult<Option<MerkleProof>> {
-    // Get the message receipts from the block.
-    let leaves: Vec<Vec<Receipt>> = message_block_txs
-        .iter()
-        .map(|id| database.receipts(id))
-        .filter_map(|result| result.into_api_result::<_, StorageError>().transpose())
-        .try_collect()?;
-    let leaves = leaves.into_iter()
-        // Flatten the receipts after filtering on output messages
-        // and mapping to message ids.
-        .flat_map(|receipts|
-            receipts.into_iter().filter_map(|r| r.message_id()));
-
-    // Build the merkle proof from the above iterator.
-    let mut tree = MerkleTree::new();
-
-    let mut proof_index = None;
-
-    for (index, id) in leaves.enumerate() {
-        // Check if this is the message id being proved.
-        if message_id == id {
+impl MessageBlock {
+    fn new<T: MessageProofData + ?Sized>(
+        database: &T,
+        block: CompressedBlock,
+    ) -> StorageResult<Self> {
+        let (header, message_block_txs) = block.into_inner();
+
+        // Get the message receipts from the block.
+        let leaves: Vec<Vec<Receipt>> = message_block_txs
+            .iter()
+            .map(|id| database.receipts(id))
+            .filter_map(|result| result.into_api_result::<_, StorageError>().transpose())
+            .try_collect()?;
+        let leaves = leaves.into_iter()
+            // Flatten the receipts after filtering on output messages
+            // and mapping to message ids.
+            .flat_map(|receipts|
+                receipts.into_iter().filter_map(|r| r.message_id()));
+
+        // Build the merkle tree from the above iterator.
+        let mut tree = MerkleTree::new();
+        let mut indexes = HashMap::new();
+
+        for (index, id) in leaves.enumerate() {
             // Save the index of this message to use as the proof index.
-            proof_index = Some(index as u64);
+            indexes.insert(id, index as u64);
+
+            // Build the merkle tree.
+            tree.push(id.as_ref());
         }
 
-        // Build the merkle tree.
-        tree.push(id.as_ref());
+        Ok(Self {
+            header,
+            tree,
+            leaves: indexes,
+        })
     }
 
-    // If we found the leaf proof index then return the proof.
-    match proof_index {
-        Some(proof_index) => {
-            // Generate the actual merkle proof.
-            match tree.prove(proof_index) {
-                Some((_, proof_set)) => Ok(Some(MerkleProof {
-                    proof_set,
-                    proof_index,
-                })),
-                None => Ok(None),
-            }
-        }
-        None => Ok(None),
+    /// Generates the merkle proof of the message in the block.
+    fn prove(&self, message_id: &MessageId) -> Option<MerkleProof> {
+        let proof_index = *self.leaves.get(message_id)?;
+        let (_, proof_set) = self.tree.prove(proof_index)?;
+        Some(MerkleProof {
+            proof_set,
+            proof_index,
+        })
     }
 }
 
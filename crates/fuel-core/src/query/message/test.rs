@@ -234,3 +234,87 @@ async fn can_build_message_proof() {
     );
     assert_eq!(proof.block_proof, block_proof);
 }
+
+#[tokio::test]
+async fn message_proofs_share_block_data() {
+    use mockall::predicate::*;
+    let commit_block_height = BlockHeight::from(2u32);
+    let message_block_height = BlockHeight::from(1u32);
+    let receipts: [Receipt; 3] = [receipt(Some(1)), receipt(None), receipt(Some(2))];
+    let transaction_id = txn_id(7);
+
+    let message_ids: Vec<MessageId> =
+        receipts.iter().filter_map(|r| r.message_id()).collect();
+
+    let mut data = MockProofDataStorage::new();
+    data.expect_receipts()
+        .with(eq(transaction_id))
+        .returning(move |_| Ok(receipts.to_vec()));
+
+    let header = |height: BlockHeight, message_ids: &[MessageId]| {
+        PartialBlockHeader {
+            application: ApplicationHeader {
+                da_height: 0u64.into(),
+                generated: Default::default(),
+            },
+            consensus: ConsensusHeader {
+                prev_root: Bytes32::zeroed(),
+                height,
+                time: Tai64::UNIX_EPOCH,
+                generated: Default::default(),
+            },
+        }
+        .generate(&[], message_ids)
+    };
+    let commit_block = CompressedBlock::test(header(commit_block_height, &[]), vec![]);
+    let message_block = CompressedBlock::test(
+        header(message_block_height, &message_ids),
+        vec![transaction_id],
+    );
+
+    let message_block_id = message_block.id();
+    data.expect_transaction_status()
+        .with(eq(transaction_id))
+        .returning(move |_| {
+            Ok(TransactionStatus::Success {
+                block_id: message_block_id,
+                time: Tai64::UNIX_EPOCH,
+                result: None,
+            })
+        });
+
+    // The blocks and the block proof are requested only once for all messages.
+    data.expect_block()
+        .times(1)
+        .with(eq(commit_block_height))
+        .returning(move |_| Ok(commit_block.clone()));
+    data.expect_block_by_id()
+        .times(1)
+        .with(eq(message_block_id))
+        .returning(move |_| Ok(message_block.clone()));
+    data.expect_block_history_proof()
+        .once()
+        .with(
+            eq(message_block_height),
+            eq(commit_block_height.pred().expect("Non-zero block height")),
+        )
+        .returning(|_, _| Ok(MerkleProof::default()));
+
+    let data: Box<dyn MessageProofData> = Box::new(data);
+
+    let messages = [
+        (transaction_id, Nonce::new([2; 32])),
+        (transaction_id, Nonce::new([1; 32])),
+        (transaction_id, Nonce::new([3; 32])),
+    ];
+    let proofs = message_proofs(data.deref(), messages, commit_block_height).unwrap();
+
+    assert_eq!(proofs.len(), 3);
+    let proof = proofs[0].as_ref().expect("The first message has a proof");
+    assert_eq!(proof.nonce, messages[0].1);
+    assert_eq!(proof.message_proof.proof_index, 1);
+    let proof = proofs[1].as_ref().expect("The second message has a proof");
+    assert_eq!(proof.nonce, messages[1].1);
+    assert_eq!(proof.message_proof.proof_index, 0);
+    assert!(proofs[2].is_none(), "The third message doesn't exist");
+}
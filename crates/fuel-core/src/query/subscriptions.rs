@@ -2,7 +2,10 @@ use crate::schema::tx::types::TransactionStatus as ApiTxStatus;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::service::TxStatusMessage;
 use fuel_core_types::{
-    fuel_types::Bytes32,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
     services::txpool::TransactionStatus as TxPoolTxStatus,
 };
 use futures::{
@@ -10,6 +13,10 @@ use futures::{
     Stream,
     StreamExt,
 };
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
 
 #[cfg(test)]
 mod test;
@@ -79,3 +86,50 @@ where
             }
         })
 }
+
+/// The result of the check done by the [`block_updates`] stream.
+pub(crate) enum BlockUpdate<T> {
+    /// Nothing has changed, wait for the next block.
+    Skip,
+    /// Return the item and wait for the next block.
+    Next(T),
+    /// Return the item and end the stream.
+    Last(T),
+}
+
+/// Returns a stream that runs the `check` right away and after each block processed
+/// by the off-chain worker, until the `check` returns the [`BlockUpdate::Last`].
+///
+/// The notifications about the blocks are skipped if the stream is polled slower than
+/// the blocks arrive, so the `check` should compare the latest state of the database
+/// with the previous one instead of relying on the height of the block.
+pub(crate) fn block_updates<'a, T, F>(
+    blocks: broadcast::Receiver<BlockHeight>,
+    check: F,
+) -> impl Stream<Item = T> + 'a
+where
+    T: Send + 'a,
+    F: FnMut() -> BlockUpdate<T> + Send + 'a,
+{
+    // The flag tells whether to wait for the next block before the `check`.
+    futures::stream::unfold(Some((blocks, check, false)), |state| async move {
+        let (mut blocks, mut check, mut wait) = state?;
+        loop {
+            if wait {
+                match blocks.recv().await {
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+            wait = true;
+
+            match check() {
+                BlockUpdate::Skip => {}
+                BlockUpdate::Next(item) => {
+                    return Some((item, Some((blocks, check, true))))
+                }
+                BlockUpdate::Last(item) => return Some((item, None)),
+            }
+        }
+    })
+}
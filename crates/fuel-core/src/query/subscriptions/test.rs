@@ -21,7 +21,10 @@
 //! - `input_stream()`: Generates a Vec<TxStatusMessage> of length 0 to 5
 use fuel_core_txpool::service::TxStatusMessage;
 use fuel_core_types::{
-    fuel_types::Bytes32,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
     services::txpool::TransactionStatus,
     tai64::Tai64,
 };
@@ -275,3 +278,40 @@ impl From<crate::schema::tx::types::TransactionStatus> for TxStatus {
         }
     }
 }
+
+#[tokio::test]
+async fn block_updates_checks_after_each_block_until_last() {
+    use super::BlockUpdate;
+
+    let (sender, receiver) = tokio::sync::broadcast::channel(10);
+    let mut checks = 0;
+    let stream = super::block_updates(receiver, move || {
+        checks += 1;
+        match checks {
+            1 => BlockUpdate::Next(checks),
+            4 => BlockUpdate::Last(checks),
+            _ => BlockUpdate::Skip,
+        }
+    });
+
+    for height in 1..=5u32 {
+        sender.send(height.into()).unwrap();
+    }
+    let updates: Vec<_> = stream.collect().await;
+
+    // The first check is done right away, and the next ones after each block.
+    assert_eq!(updates, vec![1, 4]);
+}
+
+#[tokio::test]
+async fn block_updates_ends_when_blocks_are_closed() {
+    use super::BlockUpdate;
+
+    let (sender, receiver) = tokio::sync::broadcast::channel::<BlockHeight>(10);
+    let stream = super::block_updates(receiver, || BlockUpdate::Next(()));
+    drop(sender);
+
+    let updates: Vec<_> = stream.collect().await;
+
+    assert_eq!(updates, vec![()]);
+}
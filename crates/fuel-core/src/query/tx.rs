@@ -23,7 +23,11 @@ use fuel_core_types::{
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        Bytes32,
+        ContractId,
+    },
     services::txpool::TransactionStatus,
 };
 
@@ -61,6 +65,27 @@ pub trait TransactionQueryData: Send + Sync + SimpleTransactionData {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    fn predicate_transactions(
+        &self,
+        predicate_root: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    fn script_transactions(
+        &self,
+        script_hash: Bytes32,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
 }
 
 impl<D> TransactionQueryData for D
@@ -87,4 +112,54 @@ where
             })
             .into_boxed()
     }
+    fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
+        self.contract_transactions_ids(contract_id, start, direction)
+            .map(|result| {
+                result.and_then(|(tx_pointer, tx_id)| {
+                    let tx = self.transaction(&tx_id)?;
+
+                    Ok((tx_pointer, tx))
+                })
+            })
+            .into_boxed()
+    }
+
+    fn predicate_transactions(
+        &self,
+        predicate_root: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
+        self.predicate_transactions_ids(predicate_root, start, direction)
+            .map(|result| {
+                result.and_then(|(tx_pointer, tx_id)| {
+                    let tx = self.transaction(&tx_id)?;
+
+                    Ok((tx_pointer, tx))
+                })
+            })
+            .into_boxed()
+    }
+
+    fn script_transactions(
+        &self,
+        script_hash: Bytes32,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
+        self.script_transactions_ids(script_hash, start, direction)
+            .map(|result| {
+                result.and_then(|(tx_pointer, tx_id)| {
+                    let tx = self.transaction(&tx_id)?;
+
+                    Ok((tx_pointer, tx))
+                })
+            })
+            .into_boxed()
+    }
 }
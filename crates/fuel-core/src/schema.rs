@@ -1,3 +1,7 @@
+use crate::fuel_core_graphql_api::{
+    limit_exceeded,
+    Config as GraphQLConfig,
+};
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
@@ -7,6 +11,7 @@ use async_graphql::{
         Edge,
         EmptyFields,
     },
+    Context,
     MergedObject,
     MergedSubscription,
     OutputType,
@@ -19,6 +24,7 @@ use fuel_core_storage::{
 };
 use itertools::Itertools;
 
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain;
@@ -34,6 +40,7 @@ pub mod tx;
 #[derive(MergedObject, Default)]
 pub struct Query(
     dap::DapQuery,
+    asset::AssetQuery,
     balance::BalanceQuery,
     block::BlockQuery,
     chain::ChainQuery,
@@ -50,7 +57,7 @@ pub struct Query(
 pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(tx::TxStatusSubscription, message::MessageStatusSubscription);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -64,7 +71,24 @@ pub fn build_schema() -> CoreSchemaBuilder {
     )
 }
 
+pub(crate) const PAGE_SIZE_EXCEEDED: &str = "PAGE_SIZE_EXCEEDED";
+pub(crate) const DRY_RUN_TRANSACTIONS_EXCEEDED: &str = "DRY_RUN_TRANSACTIONS_EXCEEDED";
+pub(crate) const DRY_RUN_REWIND_EXCEEDED: &str = "DRY_RUN_REWIND_EXCEEDED";
+
+/// The complexity of the paginated field: the complexity of one node multiplied by the page size.
+pub(crate) fn page_complexity(
+    first: Option<i32>,
+    last: Option<i32>,
+    child_complexity: usize,
+) -> usize {
+    let page_size = first.or(last).unwrap_or_default();
+    usize::try_from(page_size)
+        .unwrap_or_default()
+        .saturating_mul(child_complexity)
+}
+
 async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
+    ctx: &Context<'_>,
     after: Option<String>,
     before: Option<String>,
     first: Option<i32>,
@@ -107,6 +131,15 @@ where
         (_, _, _, _) => { /* Other combinations are allowed */ }
     };
 
+    let max_page_size = ctx.data_unchecked::<GraphQLConfig>().limits.max_page_size;
+    let page_size = first.or(last).unwrap_or_default();
+    if usize::try_from(page_size).map_or(false, |size| size > max_page_size) {
+        return Err(limit_exceeded(
+            PAGE_SIZE_EXCEEDED,
+            format!("The page size {page_size} exceeds the limit {max_page_size}"),
+        ))
+    }
+
     query(
         after,
         before,
@@ -0,0 +1,129 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        require_index,
+        IntoApiResult,
+    },
+    query::AssetQueryData,
+    schema::scalars::{
+        AssetId,
+        Bytes32,
+        ContractId,
+        U128,
+        U64,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Object,
+};
+use fuel_core_types::{
+    fuel_types,
+    services::graphql_api::{
+        AssetDetails,
+        OffChainIndex,
+    },
+};
+
+pub struct Asset {
+    id: fuel_types::AssetId,
+    details: AssetDetails,
+}
+
+#[Object]
+impl Asset {
+    async fn id(&self) -> AssetId {
+        self.id.into()
+    }
+
+    /// The contract that minted the asset, if the asset was minted by a contract.
+    async fn contract_id(&self) -> Option<ContractId> {
+        self.details.contract_id.map(Into::into)
+    }
+
+    /// The sub identifier of the asset within the minting contract.
+    async fn sub_id(&self) -> Option<Bytes32> {
+        self.details.sub_id.map(Into::into)
+    }
+
+    /// The cumulative amount minted by the contract.
+    async fn total_minted(&self) -> U128 {
+        self.details.total_minted.into()
+    }
+
+    /// The cumulative amount burned by the contract.
+    async fn total_burned(&self) -> U128 {
+        self.details.total_burned.into()
+    }
+
+    /// The amount minted by the contract and not burned yet.
+    async fn total_supply(&self) -> U128 {
+        self.details.total_supply().into()
+    }
+
+    /// The number of owners with unspent coins of the asset.
+    async fn holders(&self, ctx: &Context<'_>) -> async_graphql::Result<U64> {
+        require_index(ctx, OffChainIndex::CoinsByAmount)?;
+        Ok(self.details.holders.into())
+    }
+}
+
+#[derive(Default)]
+pub struct AssetQuery;
+
+#[Object]
+impl AssetQuery {
+    /// Returns the information about the asset aggregated from the executed transactions.
+    async fn asset(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Asset")] id: AssetId,
+    ) -> async_graphql::Result<Option<Asset>> {
+        require_index(ctx, OffChainIndex::Assets)?;
+        let query: &ReadView = ctx.data_unchecked();
+        let id = id.into();
+        query
+            .asset(&id)
+            .into_api_result::<AssetDetails, _>()
+            .map(|details| details.map(|details| Asset { id, details }))
+    }
+
+    /// Returns the assets known by the node ordered by the asset id.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
+    async fn assets(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<AssetId, Asset, EmptyFields, EmptyFields>> {
+        require_index(ctx, OffChainIndex::Assets)?;
+        let query: &ReadView = ctx.data_unchecked();
+
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let assets =
+                    query
+                        .assets((*start).map(Into::into), direction)
+                        .map(|result| {
+                            result.map(|(id, details)| (id.into(), Asset { id, details }))
+                        });
+
+                Ok(assets)
+            },
+        )
+        .await
+    }
+}
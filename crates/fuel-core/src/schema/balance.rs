@@ -69,6 +69,9 @@ impl BalanceQuery {
     //  It should be replaced with `UtxoId`.
     //  This API should be migrated to the indexer for better support and
     //  discontinued within fuel-core.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn balances(
         &self,
         ctx: &Context<'_>,
@@ -83,18 +86,25 @@ impl BalanceQuery {
             return Err(anyhow!("pagination is not yet supported").into())
         }
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |_, direction| {
-            let owner = filter.owner.into();
-            let base_asset_id = *ctx
-                .data_unchecked::<Config>()
-                .consensus_parameters
-                .base_asset_id();
-            Ok(query
-                .balances(owner, direction, base_asset_id)
-                .map(|result| {
-                    result.map(|balance| (balance.asset_id.into(), balance.into()))
-                }))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |_, direction| {
+                let owner = filter.owner.into();
+                let base_asset_id = *ctx
+                    .data_unchecked::<Config>()
+                    .consensus_parameters
+                    .base_asset_id();
+                Ok(query
+                    .balances(owner, direction, base_asset_id)
+                    .map(|result| {
+                        result.map(|balance| (balance.asset_id.into(), balance.into()))
+                    }))
+            },
+        )
         .await
     }
 }
@@ -7,7 +7,11 @@ use crate::{
     fuel_core_graphql_api::{
         api_service::ConsensusModule,
         database::ReadView,
-        ports::DatabaseBlocks,
+        ports::{
+            DatabaseBlocks,
+            OffChainDatabase,
+        },
+        require_index,
         Config as GraphQLConfig,
         IntoApiResult,
     },
@@ -43,6 +47,7 @@ use fuel_core_storage::{
         IntoBoxedIter,
         IterDirection,
     },
+    Error as StorageError,
     Result as StorageResult,
 };
 use fuel_core_types::{
@@ -52,6 +57,7 @@ use fuel_core_types::{
     },
     fuel_types,
     fuel_types::BlockHeight,
+    services::graphql_api::OffChainIndex,
 };
 
 pub struct Block(pub(crate) CompressedBlock);
@@ -193,21 +199,29 @@ impl BlockQuery {
         ctx: &Context<'_>,
         #[graphql(desc = "ID of the block")] id: Option<BlockId>,
         #[graphql(desc = "Height of the block")] height: Option<U32>,
+        #[graphql(desc = "Returns the first block produced at or after the time")]
+        time: Option<Tai64Timestamp>,
     ) -> async_graphql::Result<Option<Block>> {
         let query: &ReadView = ctx.data_unchecked();
-        let height = match (id, height) {
-            (Some(_), Some(_)) => {
-                return Err(async_graphql::Error::new(
-                    "Can't provide both an id and a height",
-                ))
-            }
-            (Some(id), None) => query.block_height(&id.0.into()),
-            (None, Some(height)) => {
+        let height = match (id, height, time) {
+            (Some(id), None, None) => query.block_height(&id.0.into()),
+            (None, Some(height), None) => {
                 let height: u32 = height.into();
                 Ok(height.into())
             }
-            (None, None) => {
-                return Err(async_graphql::Error::new("Missing either id or height"))
+            (None, None, Some(time)) => {
+                require_index(ctx, OffChainIndex::BlockTimes)?;
+                query.block_height_by_time(time.0, IterDirection::Forward)
+            }
+            (None, None, None) => {
+                return Err(async_graphql::Error::new(
+                    "Missing either id, height or time",
+                ))
+            }
+            _ => {
+                return Err(async_graphql::Error::new(
+                    "Can't provide more than one of id, height or time",
+                ))
             }
         };
 
@@ -216,22 +230,94 @@ impl BlockQuery {
             .into_api_result()
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn blocks(
         &self,
         ctx: &Context<'_>,
+        filter: Option<BlockFilterInput>,
         first: Option<i32>,
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
     ) -> async_graphql::Result<Connection<U32, Block, EmptyFields, EmptyFields>> {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(query, start.map(Into::into), direction))
-        })
+        let range = match filter {
+            Some(filter) => {
+                require_index(ctx, OffChainIndex::BlockTimes)?;
+                filter.height_range(query)?
+            }
+            None => Some(HeightRange::default()),
+        };
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                Ok(blocks_in_range_query(
+                    query,
+                    start.map(Into::into),
+                    direction,
+                    range,
+                ))
+            },
+        )
         .await
     }
 }
 
+#[derive(async_graphql::InputObject)]
+struct BlockFilterInput {
+    /// Returns blocks produced at or after the time.
+    from_time: Option<Tai64Timestamp>,
+    /// Returns blocks produced at or before the time.
+    to_time: Option<Tai64Timestamp>,
+}
+
+/// The inclusive range of heights of the blocks. The bound is not limited if it is `None`.
+#[derive(Clone, Copy, Default)]
+struct HeightRange {
+    from: Option<BlockHeight>,
+    to: Option<BlockHeight>,
+}
+
+impl HeightRange {
+    fn contains(&self, height: BlockHeight) -> bool {
+        self.from.map_or(true, |from| from <= height)
+            && self.to.map_or(true, |to| height <= to)
+    }
+}
+
+impl BlockFilterInput {
+    /// Converts the time range into the range of heights. The time of blocks never
+    /// decreases with the height, so the time range of blocks is a range of heights.
+    /// Returns `None` if there are no blocks in the time range.
+    fn height_range(
+        &self,
+        query: &ReadView,
+    ) -> async_graphql::Result<Option<HeightRange>> {
+        let from = self
+            .from_time
+            .map(|time| query.block_height_by_time(time.0, IterDirection::Forward))
+            .transpose();
+        let to = self
+            .to_time
+            .map(|time| query.block_height_by_time(time.0, IterDirection::Reverse))
+            .transpose();
+
+        match (from, to) {
+            (Ok(Some(from)), Ok(Some(to))) if from > to => Ok(None),
+            (Ok(from), Ok(to)) => Ok(Some(HeightRange { from, to })),
+            (Err(StorageError::NotFound(_, _)), _)
+            | (_, Err(StorageError::NotFound(_, _))) => Ok(None),
+            (Err(err), _) | (_, Err(err)) => Err(err.into()),
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct HeaderQuery;
 
@@ -244,11 +330,14 @@ impl HeaderQuery {
         #[graphql(desc = "Height of the block")] height: Option<U32>,
     ) -> async_graphql::Result<Option<Header>> {
         Ok(BlockQuery {}
-            .block(ctx, id, height)
+            .block(ctx, id, height, None)
             .await?
             .map(|b| b.0.header().clone().into()))
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn headers(
         &self,
         ctx: &Context<'_>,
@@ -258,9 +347,14 @@ impl HeaderQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<U32, Header, EmptyFields, EmptyFields>> {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(query, start.map(Into::into), direction))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| Ok(blocks_query(query, start.map(Into::into), direction)),
+        )
         .await
     }
 }
@@ -281,6 +375,40 @@ where
     blocks.into_boxed()
 }
 
+fn blocks_in_range_query<'a, T>(
+    query: &'a ReadView,
+    height: Option<BlockHeight>,
+    direction: IterDirection,
+    range: Option<HeightRange>,
+) -> BoxedIter<'a, StorageResult<(U32, T)>>
+where
+    T: async_graphql::OutputType + 'a,
+    T: From<CompressedBlock>,
+{
+    let Some(range) = range else {
+        return core::iter::empty().into_boxed()
+    };
+
+    // Start the iteration from the bound of the range if the cursor is outside of it.
+    let start = match direction {
+        IterDirection::Forward => match (height, range.from) {
+            (Some(height), Some(from)) => Some(height.max(from)),
+            (height, from) => height.or(from),
+        },
+        IterDirection::Reverse => match (height, range.to) {
+            (Some(height), Some(to)) => Some(height.min(to)),
+            (height, to) => height.or(to),
+        },
+    };
+
+    blocks_query(query, start, direction)
+        .take_while(move |result| match result {
+            Ok((height, _)) => range.contains(height.0.into()),
+            Err(_) => true,
+        })
+        .into_boxed()
+}
+
 #[derive(Default)]
 pub struct BlockMutation;
 
@@ -1,10 +1,13 @@
 use crate::{
     coins_query::{
-        random_improve,
+        self,
+        select_coins,
         SpendQuery,
     },
     fuel_core_graphql_api::{
+        api_service::TxPool,
         database::ReadView,
+        is_index_ready,
         Config as GraphQLConfig,
         IntoApiResult,
     },
@@ -37,6 +40,7 @@ use fuel_core_types::{
         },
     },
     fuel_tx,
+    services::graphql_api::OffChainIndex,
 };
 use itertools::Itertools;
 
@@ -141,6 +145,32 @@ pub struct ExcludeInput {
     messages: Vec<Nonce>,
 }
 
+/// The strategy used to select the coins to spend.
+#[derive(Copy, Clone, Debug, Default, async_graphql::Enum, Eq, PartialEq)]
+pub enum CoinSelectionStrategy {
+    /// Uses the biggest coins to reach the target.
+    LargestFirst,
+    /// Uses random coins, preferring the selection with the change close to the target.
+    #[default]
+    RandomImprove,
+    /// Uses as many of the smallest coins as allowed by `max` to consolidate the dust.
+    SmallestFirst,
+    /// Uses the coins that add up exactly to the target to avoid the change output.
+    /// Falls back to `RANDOM_IMPROVE` if there are no such coins.
+    ExactMatch,
+}
+
+impl From<CoinSelectionStrategy> for coins_query::CoinSelectionStrategy {
+    fn from(value: CoinSelectionStrategy) -> Self {
+        match value {
+            CoinSelectionStrategy::LargestFirst => Self::LargestFirst,
+            CoinSelectionStrategy::RandomImprove => Self::RandomImprove,
+            CoinSelectionStrategy::SmallestFirst => Self::SmallestFirst,
+            CoinSelectionStrategy::ExactMatch => Self::ExactMatch,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct CoinQuery;
 
@@ -157,6 +187,9 @@ impl CoinQuery {
     }
 
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn coins(
         &self,
         ctx: &Context<'_>,
@@ -167,24 +200,32 @@ impl CoinQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let owner: fuel_tx::Address = filter.owner.into();
-            let coins = query
-                .owned_coins(&owner, (*start).map(Into::into), direction)
-                .filter_map(|result| {
-                    if let (Ok(coin), Some(filter_asset_id)) = (&result, &filter.asset_id)
-                    {
-                        if coin.asset_id != filter_asset_id.0 {
-                            return None
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let owner: fuel_tx::Address = filter.owner.into();
+                let coins = query
+                    .owned_coins(&owner, (*start).map(Into::into), direction)
+                    .filter_map(|result| {
+                        if let (Ok(coin), Some(filter_asset_id)) =
+                            (&result, &filter.asset_id)
+                        {
+                            if coin.asset_id != filter_asset_id.0 {
+                                return None
+                            }
                         }
-                    }
 
-                    Some(result)
-                })
-                .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
+                        Some(result)
+                    })
+                    .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
 
-            Ok(coins)
-        })
+                Ok(coins)
+            },
+        )
         .await
     }
 
@@ -192,7 +233,9 @@ impl CoinQuery {
     /// `owner` that add up at least the query amount. The returned coins can be spent.
     /// The number of coins is optimized to prevent dust accumulation.
     ///
-    /// The query supports excluding and maximum the number of coins.
+    /// The query supports excluding and maximum the number of coins. The coins used by
+    /// the pending transactions in the `TxPool` can be excluded as well. The `strategy`
+    /// defines how the coins are selected.
     ///
     /// Returns:
     ///     The list of spendable coins per asset from the query. The length of the result is
@@ -210,6 +253,13 @@ impl CoinQuery {
         #[graphql(desc = "The excluded coins from the selection.")] excluded_ids: Option<
             ExcludeInput,
         >,
+        #[graphql(desc = "\
+            Exclude the coins and messages already used as inputs by the pending \
+            transactions in the `TxPool`.")]
+        exclude_pending_inputs: Option<bool>,
+        #[graphql(desc = "\
+            The strategy used to select the coins. The `RANDOM_IMPROVE` is used by default.")]
+        strategy: Option<CoinSelectionStrategy>,
     ) -> async_graphql::Result<Vec<Vec<CoinType>>> {
         let config = ctx.data_unchecked::<GraphQLConfig>();
 
@@ -237,12 +287,22 @@ impl CoinQuery {
         });
 
         let base_asset_id = config.consensus_parameters.base_asset_id();
-        let spend_query =
+        let mut spend_query =
             SpendQuery::new(owner, &query_per_asset, excluded_ids, *base_asset_id)?;
+        if exclude_pending_inputs.unwrap_or(false) {
+            let txpool = ctx.data_unchecked::<TxPool>();
+            spend_query.exclude_pending_inputs(txpool.as_ref());
+        }
+        // All strategies read the coins from the index ordered by the amount
+        // if it is built, otherwise all coins of the owner are loaded.
+        if !is_index_ready(ctx, OffChainIndex::CoinsByAmount)? {
+            spend_query.without_by_amount_index();
+        }
 
         let query: &ReadView = ctx.data_unchecked();
 
-        let coins = random_improve(query, &spend_query)?
+        let strategy = strategy.unwrap_or_default().into();
+        let coins = select_coins(query, &spend_query, strategy)?
             .into_iter()
             .map(|coins| {
                 coins
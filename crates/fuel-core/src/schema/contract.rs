@@ -124,6 +124,9 @@ impl ContractBalanceQuery {
             })
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn contract_balances(
         &self,
         ctx: &Context<'_>,
@@ -137,22 +140,29 @@ impl ContractBalanceQuery {
     > {
         let query: &ReadView = ctx.data_unchecked();
 
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let balances = query
-                .contract_balances(
-                    filter.contract.into(),
-                    (*start).map(Into::into),
-                    direction,
-                )
-                .map(move |balance| {
-                    let balance = balance?;
-                    let asset_id = balance.asset_id;
-
-                    Ok((asset_id.into(), balance.into()))
-                });
-
-            Ok(balances)
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let balances = query
+                    .contract_balances(
+                        filter.contract.into(),
+                        (*start).map(Into::into),
+                        direction,
+                    )
+                    .map(move |balance| {
+                        let balance = balance?;
+                        let asset_id = balance.asset_id;
+
+                        Ok((asset_id.into(), balance.into()))
+                    });
+
+                Ok(balances)
+            },
+        )
         .await
     }
 }
@@ -1,9 +1,20 @@
 use crate::{
     database::{
+        history::StateRewindPolicy,
         transaction::DatabaseTransaction,
         Database,
     },
-    schema::scalars::U64,
+    schema::scalars::{
+        AssetId,
+        Bytes32,
+        ContractId,
+        TransactionId,
+        U64,
+    },
+    service::{
+        adapters::ExecutorAdapter,
+        config::DebuggerConfig,
+    },
 };
 use async_graphql::{
     Context,
@@ -13,37 +24,103 @@ use async_graphql::{
 };
 use fuel_core_storage::{
     not_found,
+    tables::{
+        ContractsAssets,
+        ContractsState,
+        FuelBlocks,
+        Transactions,
+    },
     vm_storage::VmStorage,
+    ContractsAssetKey,
+    ContractsStateKey,
     InterpreterStorage,
+    StorageAsRef,
 };
 use fuel_core_types::{
+    blockchain::header::{
+        ApplicationHeader,
+        ConsensusHeader,
+        PartialBlockHeader,
+    },
     fuel_asm::{
         Instruction,
+        PanicReason,
+        RegId,
         RegisterId,
         Word,
     },
     fuel_tx::{
+        field::{
+            InputContract,
+            ScriptGasLimit,
+        },
         Buildable,
         ConsensusParameters,
+        Create,
         Executable,
+        Input,
+        Receipt,
         Script,
+        ScriptExecutionResult,
         Transaction,
+        TxId,
+    },
+    fuel_types::{
+        self,
+        canonical::Deserialize,
+        BlockHeight,
     },
     fuel_vm::{
         checked_transaction::{
+            CheckPredicateParams,
             CheckedTransaction,
             IntoChecked,
         },
         consts,
+        context::Context as VmContext,
+        interpreter::ExecutableTransaction,
+        predicate::RuntimePredicate,
+        state::{
+            Debugger,
+            ExecuteState,
+        },
+        Breakpoint,
+        CallFrame,
         Interpreter,
         InterpreterError,
+        PredicateStorage,
+        PredicateVerificationFailed,
+        ProgramState,
     },
+    services::txpool::TransactionStatus,
+};
+use fuel_core_services::{
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use futures::lock::{
+    Mutex,
+    OwnedMutexGuard,
 };
-use futures::lock::Mutex;
 use std::{
-    collections::HashMap,
-    io,
-    sync,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::{
+        self,
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+    },
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tracing::{
     debug,
@@ -54,84 +131,252 @@ use uuid::Uuid;
 use crate::schema::scalars::U32;
 use fuel_core_types::fuel_vm::state::DebugEval;
 
+/// The size of the instruction in bytes, the VM uses it to address the code.
+const INSTRUCTION_SIZE: Word = Instruction::SIZE as Word;
+
 pub struct Config {
     /// `true` means that debugger functionality is enabled.
     debug_enabled: bool,
 }
 
-#[derive(Debug, Clone, Default)]
-pub struct ConcreteStorage {
-    vm: HashMap<ID, Interpreter<VmStorage<Database>, Script>>,
-    tx: HashMap<ID, Vec<Script>>,
-    db: HashMap<ID, DatabaseTransaction>,
-    params: ConsensusParameters,
+/// The way the script of the session is run.
+#[derive(Debug, Clone, Copy)]
+enum RunMode {
+    /// The VM runs the script with `transact` and `resume` and stops on the
+    /// breakpoints of the script, `stop` is the breakpoint where it stopped.
+    Vm { stop: Option<Breakpoint> },
+    /// The session steps through the script. The VM ignores its breakpoints inside
+    /// of the contract calls, so the session steps only when it must stop there.
+    Stepped,
 }
 
-impl ConcreteStorage {
-    pub fn new(params: ConsensusParameters) -> Self {
-        Self {
-            params,
-            ..Default::default()
+/// The evaluation of the predicate of the transaction input.
+#[derive(Debug)]
+pub struct PredicateRun {
+    vm: PredicateVm,
+    _memory: MemoryReservation,
+    input_index: u32,
+    /// The end of the predicate code in the memory.
+    program_end: Word,
+}
+
+#[derive(Debug)]
+enum PredicateVm {
+    Script(Interpreter<PredicateStorage, Script>),
+    Create(Interpreter<PredicateStorage, Create>),
+}
+
+impl PredicateVm {
+    fn registers(&self) -> &[Word] {
+        match self {
+            PredicateVm::Script(vm) => vm.registers(),
+            PredicateVm::Create(vm) => vm.registers(),
         }
     }
 
-    pub fn register(&self, id: &ID, register: RegisterId) -> Option<Word> {
-        self.vm
-            .get(id)
-            .and_then(|vm| vm.registers().get(register).copied())
+    fn memory(&self) -> &[u8] {
+        match self {
+            PredicateVm::Script(vm) => vm.memory(),
+            PredicateVm::Create(vm) => vm.memory(),
+        }
     }
+}
 
-    pub fn memory(&self, id: &ID, start: usize, size: usize) -> Option<&[u8]> {
-        let (end, overflow) = start.overflowing_add(size);
-        if overflow || end as u64 > consts::VM_MAX_RAM {
-            return None
+/// Accounts the memory allocated by the VMs of all debug sessions.
+#[derive(Debug, Clone)]
+struct MemoryAccounting {
+    used: sync::Arc<AtomicUsize>,
+    limit: usize,
+}
+
+impl MemoryAccounting {
+    fn new(limit: usize) -> Self {
+        Self {
+            used: Default::default(),
+            limit,
         }
+    }
+
+    /// Reserves the memory for a new VM, fails if it exceeds the limit.
+    fn reserve_vm(&self) -> anyhow::Result<MemoryReservation> {
+        let size = consts::MEM_SIZE;
+        self.used
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                used.checked_add(size).filter(|used| *used <= self.limit)
+            })
+            .map_err(|used| {
+                anyhow::anyhow!(
+                    "The debug sessions already use {used} bytes of memory \
+                    and the limit is {} bytes",
+                    self.limit
+                )
+            })?;
+
+        Ok(MemoryReservation {
+            used: self.used.clone(),
+            size,
+        })
+    }
 
-        self.vm.get(id).map(|vm| &vm.memory()[start..end])
+    fn used(&self) -> usize {
+        self.used.load(Ordering::SeqCst)
     }
+}
 
-    pub fn init(
-        &mut self,
+/// The memory reserved for the VM, it is released when the VM is dropped.
+#[derive(Debug)]
+struct MemoryReservation {
+    used: sync::Arc<AtomicUsize>,
+    size: usize,
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.used.fetch_sub(self.size, Ordering::SeqCst);
+    }
+}
+
+/// The state of the debug session. Each session is locked separately,
+/// so the execution in one session doesn't block the others.
+#[derive(Debug)]
+pub struct Session {
+    vm: Interpreter<VmStorage<Database>, Script>,
+    _vm_memory: MemoryReservation,
+    /// The breakpoints and the single-stepping mode of the session. The session
+    /// evaluates them itself when it steps through the script or the predicate.
+    debugger: Debugger,
+    /// The breakpoints of the `debugger`, they are set to the VM before the run.
+    breakpoints: HashSet<Breakpoint>,
+    /// How the current script is run.
+    run: RunMode,
+    /// The predicate evaluated by the session instead of the transaction.
+    predicate: Option<PredicateRun>,
+    tx: Vec<Script>,
+    db: DatabaseTransaction,
+    params: ConsensusParameters,
+    memory: MemoryAccounting,
+}
+
+impl Session {
+    fn new(
         txs: &[Script],
         storage: DatabaseTransaction,
-    ) -> anyhow::Result<ID> {
-        let id = Uuid::new_v4();
-        let id = ID::from(id);
-
+        params: ConsensusParameters,
+        memory: MemoryAccounting,
+    ) -> anyhow::Result<Self> {
+        let vm_memory = memory.reserve_vm()?;
         let vm_database = Self::vm_database(&storage)?;
         let tx = Self::dummy_tx();
         let checked_tx = tx
-            .into_checked_basic(vm_database.block_height()?, &self.params)
+            .into_checked_basic(vm_database.block_height()?, &params)
             .map_err(|e| anyhow::anyhow!("{:?}", e))?;
-        self.tx
-            .get_mut(&id)
-            .map(|tx| tx.extend_from_slice(txs))
-            .unwrap_or_else(|| {
-                self.tx.insert(id.clone(), txs.to_owned());
-            });
 
-        let mut vm = Interpreter::with_storage(vm_database, (&self.params).into());
+        let mut vm = Interpreter::with_storage(vm_database, (&params).into());
         vm.transact(checked_tx).map_err(|e| anyhow::anyhow!(e))?;
-        self.vm.insert(id.clone(), vm);
-        self.db.insert(id.clone(), storage);
 
-        Ok(id)
+        Ok(Self {
+            vm,
+            _vm_memory: vm_memory,
+            debugger: Debugger::default(),
+            breakpoints: Default::default(),
+            run: RunMode::Vm { stop: None },
+            predicate: None,
+            tx: txs.to_owned(),
+            db: storage,
+            params,
+            memory,
+        })
     }
 
-    pub fn kill(&mut self, id: &ID) -> bool {
-        self.tx.remove(id);
-        self.vm.remove(id);
-        self.db.remove(id).is_some()
+    pub fn register(&self, register: RegisterId) -> Option<Word> {
+        if let Some(predicate) = &self.predicate {
+            return predicate.vm.registers().get(register).copied()
+        }
+
+        self.vm.registers().get(register).copied()
     }
 
-    pub fn reset(&mut self, id: &ID, storage: DatabaseTransaction) -> anyhow::Result<()> {
+    pub fn memory(&self, start: usize, size: usize) -> Option<&[u8]> {
+        let (end, overflow) = start.overflowing_add(size);
+        if overflow || end as u64 > consts::VM_MAX_RAM {
+            return None
+        }
+
+        if let Some(predicate) = &self.predicate {
+            return Some(&predicate.vm.memory()[start..end])
+        }
+
+        Some(&self.vm.memory()[start..end])
+    }
+
+    /// Returns the call frames of the session from the outermost to the innermost one,
+    /// along with the offset of the program counter in the code of the frame.
+    pub fn call_frames(&self) -> Option<Vec<(CallFrame, Word)>> {
+        if self.predicate.is_some() {
+            // Predicates can't call contracts
+            return Some(vec![])
+        }
+
+        let memory = self.vm.memory();
+        let mut registers = self.vm.registers().to_vec();
+        let mut frames = vec![];
+
+        // Each frame keeps the registers of the caller, including its frame pointer
+        let mut fp = usize::try_from(registers[usize::from(RegId::FP)]).ok()?;
+        while fp != 0 {
+            let frame_bytes =
+                memory.get(fp..fp.checked_add(CallFrame::serialized_size())?)?;
+            let frame = CallFrame::from_bytes(frame_bytes).ok()?;
+            let offset = registers[usize::from(RegId::PC)]
+                .saturating_sub(registers[usize::from(RegId::IS)])
+                .saturating_div(INSTRUCTION_SIZE);
+            registers = frame.registers().to_vec();
+            frames.push((frame, offset));
+
+            let caller_fp = usize::try_from(registers[usize::from(RegId::FP)]).ok()?;
+            if caller_fp >= fp {
+                // The caller frame always precedes the callee on the stack
+                return None
+            }
+            fp = caller_fp;
+        }
+
+        frames.reverse();
+        Some(frames)
+    }
+
+    pub fn contract_storage(
+        &self,
+        contract_id: &fuel_types::ContractId,
+        key: &fuel_types::Bytes32,
+    ) -> anyhow::Result<Option<fuel_types::Bytes32>> {
+        let value = self
+            .vm
+            .as_ref()
+            .storage::<ContractsState>()
+            .get(&ContractsStateKey::new(contract_id, key))?
+            .map(|value| value.into_owned());
+        Ok(value)
+    }
+
+    pub fn contract_balance(
+        &self,
+        contract_id: &fuel_types::ContractId,
+        asset_id: &fuel_types::AssetId,
+    ) -> anyhow::Result<Word> {
+        let balance = self
+            .vm
+            .as_ref()
+            .storage::<ContractsAssets>()
+            .get(&ContractsAssetKey::new(contract_id, asset_id))?
+            .map(|value| value.into_owned())
+            .unwrap_or_default();
+        Ok(balance)
+    }
+
+    pub fn reset(&mut self, storage: DatabaseTransaction) -> anyhow::Result<()> {
         let vm_database = Self::vm_database(&storage)?;
-        let tx = self
-            .tx
-            .get(id)
-            .and_then(|tx| tx.first())
-            .cloned()
-            .unwrap_or(Self::dummy_tx());
+        let tx = self.tx.first().cloned().unwrap_or(Self::dummy_tx());
 
         let checked_tx = tx
             .into_checked_basic(vm_database.block_height()?, &self.params)
@@ -139,21 +384,431 @@ impl ConcreteStorage {
 
         let mut vm = Interpreter::with_storage(vm_database, (&self.params).into());
         vm.transact(checked_tx).map_err(|e| anyhow::anyhow!(e))?;
-        self.vm.insert(id.clone(), vm).ok_or_else(|| {
-            io::Error::new(io::ErrorKind::NotFound, "The VM instance was not found")
-        })?;
-        self.db.insert(id.clone(), storage);
+        self.vm = vm;
+        self.run = RunMode::Vm { stop: None };
+        self.predicate = None;
+        self.db = storage;
         Ok(())
     }
 
-    pub fn exec(&mut self, id: &ID, op: Instruction) -> anyhow::Result<()> {
+    /// Resets the session to the state of the chain right before the execution of the
+    /// transaction `tx_id` included into the block at the `height`. The state is rebuilt
+    /// by the `executor` from the state of the previous block by executing the preceding
+    /// transactions of the block. The VM of the execution is accounted in the memory
+    /// of the debug sessions.
+    ///
+    /// It is a blocking operation.
+    pub fn reset_to_transaction(
+        &mut self,
+        database: &Database,
+        executor: &ExecutorAdapter,
+        tx_id: &TxId,
+        height: BlockHeight,
+    ) -> anyhow::Result<()> {
+        let block = database
+            .storage_as_ref::<FuelBlocks>()
+            .get(&height)?
+            .ok_or(not_found!(FuelBlocks))?
+            .into_owned();
+        // The last transaction of the block is `Mint`, it defines the coinbase contract
+        let coinbase = match block.transactions().last() {
+            Some(mint_id) => match database
+                .storage_as_ref::<Transactions>()
+                .get(mint_id)?
+                .map(|tx| tx.into_owned())
+            {
+                Some(Transaction::Mint(mint)) => mint.input_contract().contract_id,
+                _ => Default::default(),
+            },
+            None => Default::default(),
+        };
+        let position = block
+            .transactions()
+            .iter()
+            .position(|id| id == tx_id)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The transaction {tx_id} is not found in the block {height}"
+                )
+            })?;
+        let preceding_transactions = block.transactions()[..position]
+            .iter()
+            .map(|id| {
+                let tx = database
+                    .storage_as_ref::<Transactions>()
+                    .get(id)?
+                    .ok_or(not_found!(Transactions))?
+                    .into_owned();
+                Ok(tx)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let header = block.header();
+        let partial_header = PartialBlockHeader {
+            application: ApplicationHeader {
+                da_height: header.da_height,
+                generated: Default::default(),
+            },
+            consensus: ConsensusHeader {
+                prev_root: *header.prev_root(),
+                height,
+                time: header.time(),
+                generated: Default::default(),
+            },
+        };
+        let changes = {
+            let _vm_memory = self.memory.reserve_vm()?;
+            executor.executor.execute_block_prefix(
+                partial_header,
+                preceding_transactions,
+                coinbase,
+            )?
+        };
+        let storage = changes.as_ref().transaction();
+
+        let vm_database = VmStorage::new(
+            storage.as_ref().clone(),
+            block.header().consensus(),
+            coinbase,
+        );
+
+        let checked_tx = Self::dummy_tx()
+            .into_checked_basic(height, &self.params)
+            .map_err(|e| anyhow::anyhow!("{:?}", e))?;
+
+        let mut vm = Interpreter::with_storage(vm_database, (&self.params).into());
+        vm.transact(checked_tx).map_err(|e| anyhow::anyhow!(e))?;
+        self.vm = vm;
+        self.run = RunMode::Vm { stop: None };
+        self.predicate = None;
+        self.db = storage;
+        Ok(())
+    }
+
+    /// Prepares the session to evaluate the predicate of the `input_index` input of
+    /// the transaction. The predicate is run the same way as during the estimation
+    /// if `estimate` is `true`, otherwise as during the verification.
+    pub fn init_predicate(
+        &mut self,
+        tx: Transaction,
+        input_index: u32,
+        estimate: bool,
+    ) -> anyhow::Result<()> {
+        // The memory of the previous predicate is released before the new one is reserved
+        self.predicate = None;
+        self.clear_last_state();
+        let memory = self.memory.reserve_vm()?;
+
+        let (vm, program_end) = match tx {
+            Transaction::Script(tx) => {
+                let (vm, program_end) =
+                    self.predicate_vm(tx, input_index as usize, estimate)?;
+                (PredicateVm::Script(vm), program_end)
+            }
+            Transaction::Create(tx) => {
+                let (vm, program_end) =
+                    self.predicate_vm(tx, input_index as usize, estimate)?;
+                (PredicateVm::Create(vm), program_end)
+            }
+            _ => anyhow::bail!("Only `Script` and `Create` transactions have predicates"),
+        };
+
+        self.predicate = Some(PredicateRun {
+            vm,
+            _memory: memory,
+            input_index,
+            program_end,
+        });
+        Ok(())
+    }
+
+    fn predicate_vm<Tx>(
+        &self,
+        tx: Tx,
+        input_index: usize,
+        estimate: bool,
+    ) -> anyhow::Result<(Interpreter<PredicateStorage, Tx>, Word)>
+    where
+        Tx: ExecutableTransaction,
+    {
+        let params = CheckPredicateParams::from(&self.params);
+        let input = tx
+            .inputs()
+            .get(input_index)
+            .ok_or_else(|| anyhow::anyhow!("The input {input_index} doesn't exist"))?;
+        let (predicate, _, predicate_gas_used) = input.predicate().ok_or_else(|| {
+            anyhow::anyhow!("The input {input_index} doesn't have a predicate")
+        })?;
+
+        let owner = input.input_owner().or_else(|| input.recipient());
+        if !owner.is_some_and(|owner| Input::is_predicate_owner_valid(owner, predicate)) {
+            anyhow::bail!("{}", PredicateVerificationFailed::InvalidOwner);
+        }
+
+        let program = RuntimePredicate::from_tx(&tx, params.tx_offset, input_index)
+            .ok_or_else(|| {
+                anyhow::anyhow!("The input {input_index} doesn't have a predicate")
+            })?;
+        let range = program.program().words();
+        let (context, available_gas) = if estimate {
+            let available_gas =
+                core::cmp::min(params.max_gas_per_predicate, params.max_gas_per_tx);
+            (VmContext::PredicateEstimation { program }, available_gas)
+        } else {
+            (
+                VmContext::PredicateVerification { program },
+                *predicate_gas_used,
+            )
+        };
+
+        let mut vm = Interpreter::with_storage(PredicateStorage {}, params.into());
+        vm.init_predicate(context, tx, available_gas)
+            .map_err(|e| anyhow::anyhow!("{:?}", e))?;
+        vm.registers_mut()[usize::from(RegId::PC)] = range.start;
+        vm.registers_mut()[usize::from(RegId::IS)] = range.start;
+
+        Ok((vm, range.end))
+    }
+
+    pub fn exec(&mut self, op: Instruction) -> anyhow::Result<()> {
+        self.predicate = None;
         self.vm
-            .get_mut(id)
-            .map(|vm| vm.instruction(op))
-            .transpose()
-            .map_err(|e| anyhow::anyhow!(e))?
+            .instruction(op)
             .map(|_| ())
-            .ok_or_else(|| anyhow::anyhow!("The VM instance was not found"))
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub fn set_single_stepping(&mut self, enable: bool) {
+        self.debugger.set_single_stepping(enable);
+    }
+
+    pub fn set_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.debugger.set_breakpoint(breakpoint);
+        self.breakpoints.insert(breakpoint);
+    }
+
+    pub fn remove_breakpoint(&mut self, breakpoint: &Breakpoint) {
+        self.debugger.remove_breakpoint(breakpoint);
+        self.breakpoints.remove(breakpoint);
+    }
+
+    /// Runs the transaction until the end or the first breakpoint.
+    pub fn run_tx(
+        &mut self,
+        checked_tx: CheckedTransaction,
+    ) -> async_graphql::Result<gql_types::RunResult> {
+        self.predicate = None;
+        self.clear_last_state();
+
+        match checked_tx {
+            CheckedTransaction::Script(script) => {
+                let stepped = self.requires_stepping();
+                let breakpoints: Vec<_> = if stepped {
+                    // The VM only stops before the first instruction,
+                    // the rest of the script is stepped by the session.
+                    vec![Breakpoint::script(0)]
+                } else {
+                    self.breakpoints.iter().copied().collect()
+                };
+                self.vm.overwrite_breakpoints(&breakpoints);
+
+                let state = self
+                    .vm
+                    .transact(script)
+                    .map(|state_ref| *state_ref.state())
+                    .map_err(|err| {
+                        async_graphql::Error::new(format!("Transaction failed: {err:?}"))
+                    })?;
+
+                if stepped && state.is_debug() {
+                    self.vm.clear_breakpoints();
+                    self.run = RunMode::Stepped;
+                    // The VM reports the result of the script when it stops,
+                    // the result is reported once the script is completed.
+                    let receipt_count_before = self.vm.receipts().len();
+                    self.step_program(receipt_count_before)
+                } else {
+                    Ok(self.vm_run_result(state, 0))
+                }
+            }
+            CheckedTransaction::Create(create) => {
+                self.run = RunMode::Vm { stop: None };
+                self.vm.deploy(create).map_err(|err| {
+                    async_graphql::Error::new(format!(
+                        "Transaction deploy failed: {err:?}"
+                    ))
+                })?;
+
+                Ok(gql_types::RunResult::completed(vec![]))
+            }
+            CheckedTransaction::Mint(_) => {
+                Err(async_graphql::Error::new("`Mint` is not supported"))
+            }
+        }
+    }
+
+    /// Continues the transaction or the predicate until the end or the next breakpoint.
+    pub fn continue_tx(&mut self) -> async_graphql::Result<gql_types::RunResult> {
+        if let Some(predicate) = &mut self.predicate {
+            // The predicate was already evaluated earlier, so it cannot be resumed
+            if self.debugger.last_state().is_none() {
+                return Ok(gql_types::RunResult::completed(vec![]))
+            }
+            return Ok(run_predicate(predicate, &mut self.debugger))
+        }
+
+        if let RunMode::Vm {
+            stop: Some(breakpoint),
+        } = self.run
+        {
+            if self.requires_stepping() {
+                // The VM can't stop inside of the contract calls,
+                // so the rest of the script is stepped by the session.
+                self.vm.clear_breakpoints();
+                self.debugger.set_last_state(ProgramState::RunProgram(
+                    DebugEval::Breakpoint(breakpoint),
+                ));
+                self.run = RunMode::Stepped;
+            }
+        }
+
+        let receipt_count_before = self.vm.receipts().len();
+        match self.run {
+            RunMode::Vm { stop: Some(_) } => {
+                let breakpoints: Vec<_> = self.breakpoints.iter().copied().collect();
+                self.vm.overwrite_breakpoints(&breakpoints);
+
+                match self.vm.resume() {
+                    Ok(state) => Ok(self.vm_run_result(state, receipt_count_before)),
+                    // The transaction was already completed earlier, so it cannot be resumed
+                    Err(InterpreterError::DebugStateNotInitialized) => {
+                        Ok(gql_types::RunResult::completed(vec![]))
+                    }
+                    Err(err) => {
+                        Err(async_graphql::Error::new(format!("VM error: {err:?}")))
+                    }
+                }
+            }
+            RunMode::Stepped if self.debugger.last_state().is_some() => {
+                self.step_program(receipt_count_before)
+            }
+            // The transaction was already completed earlier, so it cannot be resumed
+            _ => Ok(gql_types::RunResult::completed(vec![])),
+        }
+    }
+
+    /// The session has to stop inside of the contract calls.
+    fn requires_stepping(&self) -> bool {
+        self.debugger.single_stepping()
+            || self
+                .breakpoints
+                .iter()
+                .any(|breakpoint| breakpoint.contract() != &Default::default())
+    }
+
+    /// Forgets where the previous run has stopped, keeping the breakpoints.
+    fn clear_last_state(&mut self) {
+        let mut debugger = Debugger::default();
+        debugger.set_single_stepping(self.debugger.single_stepping());
+        for breakpoint in &self.breakpoints {
+            debugger.set_breakpoint(*breakpoint);
+        }
+        self.debugger = debugger;
+    }
+
+    fn vm_run_result(
+        &mut self,
+        state: ProgramState,
+        receipt_count_before: usize,
+    ) -> gql_types::RunResult {
+        let stop = match state.debug_ref() {
+            Some(DebugEval::Breakpoint(breakpoint)) => Some(*breakpoint),
+            _ => None,
+        };
+        self.run = RunMode::Vm { stop };
+
+        gql_types::RunResult {
+            state: match state.debug_ref() {
+                Some(_) => gql_types::RunState::Breakpoint,
+                None => gql_types::RunState::Completed,
+            },
+            breakpoint: stop.as_ref().map(Into::into),
+            json_receipts: json_receipts(&self.vm.receipts()[receipt_count_before..]),
+            predicate_failure: None,
+        }
+    }
+
+    /// Steps through the script until the end or the next breakpoint, including the
+    /// ones inside of the contract calls. The script is completed the same way as
+    /// by the VM: the panic is reported by the `Panic` receipt, and the result of
+    /// the script by the `ScriptResult` receipt.
+    fn step_program(
+        &mut self,
+        receipt_count_before: usize,
+    ) -> async_graphql::Result<gql_types::RunResult> {
+        let mut completion_receipts = vec![];
+        let breakpoint = loop {
+            let registers = self.vm.registers();
+            let in_call = registers[usize::from(RegId::FP)] != 0;
+            let pc = registers[usize::from(RegId::PC)]
+                .saturating_sub(registers[usize::from(RegId::IS)]);
+            let contract = current_contract(&self.vm);
+
+            if let DebugEval::Breakpoint(bp) =
+                self.debugger.eval_state(contract.as_ref(), pc)
+            {
+                self.debugger
+                    .set_last_state(ProgramState::RunProgram(DebugEval::Breakpoint(bp)));
+                break Some(bp)
+            }
+
+            let result = match self.vm.execute() {
+                // Only reverts should terminate execution from a call context
+                Ok(ExecuteState::Revert(_)) => ScriptExecutionResult::Revert,
+                Ok(ExecuteState::Return(_) | ExecuteState::ReturnData(_)) if !in_call => {
+                    ScriptExecutionResult::Success
+                }
+                Ok(_) => continue,
+                Err(err) => match err.instruction_result() {
+                    Some(result) => {
+                        let registers = self.vm.registers();
+                        completion_receipts.push(Receipt::panic(
+                            contract.unwrap_or_default(),
+                            result,
+                            registers[usize::from(RegId::PC)],
+                            registers[usize::from(RegId::IS)],
+                        ));
+                        ScriptExecutionResult::Panic
+                    }
+                    None => {
+                        return Err(async_graphql::Error::new(format!(
+                            "VM error: {err:?}"
+                        )))
+                    }
+                },
+            };
+
+            let gas_used = self
+                .vm
+                .transaction()
+                .script_gas_limit()
+                .saturating_sub(self.vm.remaining_gas());
+            completion_receipts.push(Receipt::script_result(result, gas_used));
+            break None
+        };
+
+        let receipts = self.vm.receipts()[receipt_count_before..].iter();
+        let json_receipts = json_receipts(receipts.chain(&completion_receipts));
+
+        Ok(gql_types::RunResult {
+            state: match breakpoint {
+                Some(_) => gql_types::RunState::Breakpoint,
+                None => gql_types::RunState::Completed,
+            },
+            breakpoint: breakpoint.as_ref().map(Into::into),
+            json_receipts,
+            predicate_failure: None,
+        })
     }
 
     fn vm_database(storage: &DatabaseTransaction) -> anyhow::Result<VmStorage<Database>> {
@@ -188,8 +843,189 @@ impl ConcreteStorage {
     }
 }
 
+#[derive(Debug)]
+struct SessionEntry {
+    session: sync::Arc<Mutex<Session>>,
+    last_activity: Instant,
+}
+
+/// The registry of the debug sessions. The lock on it is only held to look up the
+/// session, so the long-running operations of one session don't block the others.
+#[derive(Debug)]
+pub struct ConcreteStorage {
+    sessions: HashMap<ID, SessionEntry>,
+    params: ConsensusParameters,
+    config: DebuggerConfig,
+    memory: MemoryAccounting,
+}
+
+impl ConcreteStorage {
+    pub fn new(params: ConsensusParameters, config: DebuggerConfig) -> Self {
+        Self {
+            sessions: Default::default(),
+            params,
+            memory: MemoryAccounting::new(config.max_memory),
+            config,
+        }
+    }
+
+    /// Returns the parameters to create a new session outside of the lock,
+    /// fails if the limit of the sessions is reached.
+    fn session_params(
+        &mut self,
+    ) -> anyhow::Result<(ConsensusParameters, MemoryAccounting)> {
+        self.ensure_session_limit()?;
+        Ok((self.params.clone(), self.memory.clone()))
+    }
+
+    pub fn insert(&mut self, session: Session) -> anyhow::Result<ID> {
+        // The sessions could be created by the other requests while this one was created
+        self.ensure_session_limit()?;
+
+        let id = Uuid::new_v4();
+        let id = ID::from(id);
+
+        self.sessions.insert(
+            id.clone(),
+            SessionEntry {
+                session: sync::Arc::new(Mutex::new(session)),
+                last_activity: Instant::now(),
+            },
+        );
+
+        Ok(id)
+    }
+
+    pub fn kill(&mut self, id: &ID) -> bool {
+        self.remove_idle_sessions();
+        self.sessions.remove(id).is_some()
+    }
+
+    /// Returns the session and marks it as active.
+    pub fn session(&mut self, id: &ID) -> anyhow::Result<sync::Arc<Mutex<Session>>> {
+        self.remove_idle_sessions();
+        let entry = self
+            .sessions
+            .get_mut(id)
+            .ok_or_else(|| anyhow::anyhow!("The VM instance was not found"))?;
+        entry.last_activity = Instant::now();
+        Ok(entry.session.clone())
+    }
+
+    /// The memory allocated by the VMs of all sessions in bytes.
+    pub fn memory_usage(&self) -> usize {
+        self.memory.used()
+    }
+
+    fn ensure_session_limit(&mut self) -> anyhow::Result<()> {
+        self.remove_idle_sessions();
+        if self.sessions.len() >= self.config.max_sessions {
+            anyhow::bail!(
+                "The limit of {} debug sessions is reached",
+                self.config.max_sessions
+            );
+        }
+        Ok(())
+    }
+
+    /// Kills the sessions that were not used longer than the idle timeout.
+    fn remove_idle_sessions(&mut self) {
+        let timeout = self.config.session_timeout;
+        self.sessions.retain(|id, entry| {
+            let is_active = entry.last_activity.elapsed() < timeout;
+            if !is_active {
+                debug!("Session {:?} was dropped after the idle timeout", id);
+            }
+            is_active
+        });
+    }
+}
+
 pub type GraphStorage = sync::Arc<Mutex<ConcreteStorage>>;
 
+pub type ReaperService = ServiceRunner<IdleSessionsReaper>;
+
+/// Kills the idle debug sessions periodically, so they don't hold the memory
+/// until the next request to the debugger.
+pub struct IdleSessionsReaper {
+    storage: GraphStorage,
+    timer: tokio::time::Interval,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for IdleSessionsReaper {
+    const NAME: &'static str = "DebugSessionsReaper";
+
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        self.timer.reset();
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for IdleSessionsReaper {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.timer.tick() => {
+                self.storage.lock().await.remove_idle_sessions();
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_storage(params: ConsensusParameters, config: DebuggerConfig) -> GraphStorage {
+    GraphStorage::new(Mutex::new(ConcreteStorage::new(params, config)))
+}
+
+/// Creates the service that checks the idle sessions every `session_timeout`.
+pub fn new_reaper_service(
+    storage: GraphStorage,
+    session_timeout: Duration,
+) -> ReaperService {
+    ServiceRunner::new(IdleSessionsReaper {
+        storage,
+        timer: tokio::time::interval(session_timeout),
+    })
+}
+
+/// Looks up the session and locks it for the exclusive use.
+async fn lock_session(
+    ctx: &Context<'_>,
+    id: &ID,
+) -> async_graphql::Result<OwnedMutexGuard<Session>> {
+    let session = ctx
+        .data_unchecked::<GraphStorage>()
+        .lock()
+        .await
+        .session(id)?;
+    Ok(session.lock_owned().await)
+}
+
 #[derive(Default)]
 pub struct DapQuery;
 #[derive(Default)]
@@ -197,11 +1033,13 @@ pub struct DapMutation;
 
 pub fn init<Q, M, S>(
     schema: SchemaBuilder<Q, M, S>,
-    params: ConsensusParameters,
+    storage: GraphStorage,
+    executor: ExecutorAdapter,
     debug_enabled: bool,
 ) -> SchemaBuilder<Q, M, S> {
     schema
-        .data(GraphStorage::new(Mutex::new(ConcreteStorage::new(params))))
+        .data(storage)
+        .data(executor)
         .data(Config { debug_enabled })
 }
 
@@ -224,10 +1062,9 @@ impl DapQuery {
         register: U32,
     ) -> async_graphql::Result<U64> {
         require_debug(ctx)?;
-        ctx.data_unchecked::<GraphStorage>()
-            .lock()
-            .await
-            .register(&id, register.0 as RegisterId)
+        lock_session(ctx, &id)
+            .await?
+            .register(register.0 as RegisterId)
             .ok_or_else(|| async_graphql::Error::new("Invalid register identifier"))
             .map(|val| val.into())
     }
@@ -240,13 +1077,56 @@ impl DapQuery {
         size: U32,
     ) -> async_graphql::Result<String> {
         require_debug(ctx)?;
-        ctx.data_unchecked::<GraphStorage>()
-            .lock()
-            .await
-            .memory(&id, start.0 as usize, size.0 as usize)
+        lock_session(ctx, &id)
+            .await?
+            .memory(start.0 as usize, size.0 as usize)
             .ok_or_else(|| async_graphql::Error::new("Invalid memory range"))
             .and_then(|mem| Ok(serde_json::to_string(mem)?))
     }
+
+    /// The call frames of the contracts from the outermost to the innermost one.
+    async fn call_frames(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> async_graphql::Result<Vec<gql_types::CallFrame>> {
+        require_debug(ctx)?;
+        lock_session(ctx, &id)
+            .await?
+            .call_frames()
+            .ok_or_else(|| async_graphql::Error::new("Invalid call frames"))
+            .map(|frames| frames.iter().map(Into::into).collect())
+    }
+
+    /// The value of the contract storage slot, `null` if the slot is unset.
+    async fn session_storage_slot(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> async_graphql::Result<Option<Bytes32>> {
+        require_debug(ctx)?;
+        let value = lock_session(ctx, &id)
+            .await?
+            .contract_storage(&contract_id.0, &key.0)?;
+        Ok(value.map(Into::into))
+    }
+
+    /// The balance of the contract in the asset.
+    async fn session_balance(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        contract_id: ContractId,
+        asset_id: AssetId,
+    ) -> async_graphql::Result<U64> {
+        require_debug(ctx)?;
+        let balance = lock_session(ctx, &id)
+            .await?
+            .contract_balance(&contract_id.0, &asset_id.0)?;
+        Ok(balance.into())
+    }
 }
 
 #[Object]
@@ -256,14 +1136,20 @@ impl DapMutation {
         trace!("Initializing new interpreter");
 
         let db = ctx.data_unchecked::<Database>();
+        let storage = ctx.data_unchecked::<GraphStorage>();
+
+        // The session is created without the lock, it executes the transaction
+        let (params, memory) = storage.lock().await.session_params()?;
+        let session = Session::new(&[], db.transaction(), params, memory)?;
 
-        let id = ctx
-            .data_unchecked::<GraphStorage>()
-            .lock()
-            .await
-            .init(&[], db.transaction())?;
+        let mut storage = storage.lock().await;
+        let id = storage.insert(session)?;
 
-        debug!("Session {:?} initialized", id);
+        debug!(
+            "Session {:?} initialized, the sessions use {} bytes of memory",
+            id,
+            storage.memory_usage()
+        );
 
         Ok(id)
     }
@@ -285,10 +1171,7 @@ impl DapMutation {
         require_debug(ctx)?;
         let db = ctx.data_unchecked::<Database>();
 
-        ctx.data_unchecked::<GraphStorage>()
-            .lock()
-            .await
-            .reset(&id, db.transaction())?;
+        lock_session(ctx, &id).await?.reset(db.transaction())?;
 
         debug!("Session {:?} was reset", id);
 
@@ -308,8 +1191,7 @@ impl DapMutation {
 
         trace!("Op decoded to {:?}", op);
 
-        let storage = ctx.data_unchecked::<GraphStorage>().clone();
-        let result = storage.lock().await.exec(&id, op).is_ok();
+        let result = lock_session(ctx, &id).await?.exec(op).is_ok();
 
         debug!("Op {:?} executed with result {}", op, result);
 
@@ -325,13 +1207,7 @@ impl DapMutation {
         require_debug(ctx)?;
         trace!("Set single stepping to {} for VM {:?}", enable, id);
 
-        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
-        let vm = locked
-            .vm
-            .get_mut(&id)
-            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
-
-        vm.set_single_stepping(enable);
+        lock_session(ctx, &id).await?.set_single_stepping(enable);
         Ok(enable)
     }
 
@@ -344,13 +1220,24 @@ impl DapMutation {
         require_debug(ctx)?;
         trace!("Continue execution of VM {:?}", id);
 
-        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
-        let vm = locked
-            .vm
-            .get_mut(&id)
-            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
+        lock_session(ctx, &id)
+            .await?
+            .set_breakpoint(breakpoint.into());
+        Ok(true)
+    }
+
+    async fn remove_breakpoint(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        breakpoint: gql_types::Breakpoint,
+    ) -> async_graphql::Result<bool> {
+        require_debug(ctx)?;
+        trace!("Remove breakpoint of VM {:?}", id);
 
-        vm.set_breakpoint(breakpoint.into());
+        lock_session(ctx, &id)
+            .await?
+            .remove_breakpoint(&breakpoint.into());
         Ok(true)
     }
 
@@ -366,64 +1253,112 @@ impl DapMutation {
         let tx: Transaction = serde_json::from_str(&tx_json)
             .map_err(|_| async_graphql::Error::new("Invalid transaction JSON"))?;
 
-        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
-
-        let db = locked.db.get(&id).ok_or("Invalid debugging session ID")?;
+        let mut session = lock_session(ctx, &id).await?;
 
         let checked_tx = tx
-            .into_checked_basic(db.latest_height()?, &locked.params)
+            .into_checked_basic(session.db.latest_height()?, &session.params)
             .map_err(|err| anyhow::anyhow!("{:?}", err))?
             .into();
 
-        let vm = locked
-            .vm
-            .get_mut(&id)
-            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
+        session.run_tx(checked_tx)
+    }
 
-        match checked_tx {
-            CheckedTransaction::Script(script) => {
-                let state_ref = vm.transact(script).map_err(|err| {
-                    async_graphql::Error::new(format!("Transaction failed: {err:?}"))
-                })?;
+    /// Starts the evaluation of the predicate of the `input_index` input of the
+    /// transaction. The predicate is estimated if `estimate` is `true`, otherwise
+    /// it is verified with the gas set in the input.
+    ///
+    /// Until the next transaction is started, the registers and the memory
+    /// of the session are the ones of the predicate.
+    async fn start_predicate(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        tx_json: String,
+        input_index: U32,
+        #[graphql(default)] estimate: bool,
+    ) -> async_graphql::Result<gql_types::RunResult> {
+        require_debug(ctx)?;
+        trace!(
+            "Spawning a new VM instance for the predicate {}",
+            input_index.0
+        );
 
-                let json_receipts = state_ref
-                    .receipts()
-                    .iter()
-                    .map(|r| {
-                        serde_json::to_string(&r).expect("JSON serialization failed")
-                    })
-                    .collect();
-
-                let dbgref = state_ref.state().debug_ref();
-                Ok(gql_types::RunResult {
-                    state: match dbgref {
-                        Some(_) => gql_types::RunState::Breakpoint,
-                        None => gql_types::RunState::Completed,
-                    },
-                    breakpoint: dbgref.and_then(|d| match d {
-                        DebugEval::Continue => None,
-                        DebugEval::Breakpoint(bp) => Some(bp.into()),
-                    }),
-                    json_receipts,
-                })
-            }
-            CheckedTransaction::Create(create) => {
-                vm.deploy(create).map_err(|err| {
-                    async_graphql::Error::new(format!(
-                        "Transaction deploy failed: {err:?}"
-                    ))
-                })?;
+        let tx: Transaction = serde_json::from_str(&tx_json)
+            .map_err(|_| async_graphql::Error::new("Invalid transaction JSON"))?;
 
-                Ok(gql_types::RunResult {
-                    state: gql_types::RunState::Completed,
-                    breakpoint: None,
-                    json_receipts: vec![],
-                })
-            }
-            CheckedTransaction::Mint(_) => {
-                Err(async_graphql::Error::new("`Mint` is not supported"))
-            }
+        let mut session = lock_session(ctx, &id).await?;
+        session.init_predicate(tx, input_index.0, estimate)?;
+
+        let Session {
+            predicate,
+            debugger,
+            ..
+        } = &mut *session;
+        let predicate = predicate
+            .as_mut()
+            .expect("The predicate is initialized above");
+
+        Ok(run_predicate(predicate, debugger))
+    }
+
+    /// Starts the execution of the transaction already included into the chain.
+    /// The state of the session is reset to the state of the chain right before
+    /// the execution of the transaction.
+    ///
+    /// It requires the history of the state at the previous block, so the node should
+    /// be started with the `--state-rewind-blocks` covering the block of the transaction.
+    async fn start_tx_from_chain(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        tx_id: TransactionId,
+    ) -> async_graphql::Result<gql_types::RunResult> {
+        require_debug(ctx)?;
+        trace!("Spawning a new VM instance for the transaction {}", tx_id);
+
+        let db = ctx.data_unchecked::<Database>();
+        if db.state_rewind_policy() == StateRewindPolicy::NoRewind {
+            return Err(anyhow::anyhow!(
+                "The node doesn't keep the history of the state required to debug \
+                 the transactions from the chain, start it with `--state-rewind-blocks`"
+            )
+            .into())
         }
+        let tx_id = tx_id.0;
+
+        let tx = db
+            .storage_as_ref::<Transactions>()
+            .get(&tx_id)?
+            .ok_or("Transaction not found")?
+            .into_owned();
+        let block_id = match db.get_tx_status(&tx_id)? {
+            Some(TransactionStatus::Success { block_id, .. })
+            | Some(TransactionStatus::Failed { block_id, .. }) => block_id,
+            _ => return Err("The transaction is not included into the chain".into()),
+        };
+        let height = db
+            .get_block_height(&block_id)?
+            .ok_or(not_found!("BlockHeight"))?;
+
+        let mut session = lock_session(ctx, &id).await?;
+        let database = db.clone();
+        let executor = ctx.data_unchecked::<ExecutorAdapter>().clone();
+        // The preceding transactions of the block are executed on the blocking thread
+        let mut session = tokio::task::spawn_blocking(move || {
+            session
+                .reset_to_transaction(&database, &executor, &tx_id, height)
+                .map(|_| session)
+        })
+        .await??;
+
+        let checked_tx = tx
+            .into_checked_basic(height, &session.params)
+            .map_err(|err| anyhow::anyhow!("{:?}", err))?
+            .into();
+
+        debug!("Session {:?} was reset to the transaction {}", id, tx_id);
+
+        session.run_tx(checked_tx)
     }
 
     async fn continue_tx(
@@ -434,51 +1369,104 @@ impl DapMutation {
         require_debug(ctx)?;
         trace!("Continue execution of VM {:?}", id);
 
-        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
-        let vm = locked
-            .vm
-            .get_mut(&id)
-            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
+        lock_session(ctx, &id).await?.continue_tx()
+    }
+}
 
-        let receipt_count_before = vm.receipts().len();
+fn json_receipts<'a>(receipts: impl IntoIterator<Item = &'a Receipt>) -> Vec<String> {
+    receipts
+        .into_iter()
+        .map(|r| serde_json::to_string(&r).expect("JSON serialization failed"))
+        .collect()
+}
 
-        let state = match vm.resume() {
-            Ok(state) => state,
-            // The transaction was already completed earlier, so it cannot be resumed
-            Err(InterpreterError::DebugStateNotInitialized) => {
-                return Ok(gql_types::RunResult {
-                    state: gql_types::RunState::Completed,
-                    breakpoint: None,
-                    json_receipts: Vec::new(),
-                })
-            }
-            // The transaction was already completed earlier, so it cannot be resumed
-            Err(err) => {
-                return Err(async_graphql::Error::new(format!("VM error: {err:?}")))
-            }
-        };
+/// Runs the predicate until the end, the failure, or the next breakpoint.
+fn run_predicate(
+    predicate: &mut PredicateRun,
+    debugger: &mut Debugger,
+) -> gql_types::RunResult {
+    let program_end = predicate.program_end;
+    let result = match &mut predicate.vm {
+        PredicateVm::Script(vm) => run_predicate_program(vm, program_end, debugger),
+        PredicateVm::Create(vm) => run_predicate_program(vm, program_end, debugger),
+    };
 
-        let json_receipts = vm
-            .receipts()
-            .iter()
-            .skip(receipt_count_before)
-            .map(|r| serde_json::to_string(&r).expect("JSON serialization failed"))
-            .collect();
+    let (breakpoint, predicate_failure) = match result {
+        Ok(breakpoint) => (breakpoint, None),
+        Err((pc, reason)) => {
+            let failure = gql_types::PredicateFailure {
+                input_index: U32(predicate.input_index),
+                reason: reason.to_string(),
+                pc: U64(pc.saturating_div(INSTRUCTION_SIZE)),
+            };
+            (None, Some(failure))
+        }
+    };
+
+    gql_types::RunResult {
+        state: match breakpoint {
+            Some(_) => gql_types::RunState::Breakpoint,
+            None => gql_types::RunState::Completed,
+        },
+        breakpoint: breakpoint.as_ref().map(Into::into),
+        json_receipts: vec![],
+        predicate_failure,
+    }
+}
 
-        let dbgref = state.debug_ref();
+/// Follows the predicate verification of the VM, but stops on the breakpoints.
+///
+/// On failure, returns the offset of the failed instruction in bytes along with the reason.
+fn run_predicate_program<Tx>(
+    vm: &mut Interpreter<PredicateStorage, Tx>,
+    program_end: Word,
+    debugger: &mut Debugger,
+) -> Result<Option<Breakpoint>, (Word, PredicateVerificationFailed)>
+where
+    Tx: ExecutableTransaction,
+{
+    loop {
+        let registers = vm.registers();
+        let pc = registers[usize::from(RegId::PC)]
+            .saturating_sub(registers[usize::from(RegId::IS)]);
+        if program_end <= registers[usize::from(RegId::PC)] {
+            return Err((pc, PanicReason::MemoryOverflow.into()))
+        }
 
-        Ok(gql_types::RunResult {
-            state: match dbgref {
-                Some(_) => gql_types::RunState::Breakpoint,
-                None => gql_types::RunState::Completed,
-            },
-            breakpoint: dbgref.and_then(|d| match d {
-                DebugEval::Continue => None,
-                DebugEval::Breakpoint(bp) => Some(bp.into()),
-            }),
-            json_receipts,
-        })
+        if let DebugEval::Breakpoint(bp) = debugger.eval_state(None, pc) {
+            debugger
+                .set_last_state(ProgramState::VerifyPredicate(DebugEval::Breakpoint(bp)));
+            return Ok(Some(bp))
+        }
+
+        let state = vm.execute().map_err(|err| (pc, err.into()))?;
+        let reason = match state {
+            ExecuteState::Return(1) => return Ok(None),
+            ExecuteState::Return(_) => PanicReason::PredicateReturnedNonOne.into(),
+            // A predicate is not expected to return data
+            ExecuteState::ReturnData(_) => {
+                PanicReason::ContractInstructionNotAllowed.into()
+            }
+            ExecuteState::Revert(_) => PredicateVerificationFailed::False,
+            ExecuteState::Proceed | ExecuteState::DebugEvent(_) => continue,
+        };
+        return Err((pc, reason))
+    }
+}
+
+/// Returns the contract of the current call frame, `None` in the script context.
+fn current_contract(
+    vm: &Interpreter<VmStorage<Database>, Script>,
+) -> Option<fuel_types::ContractId> {
+    let fp = usize::try_from(vm.registers()[usize::from(RegId::FP)]).ok()?;
+    if fp == 0 {
+        return None
     }
+
+    let contract_id = vm
+        .memory()
+        .get(fp..fp.checked_add(fuel_types::ContractId::LEN)?)?;
+    fuel_types::ContractId::try_from(contract_id).ok()
 }
 
 mod gql_types {
@@ -486,11 +1474,19 @@ mod gql_types {
     use async_graphql::*;
 
     use crate::schema::scalars::{
+        AssetId,
         ContractId,
+        U32,
         U64,
     };
 
-    use fuel_core_types::fuel_vm::Breakpoint as FuelBreakpoint;
+    use fuel_core_types::{
+        fuel_asm::Word,
+        fuel_vm::{
+            Breakpoint as FuelBreakpoint,
+            CallFrame as FuelCallFrame,
+        },
+    };
 
     #[derive(Debug, Clone, Copy, InputObject)]
     pub struct Breakpoint {
@@ -530,6 +1526,35 @@ mod gql_types {
         }
     }
 
+    #[derive(Debug, Clone, Copy, SimpleObject)]
+    pub struct CallFrame {
+        /// The called contract
+        contract_id: ContractId,
+        /// The asset forwarded with the call
+        asset_id: AssetId,
+        /// The offset of the program counter from the start of the contract code in instructions
+        code_offset: U64,
+        /// The size of the contract code in bytes
+        code_size: U64,
+        /// The first parameter of the call
+        param1: U64,
+        /// The second parameter of the call
+        param2: U64,
+    }
+
+    impl From<&(FuelCallFrame, Word)> for CallFrame {
+        fn from((frame, code_offset): &(FuelCallFrame, Word)) -> Self {
+            Self {
+                contract_id: (*frame.to()).into(),
+                asset_id: (*frame.asset_id()).into(),
+                code_offset: U64(*code_offset),
+                code_size: U64(frame.code_size() as u64),
+                param1: U64(frame.a()),
+                param2: U64(frame.b()),
+            }
+        }
+    }
+
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Enum)]
     pub enum RunState {
         /// All breakpoints have been processed, and the program has terminated
@@ -538,10 +1563,33 @@ mod gql_types {
         Breakpoint,
     }
 
+    #[derive(Debug, Clone, SimpleObject)]
+    pub struct PredicateFailure {
+        /// The index of the input with the predicate
+        pub input_index: U32,
+        /// The reason of the failure
+        pub reason: String,
+        /// The offset of the failed instruction from the start of the predicate in instructions
+        pub pc: U64,
+    }
+
     #[derive(Debug, Clone, SimpleObject)]
     pub struct RunResult {
         pub state: RunState,
         pub breakpoint: Option<OutputBreakpoint>,
         pub json_receipts: Vec<String>,
+        /// The failure of the predicate evaluated by the session, if any
+        pub predicate_failure: Option<PredicateFailure>,
+    }
+
+    impl RunResult {
+        pub fn completed(json_receipts: Vec<String>) -> Self {
+            Self {
+                state: RunState::Completed,
+                breakpoint: None,
+                json_receipts,
+                predicate_failure: None,
+            }
+        }
     }
 }
@@ -1,4 +1,16 @@
-use async_graphql::Object;
+use crate::{
+    fuel_core_graphql_api::api_service::HealthCheck,
+    schema::scalars::{
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_types::services::health;
 
 #[derive(Default)]
 pub struct HealthQuery;
@@ -9,4 +21,188 @@ impl HealthQuery {
     async fn health(&self) -> bool {
         true
     }
+
+    /// Returns the readiness report of the node.
+    async fn readiness(&self, ctx: &Context<'_>) -> Readiness {
+        let health_check = ctx.data_unchecked::<HealthCheck>();
+        Readiness(health_check.readiness().await)
+    }
+}
+
+pub struct Readiness(health::ReadinessReport);
+
+#[Object]
+impl Readiness {
+    /// Returns true when all components of the node are ready.
+    async fn ready(&self) -> bool {
+        self.0.is_ready()
+    }
+
+    /// The state of the relayer, if the relayer is enabled.
+    async fn relayer(&self) -> Option<RelayerReadiness> {
+        self.0.relayer.clone().map(RelayerReadiness)
+    }
+
+    /// The state of the P2P network, if the P2P is enabled.
+    async fn network(&self) -> Option<NetworkReadiness> {
+        self.0.p2p.clone().map(NetworkReadiness)
+    }
+
+    /// The synchronization state of the block production, if the block production is enabled.
+    async fn sync(&self) -> Option<SyncReadiness> {
+        self.0.sync.clone().map(SyncReadiness)
+    }
+
+    /// The state of the block importer.
+    async fn importer(&self) -> ImporterReadiness {
+        ImporterReadiness(self.0.importer.clone())
+    }
+
+    /// Returns true when the database accepts writes.
+    async fn database_writable(&self) -> bool {
+        self.0.database_writable
+    }
+
+    /// The state of each sub-service of the node.
+    async fn services(&self) -> Vec<ServiceReadiness> {
+        self.0
+            .services
+            .iter()
+            .cloned()
+            .map(ServiceReadiness)
+            .collect()
+    }
+}
+
+pub struct RelayerReadiness(health::RelayerReadiness);
+
+#[Object]
+impl RelayerReadiness {
+    /// Returns true when the relayer doesn't lag behind the DA layer more than allowed.
+    async fn synced(&self) -> bool {
+        self.0.is_ready()
+    }
+
+    /// The DA height of the last synchronization with the DA layer.
+    async fn synced_da_height(&self) -> Option<U64> {
+        self.0.synced_da_height.map(|height| height.0.into())
+    }
+
+    /// The finalized DA height known to the node.
+    async fn finalized_da_height(&self) -> Option<U64> {
+        self.0.finalized_da_height.map(|height| height.0.into())
+    }
+
+    /// The latest finalized DA height observed on the DA layer.
+    async fn latest_da_finalized_height(&self) -> Option<U64> {
+        self.0.latest_da_finalized_height.map(|height| height.0.into())
+    }
+
+    /// The maximum number of DA blocks the relayer can lag behind the DA layer to be ready.
+    async fn max_lag(&self) -> U64 {
+        self.0.max_lag.into()
+    }
+}
+
+pub struct NetworkReadiness(health::P2pReadiness);
+
+#[Object]
+impl NetworkReadiness {
+    /// The number of connected peers.
+    async fn connected_peers(&self) -> U32 {
+        self.0.connected_peers.into()
+    }
+
+    /// The number of connected reserved peers.
+    async fn connected_reserved_peers(&self) -> U32 {
+        self.0.connected_reserved_peers.into()
+    }
+
+    /// The minimum number of the connected reserved peers required to be ready.
+    async fn min_connected_reserved_peers(&self) -> U32 {
+        self.0.min_connected_reserved_peers.into()
+    }
+}
+
+pub struct SyncReadiness(health::SyncReadiness);
+
+#[Object]
+impl SyncReadiness {
+    /// Returns true when the node considers itself synced with the network.
+    async fn synced(&self) -> bool {
+        self.0.synced
+    }
+
+    /// The height of the block at which the node became synced.
+    async fn synced_height(&self) -> Option<U32> {
+        self.0.synced_height.map(|height| (*height).into())
+    }
+}
+
+pub struct ImporterReadiness(health::ImporterReadiness);
+
+#[Object]
+impl ImporterReadiness {
+    /// The height of the latest imported block.
+    async fn latest_height(&self) -> U32 {
+        (*self.0.latest_height).into()
+    }
+
+    /// The highest block height reported by peers.
+    async fn highest_peer_height(&self) -> Option<U32> {
+        self.0.highest_peer_height.map(|height| (*height).into())
+    }
+
+    /// The number of blocks the node is behind the highest peer height.
+    async fn lag(&self) -> U32 {
+        self.0.lag.into()
+    }
+
+    /// The maximum lag allowed to be ready.
+    async fn max_lag(&self) -> U32 {
+        self.0.max_lag.into()
+    }
+}
+
+pub struct ServiceReadiness(health::ServiceReadiness);
+
+#[Object]
+impl ServiceReadiness {
+    /// The name of the service.
+    async fn name(&self) -> &str {
+        self.0.name.as_str()
+    }
+
+    /// The state of the service.
+    async fn state(&self) -> ServiceState {
+        self.0.state.into()
+    }
+
+    /// The error that stopped the service, if any.
+    async fn error(&self) -> Option<&str> {
+        self.0.error.as_deref()
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ServiceState {
+    NotStarted,
+    Starting,
+    Started,
+    Stopping,
+    Stopped,
+    StoppedWithError,
+}
+
+impl From<health::ServiceState> for ServiceState {
+    fn from(state: health::ServiceState) -> Self {
+        match state {
+            health::ServiceState::NotStarted => ServiceState::NotStarted,
+            health::ServiceState::Starting => ServiceState::Starting,
+            health::ServiceState::Started => ServiceState::Started,
+            health::ServiceState::Stopping => ServiceState::Stopping,
+            health::ServiceState::Stopped => ServiceState::Stopped,
+            health::ServiceState::StoppedWithError => ServiceState::StoppedWithError,
+        }
+    }
 }
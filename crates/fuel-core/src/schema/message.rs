@@ -11,11 +11,22 @@ use super::{
 };
 use crate::{
     fuel_core_graphql_api::{
-        database::ReadView,
-        ports::DatabaseBlocks,
+        api_service::OffChainWorker,
+        database::{
+            ReadDatabase,
+            ReadView,
+        },
+        ports::{
+            DatabaseBlocks,
+            OffChainDatabase,
+        },
     },
     graphql_api::IntoApiResult,
-    query::MessageQueryData,
+    query::{
+        block_updates,
+        BlockUpdate,
+        MessageQueryData,
+    },
     schema::scalars::{
         BlockId,
         U32,
@@ -30,8 +41,15 @@ use async_graphql::{
     Context,
     Enum,
     Object,
+    Subscription,
+};
+use fuel_core_storage::IsNotFound;
+use fuel_core_types::{
+    entities,
+    fuel_types::BlockHeight,
+    services::txpool::TransactionStatus,
 };
-use fuel_core_types::entities;
+use futures::Stream;
 
 pub struct Message(pub(crate) entities::message::Message);
 
@@ -77,6 +95,9 @@ impl MessageQuery {
         query.message(&nonce).into_api_result()
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn messages(
         &self,
         ctx: &Context<'_>,
@@ -89,6 +110,7 @@ impl MessageQuery {
     {
         let query: &ReadView = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -127,17 +149,7 @@ impl MessageQuery {
         commit_block_height: Option<U32>,
     ) -> async_graphql::Result<Option<MessageProof>> {
         let query: &ReadView = ctx.data_unchecked();
-        let height = match (commit_block_id, commit_block_height) {
-            (Some(commit_block_id), None) => {
-                query.block_height(&commit_block_id.0.into())?
-            },
-            (None, Some(commit_block_height)) => {
-                commit_block_height.0.into()
-            }
-            _ => Err(anyhow::anyhow!(
-                "Either `commit_block_id` or `commit_block_height` must be provided exclusively"
-            ))?,
-        };
+        let height = commit_height(query, commit_block_id, commit_block_height)?;
 
         Ok(crate::query::message_proof(
             query,
@@ -148,6 +160,32 @@ impl MessageQuery {
         .map(MessageProof))
     }
 
+    /// Returns the proofs of several output messages relatively to the same commit block.
+    /// The proofs are in the same order as the `messages`, and the proof is `null` if
+    /// the message can't be proven.
+    #[graphql(complexity = "messages.len().saturating_mul(child_complexity)")]
+    async fn message_proofs(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The transaction ids and nonces of the output messages.")]
+        messages: Vec<MessageProofInput>,
+        commit_block_id: Option<BlockId>,
+        commit_block_height: Option<U32>,
+    ) -> async_graphql::Result<Vec<Option<MessageProof>>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height = commit_height(query, commit_block_id, commit_block_height)?;
+
+        let messages = messages
+            .into_iter()
+            .map(|message| (message.transaction_id.into(), message.nonce.into()));
+        let proofs = crate::query::message_proofs(query, messages, height)?
+            .into_iter()
+            .map(|proof| proof.map(MessageProof))
+            .collect();
+
+        Ok(proofs)
+    }
+
     async fn message_status(
         &self,
         ctx: &Context<'_>,
@@ -158,6 +196,132 @@ impl MessageQuery {
         Ok(status.into())
     }
 }
+#[derive(Default)]
+pub struct MessageStatusSubscription;
+
+#[Subscription]
+impl MessageStatusSubscription {
+    /// Returns a stream of status updates for the message with the given `nonce`.
+    /// The current status is returned first. The next status is returned when
+    /// the message from the relayer is seen for the first time, and when the message
+    /// is spent. The stream ends after the message is spent.
+    ///
+    /// This stream will wait forever so it's advised to use within a timeout.
+    async fn message_status_change<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The nonce of the message")] nonce: Nonce,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<MessageStatus>> + 'a,
+    > {
+        let database: &ReadDatabase = ctx.data_unchecked();
+        let worker = ctx.data_unchecked::<OffChainWorker>();
+        let blocks = worker.block_height_subscribe();
+        let nonce = nonce.into();
+
+        let mut last_status = None;
+        let stream = block_updates(blocks, move || {
+            match crate::query::message_status(&database.view(), nonce) {
+                Ok(status) if Some(status) == last_status => BlockUpdate::Skip,
+                Ok(status) if status.state == entities::message::MessageState::Spent => {
+                    BlockUpdate::Last(Ok(MessageStatus(status)))
+                }
+                Ok(status) => {
+                    last_status = Some(status);
+                    BlockUpdate::Next(Ok(MessageStatus(status)))
+                }
+                Err(err) => BlockUpdate::Last(Err(async_graphql::Error::from(err))),
+            }
+        });
+
+        Ok(stream)
+    }
+
+    /// Returns the proof of the output message once it can be proven relatively to
+    /// the commit block at `commit_block_height`, and ends the stream. The proof is
+    /// available after the transaction with the message and the commit block are processed.
+    /// The stream ends with an error if the transaction didn't succeed or doesn't
+    /// contain the message.
+    ///
+    /// This stream will wait forever so it's advised to use within a timeout.
+    async fn message_proof_ready<'a>(
+        &self,
+        ctx: &Context<'a>,
+        transaction_id: TransactionId,
+        nonce: Nonce,
+        commit_block_height: U32,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<MessageProof>> + 'a>
+    {
+        let database: &ReadDatabase = ctx.data_unchecked();
+        let worker = ctx.data_unchecked::<OffChainWorker>();
+        let blocks = worker.block_height_subscribe();
+        let transaction_id = transaction_id.into();
+        let nonce = nonce.into();
+        let height = commit_block_height.0.into();
+
+        let stream = block_updates(blocks, move || {
+            let query = database.view();
+
+            // The proof can't be generated before the commit block is processed.
+            match query.latest_height() {
+                Ok(latest_height) if latest_height >= height => {}
+                Ok(_) => return BlockUpdate::Skip,
+                Err(err) => return BlockUpdate::Last(Err(err.into())),
+            }
+
+            match query.tx_status(&transaction_id) {
+                Ok(TransactionStatus::Success { .. }) => {}
+                Ok(TransactionStatus::Submitted { .. }) => return BlockUpdate::Skip,
+                Ok(_) => {
+                    return BlockUpdate::Last(Err(anyhow!(
+                        "The transaction {transaction_id} didn't succeed"
+                    )
+                    .into()))
+                }
+                // The transaction is not processed by the off-chain worker yet.
+                Err(err) if err.is_not_found() => return BlockUpdate::Skip,
+                Err(err) => return BlockUpdate::Last(Err(err.into())),
+            }
+
+            match crate::query::message_proof(&query, transaction_id, nonce, height) {
+                Ok(Some(proof)) => BlockUpdate::Last(Ok(MessageProof(proof))),
+                Ok(None) => BlockUpdate::Last(Err(anyhow!(
+                    "The output message with the nonce {nonce} of the transaction \
+                    {transaction_id} can't be proven at the height {height}"
+                )
+                .into())),
+                Err(err) => BlockUpdate::Last(Err(async_graphql::Error::from(err))),
+            }
+        });
+
+        Ok(stream)
+    }
+}
+
+fn commit_height(
+    query: &ReadView,
+    commit_block_id: Option<BlockId>,
+    commit_block_height: Option<U32>,
+) -> async_graphql::Result<BlockHeight> {
+    let height = match (commit_block_id, commit_block_height) {
+        (Some(commit_block_id), None) => query.block_height(&commit_block_id.0.into())?,
+        (None, Some(commit_block_height)) => commit_block_height.0.into(),
+        _ => Err(anyhow::anyhow!(
+            "Either `commit_block_id` or `commit_block_height` must be provided exclusively"
+        ))?,
+    };
+
+    Ok(height)
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct MessageProofInput {
+    /// Transaction id that contains the output message.
+    transaction_id: TransactionId,
+    /// The `Nonce` identifier of the output message.
+    nonce: Nonce,
+}
+
 pub struct MerkleProof(pub(crate) entities::message::MerkleProof);
 
 #[Object]
@@ -5,7 +5,9 @@ use crate::{
             TxPool,
         },
         database::ReadView,
+        limit_exceeded,
         ports::OffChainDatabase,
+        require_index,
         Config,
         IntoApiResult,
     },
@@ -18,12 +20,20 @@ use crate::{
     schema::{
         scalars::{
             Address,
+            Bytes32,
+            ContractId,
             HexString,
             SortedTxCursor,
             TransactionId,
             TxPointer,
+            U32,
         },
-        tx::types::TransactionStatus,
+        tx::types::{
+            DryRunTransactionExecutionStatus,
+            TransactionStatus,
+        },
+        DRY_RUN_REWIND_EXCEEDED,
+        DRY_RUN_TRANSACTIONS_EXCEEDED,
     },
 };
 use async_graphql::{
@@ -56,7 +66,10 @@ use fuel_core_types::{
         CheckPredicateParams,
         EstimatePredicates,
     },
-    services::txpool,
+    services::{
+        graphql_api::OffChainIndex,
+        txpool,
+    },
 };
 use futures::{
     Stream,
@@ -73,6 +86,8 @@ use types::Transaction;
 pub mod input;
 pub mod output;
 pub mod receipt;
+pub mod state_diff;
+pub mod trace;
 pub mod types;
 
 #[derive(Default)]
@@ -99,6 +114,9 @@ impl TxQuery {
         }
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -111,6 +129,7 @@ impl TxQuery {
     > {
         let query: &ReadView = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -160,6 +179,9 @@ impl TxQuery {
         .await
     }
 
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
     async fn transactions_by_owner(
         &self,
         ctx: &Context<'_>,
@@ -170,11 +192,13 @@ impl TxQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
     {
+        require_index(ctx, OffChainIndex::Owners)?;
         let query: &ReadView = ctx.data_unchecked();
         let config = ctx.data_unchecked::<Config>();
         let owner = fuel_types::Address::from(owner);
 
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -196,6 +220,135 @@ impl TxQuery {
         .await
     }
 
+    /// Returns the transactions that use the contract as an input,
+    /// ordered by the position of the transaction in the chain.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
+    async fn transactions_by_contract(
+        &self,
+        ctx: &Context<'_>,
+        contract_id: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        require_index(ctx, OffChainIndex::Contracts)?;
+        let query: &ReadView = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<Config>();
+        let contract_id = fuel_types::ContractId::from(contract_id);
+
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .contract_transactions(contract_id, start, direction)
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&config.consensus_parameters.chain_id);
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
+    /// Returns the transactions that spend from the predicate with the `predicate_root`,
+    /// ordered by the position of the transaction in the chain.
+    /// Requires the index of predicate roots to be enabled on the node.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
+    async fn transactions_by_predicate_root(
+        &self,
+        ctx: &Context<'_>,
+        predicate_root: Address,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        require_index(ctx, OffChainIndex::PredicateRoots)?;
+        let query: &ReadView = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<Config>();
+        let predicate_root = fuel_types::Address::from(predicate_root);
+
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .predicate_transactions(predicate_root, start, direction)
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&config.consensus_parameters.chain_id);
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
+    /// Returns the transactions with the script of the `script_hash` or that deploy
+    /// the contract bytecode with the `script_hash` root, ordered by the position
+    /// of the transaction in the chain.
+    /// Requires the index of script hashes to be enabled on the node.
+    #[graphql(
+        complexity = "crate::schema::page_complexity(first, last, child_complexity)"
+    )]
+    async fn transactions_by_script_hash(
+        &self,
+        ctx: &Context<'_>,
+        script_hash: Bytes32,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        require_index(ctx, OffChainIndex::ScriptHashes)?;
+        let query: &ReadView = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<Config>();
+        let script_hash = fuel_types::Bytes32::from(script_hash);
+
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .script_transactions(script_hash, start, direction)
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&config.consensus_parameters.chain_id);
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
     /// Estimate the predicate gas for the provided transaction
     async fn estimate_predicates(
         &self,
@@ -233,24 +386,89 @@ pub struct TxMutation;
 
 #[Object]
 impl TxMutation {
-    /// Execute a dry-run of the transaction using a fork of current state, no changes are committed.
+    /// Execute a dry-run of multiple transactions using a fork of current state, no changes are committed.
+    /// Transactions are executed in order, so each of them can spend the outputs of the previous ones.
+    /// The number of transactions is limited by the configuration of the node.
     async fn dry_run(
         &self,
         ctx: &Context<'_>,
-        tx: HexString,
+        txs: Vec<HexString>,
         // If set to false, disable input utxo validation, overriding the configuration of the node.
         // This allows for non-existent inputs to be used without signature validation
         // for read-only calls.
         utxo_validation: Option<bool>,
-    ) -> async_graphql::Result<Vec<receipt::Receipt>> {
+        // If set to true, collect the per-instruction trace of the execution.
+        // The dry run fails if the trace exceeds the limit of steps of the node.
+        trace: Option<bool>,
+        // If set to true, record the changes of the state made by each transaction.
+        state_diff: Option<bool>,
+        // The height of the block to execute transactions at. If the block already exists,
+        // transactions use its timestamp and DA height, and the state of the previous block.
+        // The dry run fails if the node doesn't have the history of the state at that height,
+        // or if the state should be rewound by more blocks than the node allows per request.
+        // By default, transactions are executed on top of the latest block.
+        block_height: Option<U32>,
+    ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
         let block_producer = ctx.data_unchecked::<BlockProducer>();
         let config = ctx.data_unchecked::<Config>();
 
-        let mut tx = FuelTx::from_bytes(&tx.0)?;
-        tx.precompute(&config.consensus_parameters.chain_id)?;
+        if txs.is_empty() {
+            return Err(
+                anyhow::anyhow!("The list of transactions to dry run is empty").into(),
+            )
+        }
+        let max_transactions = config.limits.max_dry_run_transactions;
+        if txs.len() > max_transactions {
+            return Err(limit_exceeded(
+                DRY_RUN_TRANSACTIONS_EXCEEDED,
+                format!(
+                    "The number of transactions {} exceeds the limit {max_transactions}",
+                    txs.len()
+                ),
+            ))
+        }
 
-        let receipts = block_producer.dry_run_tx(tx, None, utxo_validation).await?;
-        Ok(receipts.iter().map(Into::into).collect())
+        // The transactions are executed on top of the state of the previous block, which
+        // is rebuilt by reverting every block above it, so the number of reverted blocks
+        // is limited per request.
+        if let Some(height) = block_height {
+            let query: &ReadView = ctx.data_unchecked();
+            let latest_height = u32::from(query.latest_block_height()?);
+            let previous_height = u32::from(height).saturating_sub(1);
+            let rewind = latest_height.saturating_sub(previous_height);
+            let max_rewind = config.limits.max_dry_run_rewind;
+            if rewind > max_rewind {
+                return Err(limit_exceeded(
+                    DRY_RUN_REWIND_EXCEEDED,
+                    format!(
+                        "The dry run at the height {height} rewinds the state by {rewind} blocks, \
+                         which exceeds the limit {max_rewind}"
+                    ),
+                ))
+            }
+        }
+
+        let mut transactions = txs
+            .iter()
+            .map(|tx| FuelTx::from_bytes(&tx.0))
+            .collect::<Result<Vec<FuelTx>, _>>()?;
+        for tx in transactions.iter_mut() {
+            tx.precompute(&config.consensus_parameters.chain_id)?;
+        }
+
+        let statuses = block_producer
+            .dry_run_txs(
+                transactions,
+                block_height.map(Into::into),
+                utxo_validation,
+                trace.unwrap_or(false),
+                state_diff.unwrap_or(false),
+            )
+            .await?;
+        Ok(statuses
+            .into_iter()
+            .map(DryRunTransactionExecutionStatus)
+            .collect())
     }
 
     /// Submits transaction to the `TxPool`.
@@ -0,0 +1,107 @@
+use crate::schema::{
+    coins::Coin,
+    scalars::{
+        AssetId,
+        Bytes32,
+        ContractId,
+        Nonce,
+        UtxoId,
+        U64,
+    },
+};
+use async_graphql::Object;
+use fuel_core_types::services::executor;
+
+pub struct StateDiff(pub executor::StateDiff);
+
+#[Object]
+impl StateDiff {
+    /// The coins created from the outputs of the transaction.
+    async fn coins_created(&self) -> Vec<Coin> {
+        self.0
+            .coins_created
+            .iter()
+            .map(|coin| Coin(*coin))
+            .collect()
+    }
+
+    /// The coins spent by the inputs of the transaction.
+    async fn coins_spent(&self) -> Vec<UtxoId> {
+        self.0.coins_spent.iter().map(|id| (*id).into()).collect()
+    }
+
+    /// The messages consumed by the inputs of the transaction.
+    async fn messages_consumed(&self) -> Vec<Nonce> {
+        self.0
+            .messages_consumed
+            .iter()
+            .map(|nonce| (*nonce).into())
+            .collect()
+    }
+
+    /// The changed slots of the contracts storage.
+    async fn storage_changes(&self) -> Vec<StorageSlotChange> {
+        self.0
+            .storage_changes
+            .iter()
+            .cloned()
+            .map(StorageSlotChange)
+            .collect()
+    }
+
+    /// The changed balances of the contracts.
+    async fn balance_changes(&self) -> Vec<BalanceChange> {
+        self.0
+            .balance_changes
+            .iter()
+            .cloned()
+            .map(BalanceChange)
+            .collect()
+    }
+}
+
+pub struct StorageSlotChange(pub executor::StorageSlotChange);
+
+#[Object]
+impl StorageSlotChange {
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    /// The value before the execution, `null` if the slot was unset.
+    async fn previous(&self) -> Option<Bytes32> {
+        self.0.previous.map(Into::into)
+    }
+
+    /// The value after the execution, `null` if the slot was cleared.
+    async fn current(&self) -> Option<Bytes32> {
+        self.0.current.map(Into::into)
+    }
+}
+
+pub struct BalanceChange(pub executor::BalanceChange);
+
+#[Object]
+impl BalanceChange {
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    /// The balance before the execution.
+    async fn previous(&self) -> U64 {
+        self.0.previous.into()
+    }
+
+    /// The balance after the execution.
+    async fn current(&self) -> U64 {
+        self.0.current.into()
+    }
+}
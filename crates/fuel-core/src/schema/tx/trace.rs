@@ -0,0 +1,162 @@
+use crate::schema::scalars::{
+    AssetId,
+    Bytes32,
+    ContractId,
+    U32,
+    U64,
+    U8,
+};
+use async_graphql::{
+    Enum,
+    Object,
+};
+use fuel_core_types::{
+    fuel_asm::Instruction,
+    services::executor,
+};
+
+pub struct TransactionTrace(pub executor::TransactionTrace);
+
+#[Object]
+impl TransactionTrace {
+    /// The executed instructions in the order of execution.
+    async fn steps(&self) -> Vec<TraceStep> {
+        self.0.steps.iter().cloned().map(TraceStep).collect()
+    }
+}
+
+pub struct TraceStep(pub executor::TraceStep);
+
+#[Object]
+impl TraceStep {
+    async fn pc(&self) -> U64 {
+        self.0.pc.into()
+    }
+
+    /// The raw instruction.
+    async fn instruction(&self) -> U32 {
+        self.0.instruction.into()
+    }
+
+    /// The name of the instruction's opcode, if the instruction is valid.
+    async fn opcode(&self) -> Option<String> {
+        Instruction::try_from(self.0.instruction)
+            .ok()
+            .map(|instruction| format!("{:?}", instruction.opcode()))
+    }
+
+    async fn gas_used(&self) -> U64 {
+        self.0.gas_used.into()
+    }
+
+    /// The registers modified by the instruction with their new values.
+    async fn changed_registers(&self) -> Vec<RegisterChange> {
+        self.0
+            .changed_registers
+            .iter()
+            .map(|(register, value)| RegisterChange {
+                register: *register,
+                value: *value,
+            })
+            .collect()
+    }
+
+    /// The contract call frames active during the instruction, starting from the outermost.
+    async fn call_frames(&self) -> Vec<TraceCallFrame> {
+        self.0
+            .call_frames
+            .iter()
+            .cloned()
+            .map(TraceCallFrame)
+            .collect()
+    }
+
+    async fn storage_access(&self) -> Option<StorageAccess> {
+        self.0.storage_access.clone().map(StorageAccess)
+    }
+
+    async fn panic_reason(&self) -> Option<String> {
+        self.0.panic_reason.map(|reason| reason.to_string())
+    }
+}
+
+pub struct RegisterChange {
+    register: u8,
+    value: u64,
+}
+
+#[Object]
+impl RegisterChange {
+    async fn register(&self) -> U8 {
+        self.register.into()
+    }
+
+    async fn value(&self) -> U64 {
+        self.value.into()
+    }
+}
+
+pub struct TraceCallFrame(pub executor::TraceCallFrame);
+
+#[Object]
+impl TraceCallFrame {
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    async fn code_size(&self) -> U64 {
+        self.0.code_size.into()
+    }
+
+    async fn param1(&self) -> U64 {
+        self.0.param1.into()
+    }
+
+    async fn param2(&self) -> U64 {
+        self.0.param2.into()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Enum, Eq, PartialEq)]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+    Clear,
+}
+
+impl From<executor::StorageAccessKind> for StorageAccessKind {
+    fn from(kind: executor::StorageAccessKind) -> Self {
+        match kind {
+            executor::StorageAccessKind::Read => StorageAccessKind::Read,
+            executor::StorageAccessKind::Write => StorageAccessKind::Write,
+            executor::StorageAccessKind::Clear => StorageAccessKind::Clear,
+        }
+    }
+}
+
+pub struct StorageAccess(pub executor::StorageAccess);
+
+#[Object]
+impl StorageAccess {
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    /// The key of the first accessed slot.
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    /// The number of sequential slots accessed, starting from the `key`.
+    async fn slots(&self) -> U64 {
+        self.0.slots.into()
+    }
+
+    async fn kind(&self) -> StorageAccessKind {
+        self.0.kind.into()
+    }
+}
@@ -2,12 +2,17 @@ use super::{
     input::Input,
     output::Output,
     receipt::Receipt,
+    state_diff::StateDiff,
+    trace::TransactionTrace,
 };
 use crate::{
     fuel_core_graphql_api::{
         api_service::TxPool,
         database::ReadView,
-        ports::DatabaseBlocks,
+        ports::{
+            DatabaseBlocks,
+            OffChainDatabase,
+        },
         Config,
         IntoApiResult,
     },
@@ -75,6 +80,7 @@ use fuel_core_types::{
     fuel_types::canonical::Serialize,
     fuel_vm::ProgramState as VmProgramState,
     services::{
+        executor,
         txpool,
         txpool::TransactionStatus as TxStatus,
     },
@@ -313,6 +319,82 @@ impl From<TransactionStatus> for TxStatus {
     }
 }
 
+/// The result of the dry-run execution of the transaction.
+pub struct DryRunTransactionExecutionStatus(pub executor::TransactionExecutionStatus);
+
+#[Object]
+impl DryRunTransactionExecutionStatus {
+    async fn id(&self) -> TransactionId {
+        TransactionId(self.0.id)
+    }
+
+    async fn status(&self) -> DryRunTransactionStatus {
+        DryRunTransactionStatus::new(self.0.result.clone())
+    }
+
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.0.receipts.iter().map(Into::into).collect()
+    }
+
+    /// The per-instruction trace of the execution, if it was requested.
+    async fn trace(&self) -> Option<TransactionTrace> {
+        self.0.trace.clone().map(TransactionTrace)
+    }
+
+    /// The changes of the state made by the transaction, if they were requested.
+    async fn state_diff(&self) -> Option<StateDiff> {
+        self.0.state_diff.clone().map(StateDiff)
+    }
+}
+
+#[derive(Union, Debug)]
+pub enum DryRunTransactionStatus {
+    Success(DryRunSuccessStatus),
+    Failed(DryRunFailureStatus),
+}
+
+impl DryRunTransactionStatus {
+    pub fn new(result: executor::TransactionExecutionResult) -> Self {
+        match result {
+            executor::TransactionExecutionResult::Success { result } => {
+                DryRunTransactionStatus::Success(DryRunSuccessStatus { result })
+            }
+            executor::TransactionExecutionResult::Failed { result, reason } => {
+                DryRunTransactionStatus::Failed(DryRunFailureStatus { result, reason })
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DryRunSuccessStatus {
+    result: Option<VmProgramState>,
+}
+
+#[Object]
+impl DryRunSuccessStatus {
+    async fn program_state(&self) -> Option<ProgramState> {
+        self.result.map(Into::into)
+    }
+}
+
+#[derive(Debug)]
+pub struct DryRunFailureStatus {
+    result: Option<VmProgramState>,
+    reason: String,
+}
+
+#[Object]
+impl DryRunFailureStatus {
+    async fn program_state(&self) -> Option<ProgramState> {
+        self.result.map(Into::into)
+    }
+
+    async fn reason(&self) -> String {
+        self.reason.clone()
+    }
+}
+
 pub struct Policies(fuel_tx::policies::Policies);
 
 #[Object]
@@ -553,6 +635,17 @@ impl Transaction {
         Ok(receipts.map(|receipts| receipts.into_iter().map(Receipt).collect()))
     }
 
+    /// The changes of the state made by the transaction. Available only if the node
+    /// records the state diff of the executed transactions.
+    async fn state_diff(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<StateDiff>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let state_diff = query.tx_state_diff(&self.1)?;
+        Ok(state_diff.map(StateDiff))
+    }
+
     async fn script(&self) -> Option<HexString> {
         match &self.0 {
             fuel_tx::Transaction::Script(script) => {
@@ -25,8 +25,12 @@ use std::net::SocketAddr;
 use tracing::warn;
 
 pub use config::{
+    ApiLimitsConfig,
     Config,
     DbType,
+    DebuggerConfig,
+    IndexesConfig,
+    RateLimitConfig,
     RelayerConsensusConfig,
     VMConfig,
 };
@@ -108,7 +112,11 @@ impl FuelService {
                         config.database_path,
                         config.max_database_cache_size
                     );
-                    Database::open(&config.database_path, config.max_database_cache_size)?
+                    Database::open(
+                        &config.database_path,
+                        config.max_database_cache_size,
+                        config.block_producer.metrics,
+                    )?
                 }
             }
             DbType::InMemory => Database::in_memory(),
@@ -195,9 +203,10 @@ pub struct Task {
 
 impl Task {
     /// Private inner method for initializing the fuel service task
-    pub fn new(mut database: Database, config: Config) -> anyhow::Result<Task> {
+    pub fn new(database: Database, config: Config) -> anyhow::Result<Task> {
         // initialize state
         tracing::info!("Initializing database");
+        let mut database = database.with_state_rewind_policy(config.state_rewind_policy);
         database.init(&config.chain_conf)?;
 
         // initialize sub services
@@ -322,9 +331,9 @@ mod tests {
             i += 1;
         }
 
-        // current services: graphql, graphql worker, txpool, PoA
+        // current services: graphql, graphql worker, txpool, PoA, debug sessions reaper
         #[allow(unused_mut)]
-        let mut expected_services = 4;
+        let mut expected_services = 5;
 
         // Relayer service is disabled with `Config::local_node`.
         // #[cfg(feature = "relayer")]
@@ -10,15 +10,26 @@ use fuel_core_consensus_module::{
     RelayerConsensusConfig,
 };
 use fuel_core_executor::executor::Executor;
-use fuel_core_services::stream::BoxStream;
+use fuel_core_services::{
+    stream::BoxStream,
+    RunnableService,
+    ServiceRunner,
+    StateWatcher,
+};
 use fuel_core_txpool::service::SharedState as TxPoolSharedState;
 #[cfg(feature = "p2p")]
 use fuel_core_types::services::p2p::peer_reputation::AppScore;
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::block_importer::SharedImportResult,
+    services::{
+        block_importer::SharedImportResult,
+        p2p::PeerId,
+    },
+};
+use std::{
+    collections::HashSet,
+    sync::Arc,
 };
-use std::sync::Arc;
 
 pub mod block_importer;
 pub mod consensus_module;
@@ -36,6 +47,32 @@ pub struct PoAAdapter {
     shared_state: Option<fuel_core_poa::service::SharedState>,
 }
 
+#[derive(Clone)]
+pub struct HealthAdapter {
+    pub database: Database,
+    pub poa: PoAAdapter,
+    pub p2p: P2PAdapter,
+    /// The reserved peers of the node. `None` if the P2P is disabled.
+    pub reserved_peers: Option<HashSet<PeerId>>,
+    #[cfg(feature = "relayer")]
+    pub relayer: Option<fuel_core_relayer::SharedState<Database>>,
+    pub services: Vec<(&'static str, StateWatcher)>,
+    pub min_connected_reserved_peers: usize,
+    pub max_block_lag: u32,
+    pub max_da_lag: u64,
+}
+
+impl HealthAdapter {
+    /// Returns the name and the state watcher of the `service` to track its readiness.
+    pub fn service<S>(service: &ServiceRunner<S>) -> (&'static str, StateWatcher)
+    where
+        S: RunnableService + 'static,
+    {
+        use fuel_core_services::Service;
+        (S::NAME, service.state_watcher())
+    }
+}
+
 #[derive(Clone)]
 pub struct TxPoolAdapter {
     service: TxPoolSharedState<P2PAdapter, Database>,
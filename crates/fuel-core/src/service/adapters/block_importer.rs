@@ -117,6 +117,9 @@ impl ExecutorDatabase for Database {
                 .insert(&tx.id(chain_id), tx)?
                 .is_some();
         }
+
+        // The history allows to execute transactions on top of the state of past blocks.
+        self.record_modifications_history(height)?;
         Ok(!found)
     }
 }
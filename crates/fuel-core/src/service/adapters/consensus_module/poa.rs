@@ -21,6 +21,7 @@ use fuel_core_poa::{
         Mode,
         SharedState,
     },
+    SyncState,
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::StorageTransaction;
@@ -59,6 +60,12 @@ impl PoAAdapter {
             .manually_produce_block(start_time, mode)
             .await
     }
+
+    /// Returns the synchronization state of the block production.
+    /// Returns `None` if the block production is disabled.
+    pub fn sync_state(&self) -> Option<SyncState> {
+        self.shared_state.as_ref().map(SharedState::sync_state)
+    }
 }
 
 #[async_trait::async_trait]
@@ -16,11 +16,12 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     fuel_tx,
-    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
     services::{
         block_producer::Components,
         executor::{
             Result as ExecutorResult,
+            TransactionExecutionStatus,
             UncommittedResult,
         },
         relayer::Event,
@@ -50,10 +51,14 @@ impl ExecutorAdapter {
 
     pub(crate) fn _dry_run(
         &self,
-        block: Components<fuel_tx::Transaction>,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        state_height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
-        self.executor.dry_run(block, utxo_validation)
+        trace: bool,
+        state_diff: bool,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
+        self.executor
+            .dry_run(block, state_height, utxo_validation, trace, state_diff)
     }
 }
 
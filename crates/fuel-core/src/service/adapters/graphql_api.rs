@@ -1,6 +1,7 @@
 use super::{
     BlockImporterAdapter,
     BlockProducerAdapter,
+    HealthAdapter,
 };
 use crate::{
     database::Database,
@@ -8,6 +9,7 @@ use crate::{
         worker,
         BlockProducerPort,
         DatabaseMessageProof,
+        HealthPort,
         P2pPort,
         TxPoolPort,
     },
@@ -17,21 +19,35 @@ use crate::{
     },
 };
 use async_trait::async_trait;
-use fuel_core_services::stream::BoxStream;
+use fuel_core_poa::SyncState;
+use fuel_core_services::{
+    stream::BoxStream,
+    State,
+};
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::{
     service::TxStatusMessage,
     types::TxId,
 };
 use fuel_core_types::{
-    entities::message::MerkleProof,
-    fuel_tx::{
-        Receipt as TxReceipt,
-        Transaction,
+    entities::{
+        coins::CoinId,
+        message::MerkleProof,
     },
+    fuel_tx::Transaction,
     fuel_types::BlockHeight,
     services::{
         block_importer::SharedImportResult,
+        executor::TransactionExecutionStatus,
+        health::{
+            ImporterReadiness,
+            P2pReadiness,
+            ReadinessReport,
+            RelayerReadiness,
+            ServiceReadiness,
+            ServiceState,
+            SyncReadiness,
+        },
         p2p::PeerInfo,
         txpool::InsertionResult,
     },
@@ -72,6 +88,10 @@ impl TxPoolPort for TxPoolAdapter {
     ) -> anyhow::Result<BoxStream<TxStatusMessage>> {
         self.service.tx_update_subscribe(id)
     }
+
+    fn spent_inputs(&self) -> Vec<CoinId> {
+        self.service.spent_inputs()
+    }
 }
 
 impl DatabaseMessageProof for Database {
@@ -86,14 +106,16 @@ impl DatabaseMessageProof for Database {
 
 #[async_trait]
 impl BlockProducerPort for BlockProducerAdapter {
-    async fn dry_run_tx(
+    async fn dry_run_txs(
         &self,
-        transaction: Transaction,
+        transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
-    ) -> anyhow::Result<Vec<TxReceipt>> {
+        trace: bool,
+        state_diff: bool,
+    ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
         self.block_producer
-            .dry_run(transaction, height, utxo_validation)
+            .dry_run(transactions, height, utxo_validation, trace, state_diff)
             .await
     }
 }
@@ -136,6 +158,110 @@ impl P2pPort for P2PAdapter {
     }
 }
 
+#[async_trait::async_trait]
+impl HealthPort for HealthAdapter {
+    async fn readiness(&self) -> ReadinessReport {
+        let peers = match self.p2p.all_peer_info().await {
+            Ok(peers) => peers,
+            Err(err) => {
+                tracing::warn!("Unable to fetch peers for the readiness report: {}", err);
+                vec![]
+            }
+        };
+
+        let p2p = self.reserved_peers.as_ref().map(|reserved_peers| {
+            let connected_reserved_peers = peers
+                .iter()
+                .filter(|peer| reserved_peers.contains(&peer.id))
+                .count();
+            P2pReadiness {
+                connected_peers: u32::try_from(peers.len()).unwrap_or(u32::MAX),
+                connected_reserved_peers: u32::try_from(connected_reserved_peers)
+                    .unwrap_or(u32::MAX),
+                min_connected_reserved_peers: u32::try_from(
+                    self.min_connected_reserved_peers,
+                )
+                .unwrap_or(u32::MAX),
+            }
+        });
+
+        let latest_height = self.database.latest_height().unwrap_or_default();
+        let highest_peer_height = peers
+            .iter()
+            .filter_map(|peer| peer.heartbeat_data.block_height)
+            .max();
+        let lag = highest_peer_height
+            .map(|height| height.saturating_sub(*latest_height))
+            .unwrap_or_default();
+        let importer = ImporterReadiness {
+            latest_height,
+            highest_peer_height,
+            lag,
+            max_lag: self.max_block_lag,
+        };
+
+        let sync = self.poa.sync_state().map(|state| match state {
+            SyncState::NotSynced => SyncReadiness {
+                synced: false,
+                synced_height: None,
+            },
+            SyncState::Synced(header) => SyncReadiness {
+                synced: true,
+                synced_height: Some(*header.height()),
+            },
+        });
+
+        #[cfg(feature = "relayer")]
+        let relayer = self.relayer.as_ref().map(|relayer| RelayerReadiness {
+            synced_da_height: relayer.synced_da_height(),
+            finalized_da_height: relayer.get_finalized_da_height().ok(),
+            latest_da_finalized_height: relayer.latest_da_finalized_height(),
+            max_lag: self.max_da_lag,
+        });
+        #[cfg(not(feature = "relayer"))]
+        let relayer: Option<RelayerReadiness> = None;
+
+        let database_writable = match self.database.check_writable() {
+            Ok(()) => true,
+            Err(err) => {
+                tracing::warn!("The database doesn't accept writes: {}", err);
+                false
+            }
+        };
+
+        let services = self
+            .services
+            .iter()
+            .map(|(name, watcher)| {
+                let (state, error) = match &*watcher.borrow() {
+                    State::NotStarted => (ServiceState::NotStarted, None),
+                    State::Starting => (ServiceState::Starting, None),
+                    State::Started => (ServiceState::Started, None),
+                    State::Stopping => (ServiceState::Stopping, None),
+                    State::Stopped => (ServiceState::Stopped, None),
+                    State::StoppedWithError(err) => {
+                        (ServiceState::StoppedWithError, Some(err.clone()))
+                    }
+                };
+                ServiceReadiness {
+                    name: name.to_string(),
+                    state,
+                    error,
+                }
+            })
+            .collect();
+
+        ReadinessReport {
+            relayer,
+            p2p,
+            sync,
+            importer,
+            database_writable,
+            services,
+        }
+    }
+}
+
 impl worker::BlockImporter for BlockImporterAdapter {
     fn block_events(&self) -> BoxStream<SharedImportResult> {
         self.events()
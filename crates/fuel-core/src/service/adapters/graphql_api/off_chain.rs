@@ -15,22 +15,44 @@ use fuel_core_storage::{
         IterDirection,
     },
     not_found,
+    tables::{
+        Coins,
+        FuelBlocks,
+        Transactions,
+    },
     Error as StorageError,
     Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
+    blockchain::block::Block,
+    entities::coins::coin::CompressedCoin,
     fuel_tx::{
         Address,
+        AssetId,
         Bytes32,
+        Cacheable,
         TxPointer,
         UtxoId,
     },
     fuel_types::{
         BlockHeight,
+        ChainId,
+        ContractId,
         Nonce,
+        Word,
+    },
+    services::{
+        executor::StateDiff,
+        graphql_api::{
+            AssetDetails,
+            IndexState,
+            OffChainIndex,
+        },
+        txpool::TransactionStatus,
     },
-    services::txpool::TransactionStatus,
+    tai64::Tai64,
 };
 
 impl OffChainDatabase for Database {
@@ -56,12 +78,26 @@ impl OffChainDatabase for Database {
             .into_boxed()
     }
 
+    fn owned_coins_ids_by_amount(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Word, UtxoId)>> {
+        self.owned_coins_ids_by_amount(owner, asset_id, Some(direction))
+            .into_boxed()
+    }
+
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
         self.get_tx_status(tx_id)
             .transpose()
             .ok_or(not_found!("TransactionId"))?
     }
 
+    fn tx_state_diff(&self, tx_id: &TxId) -> StorageResult<Option<StateDiff>> {
+        self.get_tx_state_diff(tx_id)
+    }
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
@@ -76,6 +112,78 @@ impl OffChainDatabase for Database {
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
+            block_height: tx_pointer.block_height(),
+            tx_idx: tx_pointer.tx_index(),
+        });
+        self.contract_transactions(contract_id, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn predicate_transactions_ids(
+        &self,
+        predicate_root: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
+            block_height: tx_pointer.block_height(),
+            tx_idx: tx_pointer.tx_index(),
+        });
+        self.predicate_transactions(predicate_root, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn script_transactions_ids(
+        &self,
+        script_hash: Bytes32,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
+            block_height: tx_pointer.block_height(),
+            tx_idx: tx_pointer.tx_index(),
+        });
+        self.script_transactions(script_hash, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn asset_details(&self, asset_id: &AssetId) -> StorageResult<AssetDetails> {
+        self.asset_details(asset_id)
+            .transpose()
+            .ok_or(not_found!("AssetId"))?
+    }
+
+    fn assets(
+        &self,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, AssetDetails)>> {
+        self.all_assets(start_asset, Some(direction)).into_boxed()
+    }
+
+    fn block_height_by_time(
+        &self,
+        time: Tai64,
+        direction: IterDirection,
+    ) -> StorageResult<BlockHeight> {
+        self.block_height_by_time(time, direction)?
+            .ok_or(not_found!("BlockHeight"))
+    }
+
+    fn index_state(&self, index: OffChainIndex) -> StorageResult<IndexState> {
+        self.index_state(index)?.ok_or(not_found!("IndexState"))
+    }
 }
 
 impl worker::OffChainDatabase for Database {
@@ -89,6 +197,104 @@ impl worker::OffChainDatabase for Database {
         Database::record_tx_id_owner(self, owner, block_height, tx_idx, tx_id)
     }
 
+    fn record_tx_id_contract(
+        &mut self,
+        contract_id: &ContractId,
+        block_height: BlockHeight,
+        tx_idx: u16,
+        tx_id: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        Database::record_tx_id_contract(self, contract_id, block_height, tx_idx, tx_id)
+    }
+
+    fn record_tx_id_predicate_root(
+        &mut self,
+        predicate_root: &Address,
+        block_height: BlockHeight,
+        tx_idx: u16,
+        tx_id: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        Database::record_tx_id_predicate_root(
+            self,
+            predicate_root,
+            block_height,
+            tx_idx,
+            tx_id,
+        )
+    }
+
+    fn record_tx_id_script_hash(
+        &mut self,
+        script_hash: &Bytes32,
+        block_height: BlockHeight,
+        tx_idx: u16,
+        tx_id: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        Database::record_tx_id_script_hash(self, script_hash, block_height, tx_idx, tx_id)
+    }
+
+    fn record_owned_coin_amount(
+        &mut self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: Word,
+        utxo_id: &UtxoId,
+    ) -> StorageResult<()> {
+        Database::record_owned_coin_amount(self, owner, asset_id, amount, utxo_id)
+    }
+
+    fn remove_owned_coin_amount(
+        &mut self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: Word,
+        utxo_id: &UtxoId,
+    ) -> StorageResult<()> {
+        Database::remove_owned_coin_amount(self, owner, asset_id, amount, utxo_id)
+    }
+
+    fn record_asset_mint(
+        &mut self,
+        contract_id: &ContractId,
+        sub_id: &Bytes32,
+        amount: Word,
+    ) -> StorageResult<()> {
+        Database::record_asset_mint(self, contract_id, sub_id, amount)
+    }
+
+    fn record_asset_burn(
+        &mut self,
+        contract_id: &ContractId,
+        sub_id: &Bytes32,
+        amount: Word,
+    ) -> StorageResult<()> {
+        Database::record_asset_burn(self, contract_id, sub_id, amount)
+    }
+
+    fn record_asset_holder(
+        &mut self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<()> {
+        Database::record_asset_holder(self, owner, asset_id)
+    }
+
+    fn remove_asset_holder(
+        &mut self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<()> {
+        Database::remove_asset_holder(self, owner, asset_id)
+    }
+
+    fn record_block_time(
+        &mut self,
+        time: Tai64,
+        height: BlockHeight,
+    ) -> StorageResult<()> {
+        Database::record_block_time(self, time, height)
+    }
+
     fn update_tx_status(
         &mut self,
         id: &Bytes32,
@@ -96,4 +302,85 @@ impl worker::OffChainDatabase for Database {
     ) -> StorageResult<Option<TransactionStatus>> {
         Database::update_tx_status(self, id, status)
     }
+
+    fn update_tx_state_diff(
+        &mut self,
+        id: &Bytes32,
+        state_diff: &StateDiff,
+    ) -> StorageResult<Option<StateDiff>> {
+        Database::update_tx_state_diff(self, id, state_diff)
+    }
+
+    fn get_tx_status(&self, id: &Bytes32) -> StorageResult<Option<TransactionStatus>> {
+        Database::get_tx_status(self, id)
+    }
+
+    fn index_state(&self, index: OffChainIndex) -> StorageResult<Option<IndexState>> {
+        Database::index_state(self, index)
+    }
+
+    fn record_index_state(
+        &mut self,
+        index: OffChainIndex,
+        state: &IndexState,
+    ) -> StorageResult<()> {
+        Database::record_index_state(self, index, state)
+    }
+
+    fn remove_index_state(&mut self, index: OffChainIndex) -> StorageResult<()> {
+        Database::remove_index_state(self, index)
+    }
+
+    fn reset_index(&mut self, index: OffChainIndex) -> StorageResult<()> {
+        Database::reset_index(self, index)
+    }
+
+    fn first_block_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.iter_all::<FuelBlocks>(Some(IterDirection::Forward))
+            .next()
+            .transpose()
+            .map(|entry| entry.map(|(height, _)| height))
+    }
+
+    fn latest_block_height(&self) -> StorageResult<Option<BlockHeight>> {
+        Ok(self
+            .latest_compressed_block()?
+            .map(|block| *block.header().height()))
+    }
+
+    fn full_block(
+        &self,
+        height: &BlockHeight,
+        chain_id: &ChainId,
+    ) -> StorageResult<Option<Block>> {
+        let Some(block) = self.storage::<FuelBlocks>().get(height)? else {
+            return Ok(None)
+        };
+        let transactions = block
+            .transactions()
+            .iter()
+            .map(|tx_id| {
+                let mut tx = self
+                    .storage::<Transactions>()
+                    .get(tx_id)?
+                    .ok_or(not_found!(Transactions))?
+                    .into_owned();
+                tx.precompute(chain_id).map_err(|e| {
+                    anyhow::anyhow!(
+                        "Failed to compute the id of the transaction {tx_id}: {e:?}"
+                    )
+                })?;
+                Ok(tx)
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+        Ok(Some(block.into_owned().uncompress(transactions)))
+    }
+
+    fn unspent_coins(
+        &self,
+        start: &UtxoId,
+    ) -> BoxedIter<'_, StorageResult<(UtxoId, CompressedCoin)>> {
+        self.iter_all_by_start::<Coins>(Some(start), Some(IterDirection::Forward))
+            .into_boxed()
+    }
 }
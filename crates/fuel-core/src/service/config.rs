@@ -1,3 +1,4 @@
+use crate::database::history::StateRewindPolicy;
 use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
@@ -6,8 +7,13 @@ use fuel_core_chain_config::{
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     secrecy::Secret,
+    services::graphql_api::OffChainIndex,
 };
 use std::{
+    collections::{
+        BTreeSet,
+        HashSet,
+    },
     net::{
         Ipv4Addr,
         SocketAddr,
@@ -38,15 +44,22 @@ pub use fuel_core_poa::Trigger;
 pub struct Config {
     pub addr: SocketAddr,
     pub api_request_timeout: Duration,
+    pub api_limits: ApiLimitsConfig,
+    /// The indexes of the off-chain database.
+    pub indexes: IndexesConfig,
     pub max_database_cache_size: usize,
     pub database_path: PathBuf,
     pub database_type: DbType,
+    /// The policy of recording the history of the state, which allows dry runs
+    /// and debugging on top of the state of past blocks.
+    pub state_rewind_policy: StateRewindPolicy,
     pub chain_conf: ChainConfig,
     /// When `true`:
     /// - Enables manual block production.
     /// - Enables debugger endpoint.
     /// - Allows setting `utxo_validation` to `false`.
     pub debug: bool,
+    pub debugger: DebuggerConfig,
     // default to false until downstream consumers stabilize
     pub utxo_validation: bool,
     pub block_production: Trigger,
@@ -67,6 +80,10 @@ pub struct Config {
     pub min_connected_reserved_peers: usize,
     /// Time to wait after receiving the latest block before considered to be Synced.
     pub time_until_synced: Duration,
+    /// The maximum number of blocks the node can lag behind peers to be considered ready.
+    pub readiness_max_block_lag: u32,
+    /// The maximum number of DA blocks the relayer can lag behind the DA layer to be considered ready.
+    pub readiness_max_da_lag: u64,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
 }
@@ -81,6 +98,8 @@ impl Config {
         Self {
             addr: SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), 0),
             api_request_timeout: Duration::from_secs(60),
+            api_limits: Default::default(),
+            indexes: Default::default(),
             // Set the cache for tests = 10MB
             max_database_cache_size: 10 * 1024 * 1024,
             database_path: Default::default(),
@@ -88,7 +107,9 @@ impl Config {
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            state_rewind_policy: StateRewindPolicy::RewindRange { size: 1024 },
             debug: true,
+            debugger: Default::default(),
             chain_conf: chain_conf.clone(),
             block_production: Trigger::Instant,
             vm: Default::default(),
@@ -113,6 +134,8 @@ impl Config {
             relayer_consensus_config: Default::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            readiness_max_block_lag: 10,
+            readiness_max_da_lag: 10,
             query_log_threshold_time: Duration::from_secs(2),
         }
     }
@@ -149,7 +172,7 @@ impl From<&Config> for fuel_core_poa::Config {
             trigger: config.block_production,
             block_gas_limit: config.chain_conf.block_gas_limit,
             signing_key: config.consensus_key.clone(),
-            metrics: false,
+            metrics: config.block_producer.metrics,
             consensus_params: config.chain_conf.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
@@ -157,9 +180,140 @@ impl From<&Config> for fuel_core_poa::Config {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct VMConfig {
     pub backtrace: bool,
+    /// Execute non-conflicting transactions of the block concurrently.
+    pub parallel_execution: bool,
+    /// Record the changes of the state made by the transactions of the block
+    /// and store them in the off-chain database.
+    pub state_diff: bool,
+    /// The maximum number of instructions in the trace of the dry run.
+    pub max_trace_steps: usize,
+}
+
+impl Default for VMConfig {
+    fn default() -> Self {
+        Self {
+            backtrace: false,
+            parallel_execution: false,
+            state_diff: false,
+            max_trace_steps: 10_000,
+        }
+    }
+}
+
+/// The limits of the debugger sessions.
+#[derive(Clone, Debug)]
+pub struct DebuggerConfig {
+    /// The maximum number of sessions that can exist at the same time.
+    pub max_sessions: usize,
+    /// The session is killed if it is not used longer than this timeout.
+    pub session_timeout: Duration,
+    /// The maximum memory in bytes allocated by the VMs of all sessions.
+    pub max_memory: usize,
+}
+
+impl Default for DebuggerConfig {
+    fn default() -> Self {
+        Self {
+            max_sessions: 8,
+            session_timeout: Duration::from_secs(10 * 60),
+            // Enough for all sessions to evaluate a predicate along with the transaction
+            max_memory: 16 * fuel_core_types::fuel_vm::consts::MEM_SIZE,
+        }
+    }
+}
+
+/// The limits of the requests to the GraphQL API.
+#[derive(Clone, Debug)]
+pub struct ApiLimitsConfig {
+    /// The maximum depth of the query.
+    pub max_depth: usize,
+    /// The maximum complexity of the query. Each field costs one,
+    /// and the paginated fields multiply the cost of their nodes by the page size.
+    pub max_complexity: usize,
+    /// The maximum number of entries requested from the paginated field.
+    pub max_page_size: usize,
+    /// The maximum number of transactions in one dry run request.
+    pub max_dry_run_transactions: usize,
+    /// The maximum number of blocks the state is rewound by for one dry run request.
+    pub max_dry_run_rewind: u32,
+    /// The rate limit of the requests, if any.
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
+impl Default for ApiLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 16,
+            max_complexity: 80_000,
+            max_page_size: 10_000,
+            max_dry_run_transactions: 64,
+            max_dry_run_rewind: 128,
+            rate_limit: None,
+        }
+    }
+}
+
+/// The indexes of the off-chain database maintained by the GraphQL worker.
+/// The GraphQL queries that use a disabled index return an error, except the coin selection
+/// that loads all coins of the owner without the index by amount. The index enabled
+/// on the existing database is backfilled in the background.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexesConfig {
+    enabled: BTreeSet<OffChainIndex>,
+}
+
+impl IndexesConfig {
+    /// Creates the configuration with only the `indexes` enabled.
+    pub fn new(indexes: impl IntoIterator<Item = OffChainIndex>) -> Self {
+        Self {
+            enabled: indexes.into_iter().collect(),
+        }
+    }
+
+    /// Returns `true` if the worker maintains the `index`.
+    pub fn is_enabled(&self, index: OffChainIndex) -> bool {
+        self.enabled.contains(&index)
+    }
+
+    /// Enables the `index`.
+    pub fn enable(&mut self, index: OffChainIndex) {
+        self.enabled.insert(index);
+    }
+
+    /// Disables the `index`.
+    pub fn disable(&mut self, index: OffChainIndex) {
+        self.enabled.remove(&index);
+    }
+}
+
+impl Default for IndexesConfig {
+    /// All indexes are enabled, except the indexes of predicate roots and script hashes.
+    fn default() -> Self {
+        Self::new(OffChainIndex::ALL.into_iter().filter(|index| {
+            !matches!(
+                index,
+                OffChainIndex::PredicateRoots | OffChainIndex::ScriptHashes
+            )
+        }))
+    }
+}
+
+/// The rate limit of the requests to the GraphQL API.
+#[derive(Clone, Debug)]
+pub struct RateLimitConfig {
+    /// The number of requests allowed to one client during the `period`.
+    pub requests: u32,
+    pub period: Duration,
+    /// The requests with one of these keys in the `X-Api-Key` header are limited per key.
+    /// All other requests are limited per IP address of the client.
+    pub api_keys: HashSet<String>,
+    /// The header with the IP address of the client set by the trusted reverse proxy,
+    /// e.g. `X-Forwarded-For`. If it is not set, the address of the connection is used,
+    /// so all clients behind the proxy share one limit.
+    pub forwarded_for_header: Option<String>,
 }
 
 #[derive(
@@ -58,12 +58,19 @@ use fuel_core_types::{
     },
     fuel_types::{
         bytes::WORD_SIZE,
+        BlockHeight,
         Bytes32,
         ContractId,
     },
-    services::block_importer::{
-        ImportResult,
-        UncommittedResult as UncommittedImportResult,
+    services::{
+        block_importer::{
+            ImportResult,
+            UncommittedResult as UncommittedImportResult,
+        },
+        graphql_api::{
+            IndexState,
+            OffChainIndex,
+        },
     },
 };
 use itertools::Itertools;
@@ -80,7 +87,12 @@ pub fn execute_genesis_block(
     // Initialize the chain id and height.
 
     let chain_config_hash = config.chain_conf.root()?.into();
-    let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
+    let coins_root = init_coin_state(
+        database,
+        &config.chain_conf.initial_state,
+        config.indexes.is_enabled(OffChainIndex::CoinsByAmount),
+    )?
+    .into();
     let contracts_root =
         init_contracts(database, &config.chain_conf.initial_state)?.into();
     let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
@@ -94,6 +106,7 @@ pub fn execute_genesis_block(
     };
 
     let block = create_genesis_block(config);
+    init_indexes(database, config, *block.header().height())?;
     let consensus = Consensus::Genesis(genesis);
     let block = SealedBlock {
         entity: block,
@@ -153,9 +166,30 @@ pub async fn execute_and_commit_genesis_block(
     Ok(())
 }
 
+/// The new database has nothing to backfill, so the enabled indexes
+/// are built by the off-chain worker starting from the genesis block.
+fn init_indexes(
+    db: &mut Database,
+    config: &Config,
+    genesis_height: BlockHeight,
+) -> anyhow::Result<()> {
+    for index in OffChainIndex::ALL {
+        if config.indexes.is_enabled(index) {
+            db.record_index_state(
+                index,
+                &IndexState::Ready {
+                    since: genesis_height,
+                },
+            )?;
+        }
+    }
+    Ok(())
+}
+
 fn init_coin_state(
     db: &mut Database,
     state: &Option<StateConfig>,
+    index_coins_by_amount: bool,
 ) -> anyhow::Result<MerkleRoot> {
     let mut coins_tree = binary::in_memory::MerkleTree::new();
     // TODO: Store merkle sum tree root over coins with unspecified utxo ids.
@@ -215,6 +249,19 @@ fn init_coin_state(
                 {
                     return Err(anyhow!("Coin should not exist"))
                 }
+                // The genesis coins are not created by transactions,
+                // so the off-chain worker doesn't index them.
+                if index_coins_by_amount {
+                    db.record_owned_coin_amount(
+                        &coin.owner,
+                        &coin.asset_id,
+                        coin.amount,
+                        &utxo_id,
+                    )?;
+                    if coin.amount != 0 {
+                        db.record_asset_holder(&coin.owner, &coin.asset_id)?;
+                    }
+                }
                 coins_tree.push(compressed_coin.root()?.as_slice())
             }
         }
@@ -13,6 +13,7 @@ use crate::{
             BlockImporterAdapter,
             BlockProducerAdapter,
             ExecutorAdapter,
+            HealthAdapter,
             MaybeRelayerAdapter,
             PoAAdapter,
             TxPoolAdapter,
@@ -71,6 +72,9 @@ pub fn init_sub_services(
                 .unwrap_or_default(),
             backtrace: config.vm.backtrace,
             utxo_validation_default: config.utxo_validation,
+            parallel_execution: config.vm.parallel_execution,
+            state_diff: config.vm.state_diff,
+            max_trace_steps: config.vm.max_trace_steps,
         },
     );
 
@@ -149,7 +153,7 @@ pub fn init_sub_services(
         config: config.block_producer.clone(),
         view_provider: database.clone(),
         txpool: tx_pool_adapter.clone(),
-        executor: Arc::new(executor),
+        executor: Arc::new(executor.clone()),
         relayer: Box::new(relayer_adapter.clone()),
         lock: Mutex::new(()),
     };
@@ -189,9 +193,20 @@ pub fn init_sub_services(
     )?;
 
     // TODO: Figure out on how to move it into `fuel-core-graphql-api`.
+    let debug_sessions = crate::schema::dap::new_storage(
+        config.chain_conf.consensus_parameters.clone(),
+        config.debugger.clone(),
+    );
+    let debug_sessions_reaper = config.debug.then(|| {
+        crate::schema::dap::new_reaper_service(
+            debug_sessions.clone(),
+            config.debugger.session_timeout,
+        )
+    });
     let schema = crate::schema::dap::init(
         build_schema(),
-        config.chain_conf.consensus_parameters.clone(),
+        debug_sessions,
+        executor.clone(),
         config.debug,
     )
     .data(database.clone());
@@ -199,8 +214,51 @@ pub fn init_sub_services(
     let graphql_worker = fuel_core_graphql_api::worker_service::new_service(
         importer_adapter.clone(),
         database.clone(),
+        config.chain_conf.consensus_parameters.chain_id,
+        config.indexes.clone(),
     );
 
+    let mut tracked_services = vec![HealthAdapter::service(&txpool)];
+    if let Some(poa) = &poa {
+        tracked_services.push(HealthAdapter::service(poa));
+    }
+    #[cfg(feature = "relayer")]
+    if let Some(relayer) = &relayer_service {
+        tracked_services.push(HealthAdapter::service(relayer));
+    }
+    #[cfg(feature = "p2p")]
+    if let Some(network) = &network {
+        tracked_services.push(HealthAdapter::service(network));
+        tracked_services.push(HealthAdapter::service(&sync));
+    }
+    tracked_services.push(HealthAdapter::service(&graphql_worker));
+
+    #[cfg(feature = "p2p")]
+    let reserved_peers = config.p2p.as_ref().map(|p2p_config| {
+        use fuel_core_p2p::TryPeerId;
+        p2p_config
+            .reserved_nodes
+            .iter()
+            .filter_map(|address| address.try_to_peer_id())
+            .map(|peer_id| peer_id.to_bytes().into())
+            .collect()
+    });
+    #[cfg(not(feature = "p2p"))]
+    let reserved_peers = None;
+
+    let health_adapter = HealthAdapter {
+        database: database.clone(),
+        poa: poa_adapter.clone(),
+        p2p: p2p_adapter.clone(),
+        reserved_peers,
+        #[cfg(feature = "relayer")]
+        relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
+        services: tracked_services,
+        min_connected_reserved_peers: config.min_connected_reserved_peers,
+        max_block_lag: config.readiness_max_block_lag,
+        max_da_lag: config.readiness_max_da_lag,
+    };
+
     let graphql_config = GraphQLConfig {
         addr: config.addr,
         utxo_validation: config.utxo_validation,
@@ -211,6 +269,8 @@ pub fn init_sub_services(
         max_depth: config.txpool.max_depth,
         consensus_parameters: config.chain_conf.consensus_parameters.clone(),
         consensus_key: config.consensus_key.clone(),
+        limits: config.api_limits.clone(),
+        indexes: config.indexes.clone(),
     };
 
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
@@ -222,6 +282,8 @@ pub fn init_sub_services(
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
         Box::new(p2p_adapter),
+        Arc::new(health_adapter),
+        graphql_worker.shared.clone(),
         config.query_log_threshold_time,
         config.api_request_timeout,
     )?;
@@ -266,5 +328,9 @@ pub fn init_sub_services(
 
     services.push(Box::new(graphql_worker));
 
+    if let Some(debug_sessions_reaper) = debug_sessions_reaper {
+        services.push(Box::new(debug_sessions_reaper));
+    }
+
     Ok((services, shared))
 }
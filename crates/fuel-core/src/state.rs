@@ -1,5 +1,6 @@
 use crate::{
     database::{
+        history::HistoricalView,
         Column,
         Database,
         Error as DatabaseError,
@@ -15,7 +16,12 @@ use fuel_core_storage::{
         IterDirection,
         IteratorableStore,
     },
-    kv_store::BatchOperations,
+    kv_store::{
+        BatchOperations,
+        Value,
+    },
+    Error as StorageError,
+    Result as StorageResult,
 };
 use std::{
     fmt::Debug,
@@ -44,6 +50,12 @@ impl From<Arc<rocks_db::RocksDb>> for DataSource {
     }
 }
 
+impl From<Arc<HistoricalView>> for DataSource {
+    fn from(inner: Arc<HistoricalView>) -> Self {
+        Self(inner)
+    }
+}
+
 impl From<Arc<MemoryStore>> for DataSource {
     fn from(inner: Arc<MemoryStore>) -> Self {
         Self(inner)
@@ -74,4 +86,17 @@ pub trait TransactableStorage:
     }
 
     fn flush(&self) -> DatabaseResult<()>;
+
+    /// Checks that the storage accepts writes without modifying it.
+    fn check_writable(&self) -> DatabaseResult<()> {
+        Ok(())
+    }
+
+    /// Returns the values of the keys modified by the uncommitted transaction
+    /// as they were before the transaction, or `None` if the key didn't exist.
+    fn previous_values(&self) -> StorageResult<Vec<(Column, Vec<u8>, Option<Value>)>> {
+        Err(StorageError::Other(anyhow::anyhow!(
+            "Only transactions track the modified keys"
+        )))
+    }
 }
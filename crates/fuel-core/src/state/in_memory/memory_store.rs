@@ -31,12 +31,21 @@ use std::{
     },
 };
 
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct MemoryStore {
     // TODO: Remove `Mutex`.
     inner: [Mutex<BTreeMap<Vec<u8>, Value>>; Column::COUNT],
 }
 
+impl Default for MemoryStore {
+    fn default() -> Self {
+        // The array of more than 32 elements doesn't implement `Default`.
+        Self {
+            inner: core::array::from_fn(|_| Default::default()),
+        }
+    }
+}
+
 impl MemoryStore {
     pub fn iter_all(
         &self,
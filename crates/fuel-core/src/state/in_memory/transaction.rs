@@ -56,7 +56,7 @@ impl MemoryTransactionView {
     {
         Self {
             view_layer: MemoryStore::default(),
-            changes: Default::default(),
+            changes: core::array::from_fn(|_| Default::default()),
             data_source: source.into(),
         }
     }
@@ -252,6 +252,25 @@ impl TransactableStorage for MemoryTransactionView {
         self.view_layer.flush()?;
         self.data_source.flush()
     }
+
+    fn previous_values(&self) -> StorageResult<Vec<(Column, Vec<u8>, Option<Value>)>> {
+        let mut previous_values = vec![];
+        for (column_map, column) in
+            self.changes.iter().zip(enum_iterator::all::<Column>())
+        {
+            let keys: Vec<_> = column_map
+                .lock()
+                .expect("poisoned lock")
+                .keys()
+                .cloned()
+                .collect();
+            for key in keys {
+                let previous = self.data_source.get(&key, column)?;
+                previous_values.push((column, key, previous));
+            }
+        }
+        Ok(previous_values)
+    }
 }
 
 #[cfg(test)]
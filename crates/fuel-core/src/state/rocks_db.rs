@@ -12,7 +12,11 @@ use crate::{
         TransactableStorage,
     },
 };
-use fuel_core_metrics::core_metrics::database_metrics;
+use fuel_core_metrics::core_metrics::{
+    database_metrics,
+    ColumnLabel,
+    DatabaseMetrics,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -22,6 +26,7 @@ use fuel_core_storage::{
     kv_store::{
         KVItem,
         KeyValueStore,
+        StorageColumn,
         Value,
         WriteOperation,
     },
@@ -30,6 +35,7 @@ use fuel_core_storage::{
 use rand::RngCore;
 use rocksdb::{
     checkpoint::Checkpoint,
+    properties,
     BlockBasedOptions,
     BoundColumnFamily,
     Cache,
@@ -51,10 +57,14 @@ use std::{
         PathBuf,
     },
     sync::Arc,
+    time::Duration,
 };
 
 type DB = DBWithThreadMode<MultiThreaded>;
 
+/// The interval between the exports of the RocksDB statistics into the metrics.
+pub const METRICS_EXPORT_INTERVAL: Duration = Duration::from_secs(10);
+
 /// Reimplementation of `tempdir::TempDir` that allows creating a new
 /// instance without actually creating a new directory on the filesystem.
 /// This is needed since rocksdb requires empty directory for checkpoints.
@@ -94,6 +104,7 @@ impl Drop for ShallowTempDir {
 pub struct RocksDb {
     db: DB,
     capacity: Option<usize>,
+    columns: Vec<Column>,
 }
 
 impl RocksDb {
@@ -105,13 +116,17 @@ impl RocksDb {
             path,
             enum_iterator::all::<Column>().collect::<Vec<_>>(),
             capacity,
+            false,
         )
     }
 
+    /// Opens the database. The internal statistics of the RocksDB are collected
+    /// only if the `metrics` are enabled.
     pub fn open<P: AsRef<Path>>(
         path: P,
         columns: Vec<Column>,
         capacity: Option<usize>,
+        metrics: bool,
     ) -> DatabaseResult<RocksDb> {
         let mut block_opts = BlockBasedOptions::default();
         // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
@@ -143,6 +158,10 @@ impl RocksDb {
         let mut opts = Options::default();
         opts.create_if_missing(true);
         opts.set_compression_type(DBCompressionType::Lz4);
+        if metrics {
+            // Required for the block cache and write stall metrics
+            opts.enable_statistics();
+        }
         if let Some(capacity) = capacity {
             // Set cache size 1/3 of the capacity. Another 1/3 is
             // used by block cache and the last 1 / 3 remains for other purposes:
@@ -158,7 +177,7 @@ impl RocksDb {
                 // setup cfs
                 match DB::open_cf(&opts, &path, &[] as &[&str]) {
                     Ok(db) => {
-                        for i in columns {
+                        for i in columns.iter().copied() {
                             let opts = Self::cf_opts(i, &block_opts);
                             db.create_cf(RocksDb::col_name(i), &opts)
                                 .map_err(|e| DatabaseError::Other(e.into()))?;
@@ -183,10 +202,94 @@ impl RocksDb {
             ok => ok,
         }
         .map_err(|e| DatabaseError::Other(e.into()))?;
-        let rocks_db = RocksDb { db, capacity };
+        let rocks_db = RocksDb {
+            db,
+            capacity,
+            columns,
+        };
         Ok(rocks_db)
     }
 
+    /// Exports the internal statistics of the RocksDB into the database metrics.
+    pub fn export_metrics(&self) {
+        self.export_metrics_to(database_metrics())
+    }
+
+    /// Exports the internal statistics of the RocksDB into the `metrics`.
+    pub fn export_metrics_to(&self, metrics: &DatabaseMetrics) {
+        for column in self.columns.iter() {
+            let cf = self.cf(*column);
+            let label = ColumnLabel {
+                column: column.name().to_string(),
+            };
+            for (family, property) in [
+                (&metrics.sst_files_size, properties::TOTAL_SST_FILES_SIZE),
+                (
+                    &metrics.pending_compaction_bytes,
+                    properties::ESTIMATE_PENDING_COMPACTION_BYTES,
+                ),
+                (&metrics.memtables_size, properties::CUR_SIZE_ALL_MEM_TABLES),
+                (&metrics.estimated_keys, properties::ESTIMATE_NUM_KEYS),
+            ] {
+                let value = self.db.property_int_value_cf(&cf, property);
+                family.get_or_create(&label).set(gauge_value(value));
+            }
+        }
+
+        for (gauge, property) in [
+            (
+                &metrics.running_compactions,
+                properties::NUM_RUNNING_COMPACTIONS,
+            ),
+            (&metrics.block_cache_usage, properties::BLOCK_CACHE_USAGE),
+            (
+                &metrics.block_cache_capacity,
+                properties::BLOCK_CACHE_CAPACITY,
+            ),
+            (&metrics.write_stopped, properties::IS_WRITE_STOPPED),
+            (
+                &metrics.delayed_write_rate,
+                properties::ACTUAL_DELAYED_WRITE_RATE,
+            ),
+        ] {
+            gauge.set(gauge_value(self.db.property_int_value(property)));
+        }
+
+        if let Ok(Some(statistics)) =
+            self.db.property_value(properties::OPTIONS_STATISTICS)
+        {
+            // The tickers are cumulative, so the counters are advanced to their values.
+            for (counter, name) in [
+                (&metrics.block_cache_hits, "rocksdb.block.cache.hit"),
+                (&metrics.block_cache_misses, "rocksdb.block.cache.miss"),
+            ] {
+                let value = ticker(&statistics, name).unwrap_or_default();
+                counter.inc_by(value.saturating_sub(counter.get()));
+            }
+            let stall = ticker(&statistics, "rocksdb.stall.micros");
+            metrics.write_stall_duration.set(gauge_value(Ok(stall)));
+        }
+    }
+
+    /// Exports the metrics of the database every `interval` while the database is alive.
+    pub fn spawn_metrics_exporter(
+        db: &Arc<RocksDb>,
+        interval: Duration,
+    ) -> DatabaseResult<()> {
+        let db = Arc::downgrade(db);
+        std::thread::Builder::new()
+            .name("rocksdb-metrics".to_string())
+            .spawn(move || {
+                while let Some(db) = db.upgrade() {
+                    db.export_metrics();
+                    drop(db);
+                    std::thread::sleep(interval);
+                }
+            })
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(())
+    }
+
     pub fn checkpoint<P: AsRef<Path>>(&self, path: P) -> DatabaseResult<()> {
         Checkpoint::new(&self.db)
             .and_then(|checkpoint| checkpoint.create_checkpoint(path))
@@ -217,7 +320,12 @@ impl RocksDb {
         // All double-keys should be configured here
         match column {
             Column::OwnedCoins
+            | Column::OwnedCoinsByAmount
             | Column::TransactionsByOwnerBlockIdx
+            | Column::TransactionsByContractBlockIdx
+            | Column::TransactionsByPredicateRootBlockIdx
+            | Column::TransactionsByScriptHashBlockIdx
+            | Column::AssetHolders
             | Column::OwnedMessageIds
             | Column::ContractsAssets
             | Column::ContractsState => {
@@ -504,6 +612,45 @@ impl TransactableStorage for RocksDb {
             .map_err(|e| anyhow::anyhow!("Unable to flush SST files: {}", e))?;
         Ok(())
     }
+
+    fn check_writable(&self) -> DatabaseResult<()> {
+        let errors = self
+            .db
+            .property_int_value(properties::BACKGROUND_ERRORS)
+            .map_err(|e| DatabaseError::Other(e.into()))?
+            .unwrap_or_default();
+        if errors > 0 {
+            return Err(DatabaseError::Other(anyhow::anyhow!(
+                "RocksDB has {} background errors",
+                errors
+            )))
+        }
+        let write_stopped = self
+            .db
+            .property_int_value(properties::IS_WRITE_STOPPED)
+            .map_err(|e| DatabaseError::Other(e.into()))?
+            .unwrap_or_default();
+        if write_stopped > 0 {
+            return Err(DatabaseError::Other(anyhow::anyhow!("RocksDB stopped writes")))
+        }
+        Ok(())
+    }
+}
+
+fn gauge_value(value: Result<Option<u64>, rocksdb::Error>) -> i64 {
+    value
+        .ok()
+        .flatten()
+        .map(|value| i64::try_from(value).unwrap_or(i64::MAX))
+        .unwrap_or_default()
+}
+
+/// Returns the value of the ticker from the dump of the RocksDB statistics.
+fn ticker(statistics: &str, name: &str) -> Option<u64> {
+    statistics.lines().find_map(|line| {
+        let value = line.strip_prefix(name)?.strip_prefix(" COUNT : ")?;
+        value.trim().parse().ok()
+    })
 }
 
 /// The `None` means overflow, so there is not following prefix.
@@ -679,4 +826,62 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    #[test]
+    fn ticker_is_found_in_statistics() {
+        let statistics = "rocksdb.block.cache.miss COUNT : 12\n\
+                          rocksdb.block.cache.hit COUNT : 30\n\
+                          rocksdb.db.get.micros P50 : 1.000000 COUNT : 7 SUM : 9\n";
+
+        assert_eq!(ticker(statistics, "rocksdb.block.cache.hit"), Some(30));
+        assert_eq!(ticker(statistics, "rocksdb.block.cache.miss"), Some(12));
+        assert_eq!(ticker(statistics, "rocksdb.db.get.micros"), None);
+        assert_eq!(ticker(statistics, "rocksdb.stall.micros"), None);
+    }
+
+    #[test]
+    fn export_metrics_reports_size_of_columns() {
+        let (db, _tmp) = create_db();
+        db.put(&[0xA], Column::Metadata, Arc::new(vec![1, 2, 3]))
+            .unwrap();
+        db.flush().unwrap();
+
+        let metrics = DatabaseMetrics::default();
+        db.export_metrics_to(&metrics);
+
+        let label = ColumnLabel {
+            column: Column::Metadata.name().to_string(),
+        };
+        assert!(metrics.sst_files_size.get_or_create(&label).get() > 0);
+        assert_eq!(metrics.estimated_keys.get_or_create(&label).get(), 1);
+    }
+
+    #[test]
+    fn export_metrics_reports_block_cache_statistics() {
+        let tmp_dir = TempDir::new().unwrap();
+        let db = RocksDb::open(
+            tmp_dir.path(),
+            enum_iterator::all::<Column>().collect::<Vec<_>>(),
+            Some(1024 * 1024),
+            true,
+        )
+        .unwrap();
+        let key = vec![0xA];
+        db.put(&key, Column::Metadata, Arc::new(vec![1, 2, 3]))
+            .unwrap();
+        db.flush().unwrap();
+        // The first read of the flushed key misses the block cache.
+        db.get(&key, Column::Metadata).unwrap();
+
+        let metrics = DatabaseMetrics::default();
+        db.export_metrics_to(&metrics);
+
+        assert!(metrics.block_cache_capacity.get() > 0);
+        let misses = metrics.block_cache_misses.get();
+        assert!(misses > 0);
+
+        // The repeated export doesn't count the same lookups twice.
+        db.export_metrics_to(&metrics);
+        assert_eq!(metrics.block_cache_misses.get(), misses);
+    }
 }
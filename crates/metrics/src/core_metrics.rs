@@ -1,12 +1,21 @@
 use prometheus_client::{
+    encoding::EncodeLabelSet,
     metrics::{
         counter::Counter,
+        family::Family,
+        gauge::Gauge,
         histogram::Histogram,
     },
     registry::Registry,
 };
 use std::sync::OnceLock;
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ColumnLabel {
+    // the name of the database column
+    pub column: String,
+}
+
 pub struct DatabaseMetrics {
     pub registry: Registry,
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
@@ -14,10 +23,29 @@ pub struct DatabaseMetrics {
     pub read_meter: Counter,
     pub bytes_written: Histogram,
     pub bytes_read: Histogram,
+    // The internal statistics of the RocksDB, see the `init` function for descriptions
+    pub sst_files_size: Family<ColumnLabel, Gauge>,
+    pub pending_compaction_bytes: Family<ColumnLabel, Gauge>,
+    pub memtables_size: Family<ColumnLabel, Gauge>,
+    pub estimated_keys: Family<ColumnLabel, Gauge>,
+    pub running_compactions: Gauge,
+    pub block_cache_usage: Gauge,
+    pub block_cache_capacity: Gauge,
+    pub block_cache_hits: Counter,
+    pub block_cache_misses: Counter,
+    pub write_stopped: Gauge,
+    pub delayed_write_rate: Gauge,
+    pub write_stall_duration: Gauge,
+}
+
+impl Default for DatabaseMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DatabaseMetrics {
-    fn new() -> Self {
+    pub fn new() -> Self {
         let registry = Registry::default();
 
         let write_meter: Counter = Counter::default();
@@ -35,6 +63,18 @@ impl DatabaseMetrics {
             read_meter,
             bytes_read: bytes_read_histogram,
             bytes_written: bytes_written_histogram,
+            sst_files_size: Family::default(),
+            pending_compaction_bytes: Family::default(),
+            memtables_size: Family::default(),
+            estimated_keys: Family::default(),
+            running_compactions: Gauge::default(),
+            block_cache_usage: Gauge::default(),
+            block_cache_capacity: Gauge::default(),
+            block_cache_hits: Counter::default(),
+            block_cache_misses: Counter::default(),
+            write_stopped: Gauge::default(),
+            delayed_write_rate: Gauge::default(),
+            write_stall_duration: Gauge::default(),
         }
     }
 }
@@ -60,6 +100,66 @@ pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
         "Histogram containing values of amount of bytes written per operation",
         metrics.bytes_written.clone(),
     );
+    metrics.registry.register(
+        "rocksdb_sst_files_size_bytes",
+        "The total size of the SST files of the column",
+        metrics.sst_files_size.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_pending_compaction_bytes",
+        "The estimated number of bytes the compaction needs to rewrite in the column",
+        metrics.pending_compaction_bytes.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_memtables_size_bytes",
+        "The size of the active and unflushed memtables of the column",
+        metrics.memtables_size.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_estimated_keys",
+        "The estimated number of keys in the column",
+        metrics.estimated_keys.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_running_compactions",
+        "The number of currently running compactions",
+        metrics.running_compactions.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_block_cache_usage_bytes",
+        "The memory size of the entries residing in the block cache",
+        metrics.block_cache_usage.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_block_cache_capacity_bytes",
+        "The capacity of the block cache",
+        metrics.block_cache_capacity.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_block_cache_hits",
+        "Number of the block cache hits",
+        metrics.block_cache_hits.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_block_cache_misses",
+        "Number of the block cache misses",
+        metrics.block_cache_misses.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_write_stopped",
+        "Equals 1 if the writes are stopped by RocksDB, otherwise 0",
+        metrics.write_stopped.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_delayed_write_rate_bytes",
+        "The rate of the delayed writes in bytes per second, 0 if the writes are not delayed",
+        metrics.delayed_write_rate.clone(),
+    );
+    metrics.registry.register(
+        "rocksdb_write_stall_duration_us",
+        "The total time the writes were stalled since the start",
+        metrics.write_stall_duration.clone(),
+    );
 
     metrics
 }
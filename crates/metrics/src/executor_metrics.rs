@@ -0,0 +1,79 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        histogram::{
+            exponential_buckets,
+            Histogram,
+        },
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RevertLabel {
+    // the `Revert` or the panic reason of the transaction
+    reason: String,
+}
+
+pub struct ExecutorMetrics {
+    pub registry: Registry,
+    pub tx_gas_used: Histogram,
+    pub predicate_verification_duration: Histogram,
+    reverts: Family<RevertLabel, Counter>,
+}
+
+impl Default for ExecutorMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        // from 1K to 4B gas
+        let tx_gas_used = Histogram::new(exponential_buckets(1_000.0, 4.0, 12));
+        // from 100us to 3s
+        let predicate_verification_duration =
+            Histogram::new(exponential_buckets(0.0001, 2.0, 16));
+        let reverts = Family::<RevertLabel, Counter>::default();
+
+        registry.register(
+            "executor_tx_gas_used",
+            "The gas used by each executed transaction",
+            tx_gas_used.clone(),
+        );
+
+        registry.register(
+            "executor_predicate_verification_duration_s",
+            "The duration of the verification of predicates of each transaction",
+            predicate_verification_duration.clone(),
+        );
+
+        registry.register(
+            "executor_reverted_txs",
+            "The number of reverted transactions by the reason of the revert",
+            reverts.clone(),
+        );
+
+        Self {
+            registry,
+            tx_gas_used,
+            predicate_verification_duration,
+            reverts,
+        }
+    }
+}
+
+impl ExecutorMetrics {
+    pub fn record_revert(&self, reason: &str) {
+        self.reverts
+            .get_or_create(&RevertLabel {
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
+}
+
+static EXECUTOR_METRICS: OnceLock<ExecutorMetrics> = OnceLock::new();
+pub fn executor_metrics() -> &'static ExecutorMetrics {
+    EXECUTOR_METRICS.get_or_init(ExecutorMetrics::default)
+}
@@ -2,6 +2,7 @@ use crate::timing_buckets;
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::{
+        counter::Counter,
         family::Family,
         histogram::Histogram,
     },
@@ -15,9 +16,16 @@ pub struct Label {
     path: String,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RejectionLabel {
+    // the reason of the rejection
+    reason: String,
+}
+
 pub struct GraphqlMetrics {
     pub registry: Registry,
     requests: Family<Label, Histogram>,
+    rejections: Family<RejectionLabel, Counter>,
 }
 
 impl GraphqlMetrics {
@@ -27,7 +35,17 @@ impl GraphqlMetrics {
             Histogram::new(timing_buckets().iter().cloned())
         });
         registry.register("graphql_request_duration_seconds", "", requests.clone());
-        Self { registry, requests }
+        let rejections = Family::<RejectionLabel, Counter>::default();
+        registry.register(
+            "graphql_rejected_requests",
+            "The number of requests rejected because of the API limits",
+            rejections.clone(),
+        );
+        Self {
+            registry,
+            requests,
+            rejections,
+        }
     }
 
     pub fn graphql_observe(&self, query: &str, time: f64) {
@@ -36,6 +54,14 @@ impl GraphqlMetrics {
         });
         histogram.observe(time);
     }
+
+    pub fn graphql_reject(&self, reason: &str) {
+        self.rejections
+            .get_or_create(&RejectionLabel {
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
 }
 
 static GRAPHQL_METRICS: OnceLock<GraphqlMetrics> = OnceLock::new();
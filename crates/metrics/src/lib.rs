@@ -6,12 +6,15 @@
 use std::sync::OnceLock;
 
 pub mod core_metrics;
+pub mod executor_metrics;
 pub mod future_tracker;
 pub mod graphql_metrics;
 pub mod importer;
 pub mod p2p_metrics;
+pub mod poa_metrics;
 pub mod response;
 pub mod services;
+pub mod sync_metrics;
 pub mod txpool_metrics;
 
 // recommended bucket defaults for logging response times
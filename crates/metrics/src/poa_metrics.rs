@@ -0,0 +1,68 @@
+use crate::timing_buckets;
+use prometheus_client::{
+    metrics::{
+        gauge::Gauge,
+        histogram::{
+            linear_buckets,
+            Histogram,
+        },
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+pub struct PoAMetrics {
+    pub registry: Registry,
+    pub block_production_duration: Histogram,
+    pub available_transactions: Gauge,
+    pub selected_transactions: Gauge,
+    pub gas_utilization: Histogram,
+}
+
+impl Default for PoAMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let block_production_duration = Histogram::new(timing_buckets().iter().cloned());
+        let available_transactions = Gauge::default();
+        let selected_transactions = Gauge::default();
+        let gas_utilization = Histogram::new(linear_buckets(0.1, 0.1, 10));
+
+        registry.register(
+            "poa_block_production_duration_s",
+            "The time from the trigger of the block production to the sealed block",
+            block_production_duration.clone(),
+        );
+
+        registry.register(
+            "poa_available_transactions",
+            "The number of transactions available for the latest produced block",
+            available_transactions.clone(),
+        );
+
+        registry.register(
+            "poa_selected_transactions",
+            "The number of transactions included into the latest produced block",
+            selected_transactions.clone(),
+        );
+
+        registry.register(
+            "poa_gas_utilization",
+            "The ratio of the gas used by the produced block to the block gas limit",
+            gas_utilization.clone(),
+        );
+
+        Self {
+            registry,
+            block_production_duration,
+            available_transactions,
+            selected_transactions,
+            gas_utilization,
+        }
+    }
+}
+
+static POA_METRICS: OnceLock<PoAMetrics> = OnceLock::new();
+pub fn poa_metrics() -> &'static PoAMetrics {
+    POA_METRICS.get_or_init(PoAMetrics::default)
+}
@@ -1,8 +1,12 @@
 use crate::{
+    core_metrics::database_metrics,
+    executor_metrics::executor_metrics,
     graphql_metrics::graphql_metrics,
     importer::importer_metrics,
     p2p_metrics::p2p_metrics,
+    poa_metrics::poa_metrics,
     services::services_metrics,
+    sync_metrics::sync_metrics,
     txpool_metrics::txpool_metrics,
 };
 use axum::{
@@ -46,10 +50,26 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body()
     }
 
+    if encode(&mut encoded, &database_metrics().registry).is_err() {
+        return error_body()
+    }
+
     if encode(&mut encoded, &importer_metrics().registry).is_err() {
         return error_body()
     }
 
+    if encode(&mut encoded, &poa_metrics().registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &executor_metrics().registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &sync_metrics().registry).is_err() {
+        return error_body()
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
@@ -0,0 +1,43 @@
+use prometheus_client::{
+    metrics::gauge::Gauge,
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+pub struct SyncMetrics {
+    pub registry: Registry,
+    pub header_queue_size: Gauge,
+    pub transaction_queue_size: Gauge,
+}
+
+impl Default for SyncMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let header_queue_size = Gauge::default();
+        let transaction_queue_size = Gauge::default();
+
+        registry.register(
+            "sync_header_queue_size",
+            "The number of block headers requested from the peers and not received yet",
+            header_queue_size.clone(),
+        );
+
+        registry.register(
+            "sync_transaction_queue_size",
+            "The number of blocks with transactions requested from the peers and not received yet",
+            transaction_queue_size.clone(),
+        );
+
+        Self {
+            registry,
+            header_queue_size,
+            transaction_queue_size,
+        }
+    }
+}
+
+static SYNC_METRICS: OnceLock<SyncMetrics> = OnceLock::new();
+pub fn sync_metrics() -> &'static SyncMetrics {
+    SYNC_METRICS.get_or_init(SyncMetrics::default)
+}
@@ -23,3 +23,4 @@ pub use service::{
     new_service,
     Service,
 };
+pub use sync::SyncState;
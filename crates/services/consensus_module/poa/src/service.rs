@@ -21,6 +21,7 @@ use anyhow::{
     anyhow,
     Context,
 };
+use fuel_core_metrics::poa_metrics::poa_metrics;
 use fuel_core_services::{
     stream::BoxStream,
     RunnableService,
@@ -56,6 +57,7 @@ use fuel_core_types::{
         block_importer::ImportResult,
         executor::{
             ExecutionResult,
+            TransactionExecutionStatus,
             UncommittedResult as UncommittedExecutionResult,
         },
         Uncommitted,
@@ -70,18 +72,26 @@ use tokio::{
     sync::{
         mpsc,
         oneshot,
+        watch,
     },
     time::Instant,
 };
 use tokio_stream::StreamExt;
+use tracing::Instrument;
 
 pub type Service<T, B, I> = ServiceRunner<MainTask<T, B, I>>;
 #[derive(Clone)]
 pub struct SharedState {
     request_sender: mpsc::Sender<Request>,
+    sync_state: watch::Receiver<SyncState>,
 }
 
 impl SharedState {
+    /// Returns the current synchronization state of the block production.
+    pub fn sync_state(&self) -> SyncState {
+        self.sync_state.borrow().clone()
+    }
+
     pub async fn manually_produce_block(
         &self,
         start_time: Option<Tai64>,
@@ -91,7 +101,11 @@ impl SharedState {
 
         self.request_sender
             .send(Request::ManualBlocks((
-                ManualProduction { start_time, mode },
+                ManualProduction {
+                    start_time,
+                    mode,
+                    span: tracing::Span::current(),
+                },
                 sender,
             )))
             .await?;
@@ -109,6 +123,8 @@ pub enum Mode {
 struct ManualProduction {
     pub start_time: Option<Tai64>,
     pub mode: Mode,
+    /// The span of the requester, used as a parent for the block production.
+    pub span: tracing::Span,
 }
 
 /// Requests accepted by the task.
@@ -131,6 +147,7 @@ pub(crate) enum RequestType {
 
 pub struct MainTask<T, B, I> {
     block_gas_limit: Word,
+    metrics: bool,
     signing_key: Option<Secret<SecretKeyWrapper>>,
     block_producer: B,
     block_importer: I,
@@ -171,6 +188,7 @@ where
         let Config {
             block_gas_limit,
             signing_key,
+            metrics,
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
@@ -186,16 +204,21 @@ where
         );
 
         let sync_task_handle = ServiceRunner::new(sync_task);
+        let sync_state = sync_task_handle.shared.clone();
 
         Self {
             block_gas_limit,
+            metrics,
             signing_key,
             txpool,
             block_producer,
             block_importer,
             tx_status_update_stream,
             request_receiver,
-            shared_state: SharedState { request_sender },
+            shared_state: SharedState {
+                request_sender,
+                sync_state,
+            },
             last_height,
             last_timestamp,
             last_block_created,
@@ -308,6 +331,7 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip_all, fields(height = %height))]
     async fn produce_block(
         &mut self,
         height: BlockHeight,
@@ -325,6 +349,12 @@ where
             return Err(anyhow!("The block timestamp should monotonically increase"))
         }
 
+        let available_transactions = match &source {
+            TransactionsSource::TxPool if self.metrics => self.txpool.pending_number(),
+            TransactionsSource::TxPool => 0,
+            TransactionsSource::SpecificTransactions(txs) => txs.len(),
+        };
+
         // Ask the block producer to create the block
         let (
             ExecutionResult {
@@ -355,6 +385,14 @@ where
             entity: block,
             consensus: seal,
         };
+        if self.metrics {
+            self.update_metrics(
+                &block,
+                &tx_status,
+                available_transactions,
+                last_block_created,
+            );
+        }
         // Import the sealed block
         self.block_importer
             .commit_result(Uncommitted::new(
@@ -390,6 +428,42 @@ where
         Ok(())
     }
 
+    fn update_metrics(
+        &self,
+        block: &SealedBlock,
+        tx_status: &[TransactionExecutionStatus],
+        available_transactions: usize,
+        triggered_at: Instant,
+    ) {
+        let metrics = poa_metrics();
+        metrics
+            .block_production_duration
+            .observe(triggered_at.elapsed().as_secs_f64());
+
+        let selected_transactions = block
+            .entity
+            .transactions()
+            .iter()
+            .filter(|tx| !matches!(tx, Transaction::Mint(_)))
+            .count();
+        metrics
+            .available_transactions
+            .set(i64::try_from(available_transactions).unwrap_or(i64::MAX));
+        metrics
+            .selected_transactions
+            .set(i64::try_from(selected_transactions).unwrap_or(i64::MAX));
+
+        let used_gas = tx_status
+            .iter()
+            .map(|status| status.used_gas)
+            .fold(0u64, u64::saturating_add);
+        if self.block_gas_limit > 0 {
+            metrics
+                .gas_utilization
+                .observe(used_gas as f64 / self.block_gas_limit as f64);
+        }
+    }
+
     pub(crate) async fn on_txpool_event(&mut self) -> anyhow::Result<()> {
         match self.trigger {
             Trigger::Instant => {
@@ -491,7 +565,12 @@ where
                 if let Some(request) = request {
                     match request {
                         Request::ManualBlocks((block, response)) => {
-                            let result = self.produce_manual_blocks(block).await;
+                            let span = tracing::info_span!(
+                                parent: &block.span,
+                                "produce_manual_blocks"
+                            );
+                            let result =
+                                self.produce_manual_blocks(block).instrument(span).await;
                             let _ = response.send(result);
                         }
                     }
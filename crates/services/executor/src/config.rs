@@ -3,7 +3,7 @@ use fuel_core_types::fuel_tx::{
     ContractId,
 };
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct Config {
     /// Network-wide common parameters used for validating the chain
     pub consensus_parameters: ConsensusParameters,
@@ -13,4 +13,26 @@ pub struct Config {
     pub backtrace: bool,
     /// Default mode for utxo_validation
     pub utxo_validation_default: bool,
+    /// Execute non-conflicting transactions of the block concurrently.
+    pub parallel_execution: bool,
+    /// Record the changes of the state made by the executed transactions.
+    pub state_diff: bool,
+    /// The maximum number of instructions in the trace of the dry run.
+    /// The dry run fails if the trace exceeds this limit.
+    pub max_trace_steps: usize,
 }
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            consensus_parameters: Default::default(),
+            coinbase_recipient: Default::default(),
+            backtrace: false,
+            utxo_validation_default: false,
+            parallel_execution: false,
+            state_diff: false,
+            max_trace_steps: 10_000,
+        }
+    }
+}
+
@@ -1,4 +1,5 @@
 use crate::{
+    parallel,
     ports::{
         ExecutorDatabaseTrait,
         MaybeCheckedTransaction,
@@ -6,9 +7,16 @@ use crate::{
         TransactionsSource,
     },
     refs::ContractRef,
+    state_diff::{
+        self,
+        ContractChanges,
+        RecordingStorage,
+    },
+    tracer,
     Config,
 };
 use block_component::*;
+use fuel_core_metrics::executor_metrics::executor_metrics;
 use fuel_core_storage::{
     tables::{
         Coins,
@@ -130,9 +138,18 @@ use fuel_core_types::{
     },
 };
 use parking_lot::Mutex as ParkingMutex;
+use rayon::prelude::*;
 use std::{
     borrow::Cow,
+    panic::{
+        self,
+        AssertUnwindSafe,
+    },
     sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tracing::{
     debug,
@@ -186,12 +203,17 @@ where
         &self,
         block: fuel_core_types::services::executor::ExecutionBlock,
         options: ExecutionOptions,
-    ) -> ExecutorResult<ExecutionResult> {
+    ) -> ExecutorResult<ExecutionResult>
+    where
+        R::View: Sync,
+        D::View: Send + Sync,
+    {
         let executor = ExecutionInstance {
             database: self.database_view_provider.latest_view(),
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            parallel_execution: self.parallel_execution(),
         };
         executor.execute_and_commit(block)
     }
@@ -205,52 +227,136 @@ where
     ) -> ExecutorResult<ExecutionData>
     where
         TxSource: TransactionsSource,
+        R::View: Sync,
+        D::View: Send + Sync,
     {
         let executor = ExecutionInstance {
             database: self.database_view_provider.latest_view(),
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            parallel_execution: self.parallel_execution(),
         };
         let mut block_transaction = executor.database.transaction();
         executor.execute_block(block_transaction.as_mut(), block)
     }
 
+    /// Executes the block without committing the result. The non-conflicting
+    /// transactions are executed concurrently if the parallel execution is enabled,
+    /// which requires sharing the views between threads.
     pub fn execute_without_commit<TxSource>(
         &self,
         block: ExecutionBlockWithSource<TxSource>,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<View>>>
     where
         TxSource: TransactionsSource,
+        R::View: Sync,
+        D::View: Send + Sync,
     {
         let executor = ExecutionInstance {
             database: self.database_view_provider.latest_view(),
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options: self.config.as_ref().into(),
+            parallel_execution: self.parallel_execution(),
         };
         executor.execute_inner(block)
     }
 
+    fn parallel_execution(&self) -> Option<ParallelExecution<R::View, View>>
+    where
+        R::View: Sync,
+        D::View: Send + Sync,
+    {
+        self.config
+            .parallel_execution
+            .then_some(ExecutionInstance::execute_transactions_in_parallel as _)
+    }
+
+    /// Executes the transactions on top of the state at the `state_height`,
+    /// or on top of the latest state if it is `None`.
     pub fn dry_run(
         &self,
-        component: Components<Transaction>,
+        component: Components<Vec<Transaction>>,
+        state_height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        trace: bool,
+        state_diff: bool,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
         // fallback to service config value if no utxo_validation override is provided
         let utxo_validation =
             utxo_validation.unwrap_or(self.config.utxo_validation_default);
 
-        let options = ExecutionOptions { utxo_validation };
+        let options = ExecutionOptions {
+            utxo_validation,
+            trace,
+            state_diff,
+        };
+
+        // Only the historical state is rewound. The state above the latest block
+        // doesn't exist, e.g. if the block was imported after the `state_height`
+        // was chosen, so the latest state is used instead.
+        let database = match state_height {
+            Some(height) if height < self.database_view_provider.latest_height() => {
+                self.database_view_provider.view_at(&height)?
+            }
+            _ => self.database_view_provider.latest_view(),
+        };
 
         let executor = ExecutionInstance {
-            database: self.database_view_provider.latest_view(),
+            database,
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            // The dry runs are executed sequentially.
+            parallel_execution: None,
         };
         executor.dry_run(component)
     }
+
+    /// Executes the `transactions` as a part of the existing block on top of the state
+    /// of the previous block and returns the uncommitted changes of the state.
+    /// The `Mint` transaction is not executed, so the `transactions` can be only
+    /// the beginning of the block. The `coinbase_recipient` is the fee recipient of the block.
+    pub fn execute_block_prefix(
+        &self,
+        header: PartialBlockHeader,
+        transactions: Vec<Transaction>,
+        coinbase_recipient: ContractId,
+    ) -> ExecutorResult<StorageTransaction<View>> {
+        let previous_height = header
+            .height()
+            .pred()
+            .ok_or(ExecutorError::ExecutingGenesisBlock)?;
+
+        let executor = ExecutionInstance {
+            database: self.database_view_provider.view_at(&previous_height)?,
+            relayer: self.relayer_view_provider.latest_view(),
+            config: Arc::new(Config {
+                coinbase_recipient,
+                ..self.config.as_ref().clone()
+            }),
+            options: ExecutionOptions {
+                utxo_validation: self.config.utxo_validation_default,
+                trace: false,
+                state_diff: false,
+            },
+            parallel_execution: None,
+        };
+        let component = Components {
+            header_to_produce: header,
+            transactions_source: OnceTransactionsSource::new(transactions),
+            gas_limit: u64::MAX,
+        };
+
+        let (result, changes) = executor
+            .execute_without_commit(ExecutionTypes::DryRun(component))?
+            .into();
+        if let Some((_, error)) = result.skipped_transactions.into_iter().next() {
+            return Err(error)
+        }
+        Ok(changes)
+    }
 }
 
 /// Data that is generated after executing all transactions.
@@ -262,20 +368,36 @@ pub struct ExecutionData {
     found_mint: bool,
     message_ids: Vec<MessageId>,
     tx_status: Vec<TransactionExecutionStatus>,
+    tx_metrics: Vec<TransactionMetrics>,
     pub skipped_transactions: Vec<(TxId, ExecutorError)>,
 }
 
+/// The metrics of the executed transaction. They are recorded after the execution
+/// of the whole block, so the transactions re-executed sequentially after the failed
+/// parallel execution are recorded only once.
+struct TransactionMetrics {
+    used_gas: u64,
+    predicate_verification_duration: Option<Duration>,
+    revert: Option<String>,
+}
+
 /// Per-block execution options
 #[derive(Copy, Clone, Default, Debug)]
 pub struct ExecutionOptions {
     /// UTXO Validation flag, when disabled the executor skips signature and UTXO existence checks
     pub utxo_validation: bool,
+    /// Collect the per-instruction trace of the executed transactions.
+    pub trace: bool,
+    /// Record the changes of the state made by the executed transactions.
+    pub state_diff: bool,
 }
 
 impl From<&Config> for ExecutionOptions {
     fn from(value: &Config) -> Self {
         Self {
             utxo_validation: value.utxo_validation_default,
+            trace: false,
+            state_diff: value.state_diff,
         }
     }
 }
@@ -284,14 +406,28 @@ impl From<&Config> for ExecutionOptions {
 /// the transactions contained in the block and persist changes to the underlying database as needed.
 /// In production mode, block fields like transaction commitments are set based on the executed txs.
 /// In validation mode, the processed block commitments are compared with the proposed block.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 struct ExecutionInstance<R, D> {
     pub relayer: R,
     pub database: D,
     pub config: Arc<Config>,
     pub options: ExecutionOptions,
+    /// Executes the transactions concurrently. It is `None` if the parallel execution
+    /// is disabled.
+    pub parallel_execution: Option<ParallelExecution<R, D>>,
 }
 
+/// Executes the transactions on top of the block storage transaction.
+/// Only available if the database and relayer can be shared between threads.
+type ParallelExecution<R, D> = fn(
+    &ExecutionInstance<R, D>,
+    &mut D,
+    &mut PartialFuelBlock,
+    ExecutionKind,
+    &mut ExecutionData,
+    Vec<MaybeCheckedTransaction>,
+) -> ExecutorResult<()>;
+
 impl<R, D> ExecutionInstance<R, D>
 where
     R: RelayerPort,
@@ -338,13 +474,13 @@ where
 
     pub fn dry_run(
         self,
-        component: Components<Transaction>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        component: Components<Vec<Transaction>>,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
         let component = Components {
             header_to_produce: component.header_to_produce,
-            transactions_source: OnceTransactionsSource::new(vec![
+            transactions_source: OnceTransactionsSource::new(
                 component.transactions_source,
-            ]),
+            ),
             gas_limit: component.gas_limit,
         };
 
@@ -364,10 +500,7 @@ where
             return Err(err)
         }
 
-        Ok(tx_status
-            .into_iter()
-            .map(|tx| tx.receipts)
-            .collect::<Vec<Vec<Receipt>>>())
+        Ok(tx_status)
         // drop `_temporary_db` without committing to avoid altering state.
     }
 }
@@ -534,6 +667,7 @@ where
             found_mint: false,
             message_ids: Vec::new(),
             tx_status: Vec::new(),
+            tx_metrics: Vec::new(),
             skipped_transactions: Vec::new(),
         };
         let execution_data = &mut data;
@@ -552,67 +686,34 @@ where
         // ALl transactions should be in the `TxSource`.
         // We use `block.transactions` to store executed transactions.
         debug_assert!(block.transactions.is_empty());
-        let mut iter = source.next(remaining_gas_limit).into_iter().peekable();
-
-        let mut execute_transaction = |execution_data: &mut ExecutionData,
-                                       tx: MaybeCheckedTransaction|
-         -> ExecutorResult<()> {
-            let tx_count = execution_data.tx_count;
-            let tx = {
-                let mut tx_st_transaction = block_st_transaction.transaction();
-                let tx_id = tx.id(&self.config.consensus_parameters.chain_id);
-                let result = self.execute_transaction(
-                    tx,
-                    &tx_id,
-                    &block.header,
-                    execution_data,
+        let mut transactions = source.next(remaining_gas_limit);
+
+        while !transactions.is_empty() {
+            if let Some(execute_in_parallel) = self.parallel_execution {
+                execute_in_parallel(
+                    self,
+                    block_st_transaction,
+                    block,
                     execution_kind,
-                    &mut tx_st_transaction,
-                );
-
-                let tx = match result {
-                    Err(err) => {
-                        return match execution_kind {
-                            ExecutionKind::Production => {
-                                // If, during block production, we get an invalid transaction,
-                                // remove it from the block and continue block creation. An invalid
-                                // transaction means that the caller didn't validate it first, so
-                                // maybe something is wrong with validation rules in the `TxPool`
-                                // (or in another place that should validate it). Or we forgot to
-                                // clean up some dependent/conflict transactions. But it definitely
-                                // means that something went wrong, and we must fix it.
-                                execution_data.skipped_transactions.push((tx_id, err));
-                                Ok(())
-                            }
-                            ExecutionKind::DryRun | ExecutionKind::Validation => Err(err),
-                        }
-                    }
-                    Ok(tx) => tx,
-                };
-
-                if let Err(err) = tx_st_transaction.commit() {
-                    return Err(err.into())
+                    execution_data,
+                    transactions,
+                )?;
+            } else {
+                for transaction in transactions {
+                    self.execute_transaction_and_commit(
+                        block_st_transaction,
+                        block,
+                        execution_kind,
+                        execution_data,
+                        transaction,
+                    )?;
                 }
-                tx
-            };
-
-            block.transactions.push(tx);
-            execution_data.tx_count = tx_count
-                .checked_add(1)
-                .ok_or(ExecutorError::TooManyTransactions)?;
-
-            Ok(())
-        };
-
-        while iter.peek().is_some() {
-            for transaction in iter {
-                execute_transaction(&mut *execution_data, transaction)?;
             }
 
             remaining_gas_limit =
                 component.gas_limit.saturating_sub(execution_data.used_gas);
 
-            iter = source.next(remaining_gas_limit).into_iter().peekable();
+            transactions = source.next(remaining_gas_limit);
         }
 
         // After the execution of all transactions in production mode, we can set the final fee.
@@ -642,7 +743,10 @@ where
                 self.config.consensus_parameters.base_asset_id,
             );
 
-            execute_transaction(
+            self.execute_transaction_and_commit(
+                block_st_transaction,
+                block,
+                execution_kind,
                 execution_data,
                 MaybeCheckedTransaction::Transaction(coinbase_tx.into()),
             )?;
@@ -652,9 +756,243 @@ where
             return Err(ExecutorError::MintMissing)
         }
 
+        // The dry runs are not a part of the chain, so they are not recorded
+        if execution_kind != ExecutionKind::DryRun {
+            record_metrics(&data.tx_metrics);
+        }
+
         Ok(data)
     }
 
+    /// Executes the transaction on top of the `block_st_transaction` and
+    /// commits the result into it.
+    fn execute_transaction_and_commit(
+        &self,
+        block_st_transaction: &mut D,
+        block: &mut PartialFuelBlock,
+        execution_kind: ExecutionKind,
+        execution_data: &mut ExecutionData,
+        tx: MaybeCheckedTransaction,
+    ) -> ExecutorResult<()> {
+        let tx_count = execution_data.tx_count;
+        let tx = {
+            let mut tx_st_transaction = block_st_transaction.transaction();
+            let tx_id = tx.id(&self.config.consensus_parameters.chain_id);
+            let result = self.execute_transaction(
+                tx,
+                &tx_id,
+                &block.header,
+                execution_data,
+                execution_kind,
+                &mut tx_st_transaction,
+            );
+
+            let tx = match result {
+                Err(err) => {
+                    return match execution_kind {
+                        ExecutionKind::Production => {
+                            // If, during block production, we get an invalid transaction,
+                            // remove it from the block and continue block creation. An invalid
+                            // transaction means that the caller didn't validate it first, so
+                            // maybe something is wrong with validation rules in the `TxPool`
+                            // (or in another place that should validate it). Or we forgot to
+                            // clean up some dependent/conflict transactions. But it definitely
+                            // means that something went wrong, and we must fix it.
+                            execution_data.skipped_transactions.push((tx_id, err));
+                            Ok(())
+                        }
+                        ExecutionKind::DryRun | ExecutionKind::Validation => Err(err),
+                    }
+                }
+                Ok(tx) => tx,
+            };
+
+            if let Err(err) = tx_st_transaction.commit() {
+                return Err(err.into())
+            }
+            tx
+        };
+
+        block.transactions.push(tx);
+        execution_data.tx_count = tx_count
+            .checked_add(1)
+            .ok_or(ExecutorError::TooManyTransactions)?;
+
+        Ok(())
+    }
+}
+
+impl<R, D> ExecutionInstance<R, D>
+where
+    R: RelayerPort + Sync,
+    D: ExecutorDatabaseTrait<D> + Send + Sync,
+{
+    /// Executes non-conflicting transactions concurrently, each on its own storage
+    /// transaction, and merges the results in the order of the block. The result is
+    /// the same as of the sequential execution.
+    ///
+    /// If any transaction fails, the parallel results are discarded, and all
+    /// transactions are re-executed sequentially. It guarantees the same order of
+    /// errors and the same indexes of transactions as in the sequential mode.
+    fn execute_transactions_in_parallel(
+        &self,
+        block_st_transaction: &mut D,
+        block: &mut PartialFuelBlock,
+        execution_kind: ExecutionKind,
+        execution_data: &mut ExecutionData,
+        transactions: Vec<MaybeCheckedTransaction>,
+    ) -> ExecutorResult<()> {
+        let chain_id = &self.config.consensus_parameters.chain_id;
+        let mut transactions = transactions
+            .into_iter()
+            .map(|tx| (tx.id(chain_id), tx))
+            .collect::<Vec<_>>();
+
+        let layers = parallel::layers(&transactions);
+        // Transactions starting from the `Mint` are not scheduled,
+        // they are executed sequentially.
+        let scheduled = layers.iter().map(Vec::len).sum::<usize>();
+        let remaining = transactions.split_off(scheduled);
+
+        let executed = if scheduled > 1 {
+            self.execute_layers(
+                block_st_transaction,
+                &block.header,
+                execution_kind,
+                execution_data.tx_count,
+                &transactions,
+                layers,
+            )?
+        } else {
+            None
+        };
+
+        if let Some((batch_st_transaction, executed)) = executed {
+            batch_st_transaction.commit()?;
+            for (tx, tx_data) in executed {
+                execution_data.coinbase = execution_data
+                    .coinbase
+                    .checked_add(tx_data.coinbase)
+                    .ok_or(ExecutorError::FeeOverflow)?;
+                execution_data.used_gas =
+                    execution_data.used_gas.saturating_add(tx_data.used_gas);
+                execution_data.message_ids.extend(tx_data.message_ids);
+                execution_data.tx_status.extend(tx_data.tx_status);
+                execution_data.tx_metrics.extend(tx_data.tx_metrics);
+                execution_data.tx_count = execution_data
+                    .tx_count
+                    .checked_add(1)
+                    .ok_or(ExecutorError::TooManyTransactions)?;
+                block.transactions.push(tx);
+            }
+        } else {
+            for (_, transaction) in transactions {
+                self.execute_transaction_and_commit(
+                    block_st_transaction,
+                    block,
+                    execution_kind,
+                    execution_data,
+                    transaction,
+                )?;
+            }
+        }
+
+        for (_, transaction) in remaining {
+            self.execute_transaction_and_commit(
+                block_st_transaction,
+                block,
+                execution_kind,
+                execution_data,
+                transaction,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Executes the transactions layer by layer on top of a new storage transaction.
+    /// Returns this storage transaction with the executed transactions in the order
+    /// of the block, or `None` if at least one transaction failed.
+    ///
+    /// The transactions of one layer are executed by the global `rayon` thread pool,
+    /// which is bounded by the number of CPUs.
+    #[allow(clippy::type_complexity)]
+    fn execute_layers(
+        &self,
+        block_st_transaction: &D,
+        header: &PartialBlockHeader,
+        execution_kind: ExecutionKind,
+        first_tx_index: u16,
+        transactions: &[(TxId, MaybeCheckedTransaction)],
+        layers: Vec<Vec<usize>>,
+    ) -> ExecutorResult<Option<(StorageTransaction<D>, Vec<(Transaction, ExecutionData)>)>>
+    {
+        let batch_st_transaction = block_st_transaction.transaction();
+        let mut executed = (0..transactions.len()).map(|_| None).collect::<Vec<_>>();
+
+        for layer in layers {
+            let batch = batch_st_transaction.as_ref();
+            let results = layer
+                .par_iter()
+                .map(|index| {
+                    let (tx_id, tx) = &transactions[*index];
+                    let tx_index = u16::try_from(*index)
+                        .ok()
+                        .and_then(|index| first_tx_index.checked_add(index))
+                        .ok_or(ExecutorError::TooManyTransactions)?;
+                    // The panic inside of the worker thread is returned as an error
+                    // to not crash the block production.
+                    panic::catch_unwind(AssertUnwindSafe(|| {
+                        let mut tx_data = ExecutionData {
+                            tx_count: tx_index,
+                            ..Default::default()
+                        };
+                        let mut tx_st_transaction = batch.transaction();
+                        let tx = self.execute_transaction(
+                            tx.clone(),
+                            tx_id,
+                            header,
+                            &mut tx_data,
+                            execution_kind,
+                            &mut tx_st_transaction,
+                        )?;
+                        Ok((tx_st_transaction, tx, tx_data))
+                    }))
+                    .map_err(|_| ExecutorError::TransactionExecutionPanicked(*tx_id))?
+                })
+                .collect::<Vec<ExecutorResult<_>>>();
+
+            for (index, result) in layer.iter().zip(results) {
+                match result {
+                    Ok((tx_st_transaction, tx, tx_data)) => {
+                        tx_st_transaction.commit()?;
+                        executed[*index] = Some((tx, tx_data));
+                    }
+                    Err(err @ ExecutorError::TransactionExecutionPanicked(_)) => {
+                        return Err(err)
+                    }
+                    // The failed transaction is re-executed sequentially.
+                    Err(_) => return Ok(None),
+                }
+            }
+        }
+
+        let executed = executed
+            .into_iter()
+            .zip(transactions)
+            .map(|(executed, (tx_id, _))| {
+                executed.ok_or(ExecutorError::TransactionIsNotScheduled(*tx_id))
+            })
+            .collect::<ExecutorResult<_>>()?;
+        Ok(Some((batch_st_transaction, executed)))
+    }
+}
+
+impl<R, D> ExecutionInstance<R, D>
+where
+    R: RelayerPort,
+    D: ExecutorDatabaseTrait<D>,
+{
     fn process_da(
         &self,
         block_st_transaction: &mut D,
@@ -769,6 +1107,8 @@ where
 
         let coinbase_id = checked_mint.id();
         let (mut mint, _) = checked_mint.into();
+        let contract_changes: Option<Arc<ParkingMutex<ContractChanges>>> =
+            self.options.state_diff.then(Default::default);
 
         fn verify_mint_for_empty_contract(mint: &Mint) -> ExecutorResult<()> {
             if *mint.mint_amount() != 0 {
@@ -836,7 +1176,10 @@ where
             let mut sub_block_db_commit = block_st_transaction.transaction();
 
             let mut vm_db = VmStorage::new(
-                sub_block_db_commit.as_mut(),
+                RecordingStorage::new(
+                    sub_block_db_commit.as_mut(),
+                    contract_changes.clone(),
+                ),
                 &header.consensus,
                 self.config.coinbase_recipient,
             );
@@ -896,10 +1239,26 @@ where
 
         let tx = mint.into();
 
+        let state_diff = contract_changes
+            .map(|changes| {
+                state_diff::state_diff(
+                    &changes,
+                    block_st_transaction.as_ref(),
+                    &coinbase_id,
+                    TxPointer::new(*header.height(), execution_data.tx_count),
+                    &[],
+                    &[],
+                    false,
+                )
+            })
+            .transpose()?;
         execution_data.tx_status.push(TransactionExecutionStatus {
             id: coinbase_id,
             result: TransactionExecutionResult::Success { result: None },
             receipts: vec![],
+            used_gas: 0,
+            trace: None,
+            state_diff,
         });
 
         if block_st_transaction
@@ -928,17 +1287,26 @@ where
     {
         let tx_id = checked_tx.id();
         let max_fee = checked_tx.metadata().max_fee();
+        let mut predicate_verification_duration = None;
 
         if self.options.utxo_validation {
-            checked_tx = checked_tx
-                .check_predicates(&CheckPredicateParams::from(
-                    &self.config.consensus_parameters,
-                ))
-                .map_err(|_| {
-                    ExecutorError::TransactionValidity(
-                        TransactionValidityError::InvalidPredicate(tx_id),
-                    )
-                })?;
+            let has_predicates = checked_tx
+                .transaction()
+                .inputs()
+                .iter()
+                .any(|input| input.predicate().is_some());
+            let verification_start = Instant::now();
+            let verified = checked_tx.check_predicates(&CheckPredicateParams::from(
+                &self.config.consensus_parameters,
+            ));
+            if has_predicates {
+                predicate_verification_duration = Some(verification_start.elapsed());
+            }
+            checked_tx = verified.map_err(|_| {
+                ExecutorError::TransactionValidity(
+                    TransactionValidityError::InvalidPredicate(tx_id),
+                )
+            })?;
             debug_assert!(checked_tx.checks().contains(Checks::Predicates));
 
             // validate utxos exist and maturity is properly set
@@ -955,14 +1323,39 @@ where
             debug_assert!(checked_tx.checks().contains(Checks::Signatures));
         }
 
+        let trace = if self.options.trace {
+            // the traced execution is discarded, the real one follows below
+            let mut trace_db_commit = tx_st_transaction.transaction();
+            let vm_db = VmStorage::new(
+                trace_db_commit.as_mut().clone(),
+                &header.consensus,
+                self.config.coinbase_recipient,
+            );
+            let mut vm = Interpreter::with_storage(
+                vm_db,
+                InterpreterParams::from(&self.config.consensus_parameters),
+            );
+            let trace = tracer::trace(
+                &mut vm,
+                checked_tx.clone(),
+                tx_id,
+                self.config.max_trace_steps,
+            )?;
+            Some(trace)
+        } else {
+            None
+        };
+
         // execute transaction
         // setup database view that only lives for the duration of vm execution
         let mut sub_block_db_commit = tx_st_transaction.transaction();
         let sub_db_view = sub_block_db_commit.as_mut();
+        let contract_changes: Option<Arc<ParkingMutex<ContractChanges>>> =
+            self.options.state_diff.then(Default::default);
 
         // execution vm
         let vm_db = VmStorage::new(
-            sub_db_view.clone(),
+            RecordingStorage::new(sub_db_view.clone(), contract_changes.clone()),
             &header.consensus,
             self.config.coinbase_recipient,
         );
@@ -1056,6 +1449,20 @@ where
         )?;
         *tx.outputs_mut() = outputs;
 
+        let state_diff = contract_changes
+            .map(|changes| {
+                state_diff::state_diff(
+                    &changes,
+                    tx_st_transaction.as_ref(),
+                    &tx_id,
+                    TxPointer::new(*header.height(), execution_data.tx_count),
+                    tx.inputs(),
+                    tx.outputs(),
+                    reverted,
+                )
+            })
+            .transpose()?;
+
         let final_tx = tx.into();
 
         // Store tx into the block db transaction
@@ -1064,6 +1471,7 @@ where
             .storage::<ProcessedTransactions>()
             .insert(&tx_id, &())?;
 
+        let mut revert = None;
         let status = if reverted {
             self.log_backtrace(&vm, &receipts);
             // get reason for revert
@@ -1077,6 +1485,7 @@ where
                     _ => None,
                 })
                 .unwrap_or_else(|| format!("{:?}", &state));
+            revert = Some(revert_label(&receipts));
 
             TransactionExecutionResult::Failed {
                 reason,
@@ -1095,6 +1504,11 @@ where
             .checked_add(tx_fee)
             .ok_or(ExecutorError::FeeOverflow)?;
         execution_data.used_gas = execution_data.used_gas.saturating_add(used_gas);
+        execution_data.tx_metrics.push(TransactionMetrics {
+            used_gas,
+            predicate_verification_duration,
+            revert,
+        });
         execution_data
             .message_ids
             .extend(receipts.iter().filter_map(|r| r.message_id()));
@@ -1103,6 +1517,9 @@ where
             id: tx_id,
             result: status,
             receipts,
+            used_gas,
+            trace,
+            state_diff,
         });
 
         Ok(final_tx)
@@ -1494,7 +1911,7 @@ where
     /// Log a VM backtrace if configured to do so
     fn log_backtrace<Tx>(
         &self,
-        vm: &Interpreter<VmStorage<D>, Tx>,
+        vm: &Interpreter<VmStorage<RecordingStorage<D>>, Tx>,
         receipts: &[Receipt],
     ) {
         if self.config.backtrace {
@@ -1637,6 +2054,35 @@ where
     }
 }
 
+fn record_metrics(tx_metrics: &[TransactionMetrics]) {
+    let metrics = executor_metrics();
+    for tx in tx_metrics {
+        metrics.tx_gas_used.observe(tx.used_gas as f64);
+        if let Some(duration) = tx.predicate_verification_duration {
+            metrics
+                .predicate_verification_duration
+                .observe(duration.as_secs_f64());
+        }
+        if let Some(revert) = &tx.revert {
+            metrics.record_revert(revert);
+        }
+    }
+}
+
+/// The reason of the revert used as the label of the metric. Unlike the reason of the
+/// `TransactionExecutionResult::Failed`, it doesn't include the revert code to keep the
+/// number of labels bounded.
+fn revert_label(receipts: &[Receipt]) -> String {
+    receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::Revert { .. } => Some("Revert".to_string()),
+            Receipt::Panic { reason, .. } => Some(format!("{}", reason.reason())),
+            _ => None,
+        })
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
 trait Fee {
     fn max_fee(&self) -> Word;
 
@@ -4,6 +4,9 @@
 #![deny(warnings)]
 
 mod config;
+mod parallel;
+mod state_diff;
+mod tracer;
 
 pub mod executor;
 pub mod ports;
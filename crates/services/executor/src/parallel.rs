@@ -0,0 +1,123 @@
+//! The scheduling of transactions for the parallel execution.
+//!
+//! Each transaction declares all coins, messages and contracts it touches in
+//! its inputs and outputs. Two transactions conflict if they touch the same
+//! resource, or if one of them spends an output of the other. Conflicting
+//! transactions must be executed in the order of the block, while others
+//! can be executed concurrently.
+
+use crate::ports::MaybeCheckedTransaction;
+use fuel_core_types::{
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        Input,
+        Output,
+        Transaction,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        ContractId,
+        Nonce,
+    },
+    fuel_vm::checked_transaction::CheckedTransaction,
+};
+use std::collections::HashMap;
+
+/// The resource of the state that can be touched by the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Resource {
+    /// The transaction itself and all outputs created by it.
+    Transaction(TxId),
+    Coin(UtxoId),
+    Message(Nonce),
+    Contract(ContractId),
+}
+
+impl MaybeCheckedTransaction {
+    /// Returns the inputs and outputs of the transaction.
+    /// Returns `None` for `Mint` transactions.
+    fn inputs_and_outputs(&self) -> Option<(&[Input], &[Output])> {
+        match self {
+            MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Script(
+                tx,
+            )) => Some((tx.transaction().inputs(), tx.transaction().outputs())),
+            MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Create(
+                tx,
+            )) => Some((tx.transaction().inputs(), tx.transaction().outputs())),
+            MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Mint(_)) => {
+                None
+            }
+            MaybeCheckedTransaction::Transaction(Transaction::Script(tx)) => {
+                Some((tx.inputs(), tx.outputs()))
+            }
+            MaybeCheckedTransaction::Transaction(Transaction::Create(tx)) => {
+                Some((tx.inputs(), tx.outputs()))
+            }
+            MaybeCheckedTransaction::Transaction(_) => None,
+        }
+    }
+}
+
+fn resources(tx_id: &TxId, inputs: &[Input], outputs: &[Output]) -> Vec<Resource> {
+    let mut resources = vec![Resource::Transaction(*tx_id)];
+    for input in inputs {
+        if let Some(contract_id) = input.contract_id() {
+            resources.push(Resource::Contract(*contract_id));
+        } else if let Some(utxo_id) = input.utxo_id() {
+            resources.push(Resource::Coin(*utxo_id));
+            // The coin may be created by the previous transaction of the block.
+            resources.push(Resource::Transaction(*utxo_id.tx_id()));
+        } else if let Some(nonce) = input.nonce() {
+            resources.push(Resource::Message(*nonce));
+        }
+    }
+    for output in outputs {
+        if let Some(contract_id) = output.contract_id() {
+            resources.push(Resource::Contract(*contract_id));
+        }
+    }
+    resources
+}
+
+/// Splits the transactions into layers. Transactions inside of one layer don't
+/// conflict with each other, and each transaction is placed into the layer after
+/// the last layer with a conflicting transaction that goes before it in the block.
+///
+/// Returns the indexes of the transactions for each layer in ascending order.
+/// The `Mint` transaction depends on the result of all previous transactions,
+/// so only transactions before the first `Mint` are split into layers.
+pub(crate) fn layers(
+    transactions: &[(TxId, MaybeCheckedTransaction)],
+) -> Vec<Vec<usize>> {
+    let mut last_layer_of_resource = HashMap::<Resource, usize>::new();
+    let mut layers: Vec<Vec<usize>> = vec![];
+
+    for (index, (tx_id, tx)) in transactions.iter().enumerate() {
+        let Some((inputs, outputs)) = tx.inputs_and_outputs() else {
+            break
+        };
+        let resources = resources(tx_id, inputs, outputs);
+
+        let layer = resources
+            .iter()
+            .filter_map(|resource| last_layer_of_resource.get(resource))
+            .map(|layer| layer.saturating_add(1))
+            .max()
+            .unwrap_or_default();
+
+        for resource in resources {
+            last_layer_of_resource.insert(resource, layer);
+        }
+
+        if layers.len() <= layer {
+            layers.resize_with(layer.saturating_add(1), Vec::new);
+        }
+        layers[layer].push(index);
+    }
+
+    layers
+}
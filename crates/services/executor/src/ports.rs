@@ -33,6 +33,7 @@ use fuel_core_types::{
 };
 
 /// The wrapper around either `Transaction` or `CheckedTransaction`.
+#[derive(Clone)]
 pub enum MaybeCheckedTransaction {
     CheckedTransaction(CheckedTransaction),
     Transaction(fuel_tx::Transaction),
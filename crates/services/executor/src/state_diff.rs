@@ -0,0 +1,373 @@
+//! The recording of the state changes made by the execution of the transaction.
+//!
+//! The VM accesses the storage of contracts through [`RecordingStorage`], which remembers
+//! the value of each changed storage slot and balance before the first change. After
+//! the execution, the recorded values are compared with the actual ones, so the changes
+//! of the reverted transactions are not a part of the [`StateDiff`].
+
+use fuel_core_storage::{
+    tables::{
+        ContractsAssets,
+        ContractsInfo,
+        ContractsRawCode,
+        ContractsState,
+    },
+    ContractsAssetKey,
+    ContractsStateKey,
+    Error as StorageError,
+    Mappable,
+    MerkleRoot,
+    MerkleRootStorage,
+    Result as StorageResult,
+    StorageBatchMutate,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+};
+use fuel_core_types::{
+    entities::coins::coin::Coin,
+    fuel_tx::{
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
+        },
+        Input,
+        Output,
+        TxId,
+        TxPointer,
+        UtxoId,
+    },
+    fuel_types::{
+        Bytes32,
+        Word,
+    },
+    services::executor::{
+        BalanceChange,
+        StateDiff,
+        StorageSlotChange,
+    },
+};
+use parking_lot::Mutex;
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    sync::Arc,
+};
+
+/// The values of the contract storage slots and balances before the first change.
+#[derive(Debug, Default)]
+pub(crate) struct ContractChanges {
+    slots: BTreeMap<ContractsStateKey, Option<Bytes32>>,
+    balances: BTreeMap<ContractsAssetKey, Option<Word>>,
+}
+
+/// The wrapper around the storage that records the changes of the contracts storage and balances.
+/// Without the `changes` it only forwards the calls to the underlying storage.
+#[derive(Debug, Clone)]
+pub(crate) struct RecordingStorage<D> {
+    database: D,
+    changes: Option<Arc<Mutex<ContractChanges>>>,
+}
+
+impl<D> RecordingStorage<D> {
+    pub(crate) fn new(database: D, changes: Option<Arc<Mutex<ContractChanges>>>) -> Self {
+        Self { database, changes }
+    }
+
+    fn record_slot(&self, key: &ContractsStateKey, previous: Option<Bytes32>) {
+        if let Some(changes) = &self.changes {
+            changes.lock().slots.entry(*key).or_insert(previous);
+        }
+    }
+
+    fn record_balance(&self, key: &ContractsAssetKey, previous: Option<Word>) {
+        if let Some(changes) = &self.changes {
+            changes.lock().balances.entry(*key).or_insert(previous);
+        }
+    }
+}
+
+impl<D> RecordingStorage<D>
+where
+    D: StorageInspect<ContractsState, Error = StorageError>,
+{
+    /// Records the slot with its current value, used when the storage doesn't return the previous value.
+    fn record_slot_before_change(&self, key: &ContractsStateKey) -> StorageResult<()> {
+        if let Some(changes) = &self.changes {
+            if !changes.lock().slots.contains_key(key) {
+                let previous =
+                    StorageInspect::<ContractsState>::get(&self.database, key)?
+                        .map(Cow::into_owned);
+                self.record_slot(key, previous);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<D, M: Mappable> StorageInspect<M> for RecordingStorage<D>
+where
+    D: StorageInspect<M, Error = StorageError>,
+{
+    type Error = StorageError;
+
+    fn get(&self, key: &M::Key) -> Result<Option<Cow<M::OwnedValue>>, Self::Error> {
+        StorageInspect::<M>::get(&self.database, key)
+    }
+
+    fn contains_key(&self, key: &M::Key) -> Result<bool, Self::Error> {
+        StorageInspect::<M>::contains_key(&self.database, key)
+    }
+}
+
+/// Implements `StorageMutate` for tables without recording of the changes.
+macro_rules! forward_storage_mutate {
+    ($($table:ty),*) => {
+        $(
+            impl<D> StorageMutate<$table> for RecordingStorage<D>
+            where
+                D: StorageMutate<$table, Error = StorageError>,
+            {
+                fn insert(
+                    &mut self,
+                    key: &<$table as Mappable>::Key,
+                    value: &<$table as Mappable>::Value,
+                ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                    StorageMutate::<$table>::insert(&mut self.database, key, value)
+                }
+
+                fn remove(
+                    &mut self,
+                    key: &<$table as Mappable>::Key,
+                ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                    StorageMutate::<$table>::remove(&mut self.database, key)
+                }
+            }
+        )*
+    };
+}
+
+forward_storage_mutate!(ContractsInfo, ContractsRawCode);
+
+impl<D> StorageMutate<ContractsState> for RecordingStorage<D>
+where
+    D: StorageMutate<ContractsState, Error = StorageError>,
+{
+    fn insert(
+        &mut self,
+        key: &ContractsStateKey,
+        value: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::Error> {
+        let previous =
+            StorageMutate::<ContractsState>::insert(&mut self.database, key, value)?;
+        self.record_slot(key, previous);
+        Ok(previous)
+    }
+
+    fn remove(
+        &mut self,
+        key: &ContractsStateKey,
+    ) -> Result<Option<Bytes32>, Self::Error> {
+        let previous = StorageMutate::<ContractsState>::remove(&mut self.database, key)?;
+        self.record_slot(key, previous);
+        Ok(previous)
+    }
+}
+
+impl<D> StorageMutate<ContractsAssets> for RecordingStorage<D>
+where
+    D: StorageMutate<ContractsAssets, Error = StorageError>,
+{
+    fn insert(
+        &mut self,
+        key: &ContractsAssetKey,
+        value: &Word,
+    ) -> Result<Option<Word>, Self::Error> {
+        let previous =
+            StorageMutate::<ContractsAssets>::insert(&mut self.database, key, value)?;
+        self.record_balance(key, previous);
+        Ok(previous)
+    }
+
+    fn remove(&mut self, key: &ContractsAssetKey) -> Result<Option<Word>, Self::Error> {
+        let previous = StorageMutate::<ContractsAssets>::remove(&mut self.database, key)?;
+        self.record_balance(key, previous);
+        Ok(previous)
+    }
+}
+
+impl<D> StorageBatchMutate<ContractsState> for RecordingStorage<D>
+where
+    D: StorageBatchMutate<ContractsState, Error = StorageError>,
+{
+    fn init_storage<'a, Iter>(&mut self, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = (&'a ContractsStateKey, &'a Bytes32)>,
+    {
+        // The storage of the new contract doesn't have any values before
+        let set: Vec<_> = set.collect();
+        for (key, _) in set.iter() {
+            self.record_slot(key, None);
+        }
+        self.database.init_storage(set.into_iter())
+    }
+
+    fn insert_batch<'a, Iter>(&mut self, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = (&'a ContractsStateKey, &'a Bytes32)>,
+    {
+        let set: Vec<_> = set.collect();
+        for (key, _) in set.iter() {
+            self.record_slot_before_change(key)?;
+        }
+        self.database.insert_batch(set.into_iter())
+    }
+
+    fn remove_batch<'a, Iter>(&mut self, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = &'a ContractsStateKey>,
+    {
+        let set: Vec<_> = set.collect();
+        for key in set.iter() {
+            self.record_slot_before_change(key)?;
+        }
+        self.database.remove_batch(set.into_iter())
+    }
+}
+
+impl<D, M: Mappable> StorageSize<M> for RecordingStorage<D>
+where
+    D: StorageSize<M, Error = StorageError>,
+{
+    fn size_of_value(&self, key: &M::Key) -> Result<Option<usize>, Self::Error> {
+        StorageSize::<M>::size_of_value(&self.database, key)
+    }
+}
+
+impl<D, M: Mappable> StorageRead<M> for RecordingStorage<D>
+where
+    D: StorageRead<M, Error = StorageError>,
+{
+    fn read(&self, key: &M::Key, buf: &mut [u8]) -> Result<Option<usize>, Self::Error> {
+        StorageRead::<M>::read(&self.database, key, buf)
+    }
+
+    fn read_alloc(&self, key: &M::Key) -> Result<Option<Vec<u8>>, Self::Error> {
+        StorageRead::<M>::read_alloc(&self.database, key)
+    }
+}
+
+impl<D, K, M: Mappable> MerkleRootStorage<K, M> for RecordingStorage<D>
+where
+    D: MerkleRootStorage<K, M, Error = StorageError>,
+    Self: StorageMutate<M, Error = StorageError>,
+{
+    fn root(&self, key: &K) -> Result<MerkleRoot, Self::Error> {
+        MerkleRootStorage::<K, M>::root(&self.database, key)
+    }
+}
+
+/// Returns the changes of the state made by the transaction with `inputs` and `outputs`.
+/// The `database` should contain the state after the execution of the transaction.
+pub(crate) fn state_diff<D>(
+    contract_changes: &Mutex<ContractChanges>,
+    database: &D,
+    tx_id: &TxId,
+    tx_pointer: TxPointer,
+    inputs: &[Input],
+    outputs: &[Output],
+    reverted: bool,
+) -> StorageResult<StateDiff>
+where
+    D: StorageInspect<ContractsState, Error = StorageError>
+        + StorageInspect<ContractsAssets, Error = StorageError>,
+{
+    let mut diff = StateDiff::default();
+
+    for input in inputs {
+        match input {
+            Input::CoinSigned(CoinSigned { utxo_id, .. })
+            | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                diff.coins_spent.push(*utxo_id);
+            }
+            // The retryable messages are not spent if the transaction is reverted
+            Input::MessageDataSigned(_) | Input::MessageDataPredicate(_) if reverted => {}
+            Input::MessageCoinSigned(MessageCoinSigned { nonce, .. })
+            | Input::MessageCoinPredicate(MessageCoinPredicate { nonce, .. })
+            | Input::MessageDataSigned(MessageDataSigned { nonce, .. })
+            | Input::MessageDataPredicate(MessageDataPredicate { nonce, .. }) => {
+                diff.messages_consumed.push(*nonce);
+            }
+            _ => {}
+        }
+    }
+
+    for (output_index, output) in outputs.iter().enumerate() {
+        let index = u8::try_from(output_index)
+            .expect("Transaction can have only up to `u8::MAX` outputs");
+        match output {
+            Output::Coin {
+                to,
+                amount,
+                asset_id,
+            }
+            | Output::Change {
+                to,
+                amount,
+                asset_id,
+            }
+            | Output::Variable {
+                to,
+                amount,
+                asset_id,
+            } if *amount > Word::MIN => diff.coins_created.push(Coin {
+                utxo_id: UtxoId::new(*tx_id, index),
+                owner: *to,
+                amount: *amount,
+                asset_id: *asset_id,
+                maturity: 0u32.into(),
+                tx_pointer,
+            }),
+            _ => {}
+        }
+    }
+
+    let contract_changes = contract_changes.lock();
+    for (key, previous) in contract_changes.slots.iter() {
+        let current =
+            StorageInspect::<ContractsState>::get(database, key)?.map(Cow::into_owned);
+        if *previous != current {
+            diff.storage_changes.push(StorageSlotChange {
+                contract_id: *key.contract_id(),
+                key: *key.state_key(),
+                previous: *previous,
+                current,
+            });
+        }
+    }
+    for (key, previous) in contract_changes.balances.iter() {
+        let previous = previous.unwrap_or_default();
+        let current = StorageInspect::<ContractsAssets>::get(database, key)?
+            .map(Cow::into_owned)
+            .unwrap_or_default();
+        if previous != current {
+            diff.balance_changes.push(BalanceChange {
+                contract_id: *key.contract_id(),
+                asset_id: *key.asset_id(),
+                previous,
+                current,
+            });
+        }
+    }
+
+    Ok(diff)
+}
@@ -0,0 +1,237 @@
+//! The per-instruction tracing of the script execution.
+//!
+//! The tracer drives the interpreter instruction by instruction, repeating the
+//! loop of the VM's `run_program`, and records the state observable between
+//! instructions. The traced execution is a separate run of the transaction, so
+//! the caller is responsible for discarding its changes to the storage.
+//!
+//! Each step keeps the changed registers and the call frames, so the number of
+//! steps is limited to bound the memory used by the trace.
+
+use fuel_core_storage::Error as StorageError;
+use fuel_core_types::{
+    fuel_asm::{
+        Instruction,
+        RawInstruction,
+        RegId,
+        Word,
+    },
+    fuel_tx::field::Script as ScriptField,
+    fuel_types::{
+        canonical::Deserialize,
+        Bytes32,
+    },
+    fuel_vm::{
+        checked_transaction::{
+            Checked,
+            IntoChecked,
+        },
+        consts::VM_REGISTER_COUNT,
+        interpreter::{
+            CheckedMetadata,
+            ExecutableTransaction,
+        },
+        state::ExecuteState,
+        CallFrame,
+        Interpreter,
+        InterpreterError,
+        InterpreterStorage,
+    },
+    services::executor::{
+        Error as ExecutorError,
+        Result as ExecutorResult,
+        StorageAccess,
+        StorageAccessKind,
+        TraceCallFrame,
+        TraceStep,
+        TransactionTrace,
+    },
+};
+
+/// Executes the script of the transaction and returns the trace of its execution.
+/// Returns an empty trace for transactions without a script, and an error if the
+/// script executes more than `max_steps` instructions.
+pub(crate) fn trace<S, Tx>(
+    vm: &mut Interpreter<S, Tx>,
+    checked_tx: Checked<Tx>,
+    tx_id: Bytes32,
+    max_steps: usize,
+) -> ExecutorResult<TransactionTrace>
+where
+    S: InterpreterStorage<DataError = StorageError>,
+    Tx: ExecutableTransaction,
+    <Tx as IntoChecked>::Metadata: CheckedMetadata,
+{
+    let script_offset = match checked_tx.transaction().as_script() {
+        Some(script) if !script.script().is_empty() => script.script_offset(),
+        _ => return Ok(TransactionTrace::default()),
+    };
+
+    vm.init_script(checked_tx)
+        .map_err(|error| vm_execution_error(error, tx_id))?;
+    let offset = vm.tx_offset().saturating_add(script_offset) as Word;
+    vm.registers_mut()[RegId::PC] = offset;
+    vm.registers_mut()[RegId::IS] = offset;
+
+    let mut steps = vec![];
+    loop {
+        if steps.len() >= max_steps {
+            return Err(ExecutorError::TraceTruncated {
+                transaction_id: tx_id,
+                max_steps,
+            })
+        }
+
+        let mut registers = [0; VM_REGISTER_COUNT];
+        registers.copy_from_slice(vm.registers());
+        let pc = registers[RegId::PC];
+        let Some(instruction) = read_instruction(vm.memory(), pc) else {
+            // The VM panics with `MemoryOverflow` without executing anything.
+            break
+        };
+        let call_frames = call_frames(vm.memory(), &registers);
+        let storage_access =
+            storage_access(vm.memory(), &registers, &call_frames, instruction);
+
+        let result = vm.execute();
+
+        let gas_used = registers[RegId::GGAS].saturating_sub(vm.registers()[RegId::GGAS]);
+        let changed_registers = (0u8..)
+            .zip(registers.iter().zip(vm.registers()))
+            .filter(|(_, (before, after))| before != after)
+            .map(|(register, (_, after))| (register, *after))
+            .collect();
+        let panic_reason = result.as_ref().err().and_then(|error| error.panic_reason());
+
+        steps.push(TraceStep {
+            pc,
+            instruction,
+            gas_used,
+            changed_registers,
+            call_frames: call_frames
+                .into_iter()
+                .map(|frame| TraceCallFrame {
+                    contract_id: *frame.to(),
+                    asset_id: *frame.asset_id(),
+                    code_size: frame.code_size() as Word,
+                    param1: frame.a(),
+                    param2: frame.b(),
+                })
+                .collect(),
+            storage_access,
+            panic_reason,
+        });
+
+        let in_call = registers[RegId::FP] != 0;
+        match result {
+            // The panic terminates the execution.
+            Err(_) => break,
+            Ok(ExecuteState::Revert(_)) => break,
+            Ok(ExecuteState::Return(_) | ExecuteState::ReturnData(_)) if !in_call => {
+                break
+            }
+            Ok(_) => {}
+        }
+    }
+
+    Ok(TransactionTrace { steps })
+}
+
+/// Converts the error of the VM into the executor error. The storage errors are
+/// reported as the errors of the storage, not as the errors of the VM.
+fn vm_execution_error(
+    error: InterpreterError<StorageError>,
+    transaction_id: Bytes32,
+) -> ExecutorError {
+    let error = match error {
+        InterpreterError::Storage(error) => return error.into(),
+        InterpreterError::PanicInstruction(result) => {
+            InterpreterError::PanicInstruction(result)
+        }
+        InterpreterError::Panic(reason) => InterpreterError::Panic(reason),
+        InterpreterError::TransactionValidity(error) => {
+            InterpreterError::TransactionValidity(error)
+        }
+        InterpreterError::NoTransactionInitialized => {
+            InterpreterError::NoTransactionInitialized
+        }
+        InterpreterError::DebugStateNotInitialized => {
+            InterpreterError::DebugStateNotInitialized
+        }
+        InterpreterError::Bug(bug) => InterpreterError::Bug(bug),
+    };
+    ExecutorError::VmExecution {
+        error,
+        transaction_id,
+    }
+}
+
+fn read_instruction(memory: &[u8], pc: Word) -> Option<RawInstruction> {
+    let start = usize::try_from(pc).ok()?;
+    let bytes = memory.get(start..start.checked_add(Instruction::SIZE)?)?;
+    Some(RawInstruction::from_be_bytes(bytes.try_into().ok()?))
+}
+
+fn read_bytes32(memory: &[u8], pointer: Word) -> Option<Bytes32> {
+    let start = usize::try_from(pointer).ok()?;
+    let bytes = memory.get(start..start.checked_add(Bytes32::LEN)?)?;
+    Some(Bytes32::new(bytes.try_into().ok()?))
+}
+
+/// Reads the call frames from the stack, starting from the outermost one.
+/// Each frame stores the registers of the caller, including its frame pointer.
+fn call_frames(memory: &[u8], registers: &[Word]) -> Vec<CallFrame> {
+    let mut frames = vec![];
+    let mut fp = registers[RegId::FP];
+    while fp != 0 {
+        let frame = usize::try_from(fp)
+            .ok()
+            .and_then(|start| {
+                memory.get(start..start.checked_add(CallFrame::serialized_size())?)
+            })
+            .and_then(|bytes| CallFrame::from_bytes(bytes).ok());
+        let Some(frame) = frame else { break };
+        fp = frame.registers()[RegId::FP];
+        frames.push(frame);
+    }
+    frames.reverse();
+    frames
+}
+
+fn storage_access(
+    memory: &[u8],
+    registers: &[Word],
+    call_frames: &[CallFrame],
+    instruction: RawInstruction,
+) -> Option<StorageAccess> {
+    let (kind, key, slots) = match Instruction::try_from(instruction).ok()? {
+        Instruction::SRW(op) => {
+            let (_, _, key) = op.unpack();
+            (StorageAccessKind::Read, key, 1)
+        }
+        Instruction::SRWQ(op) => {
+            let (_, _, key, slots) = op.unpack();
+            (StorageAccessKind::Read, key, registers[slots])
+        }
+        Instruction::SWW(op) => {
+            let (key, _, _) = op.unpack();
+            (StorageAccessKind::Write, key, 1)
+        }
+        Instruction::SWWQ(op) => {
+            let (key, _, _, slots) = op.unpack();
+            (StorageAccessKind::Write, key, registers[slots])
+        }
+        Instruction::SCWQ(op) => {
+            let (key, _, slots) = op.unpack();
+            (StorageAccessKind::Clear, key, registers[slots])
+        }
+        _ => return None,
+    };
+
+    Some(StorageAccess {
+        contract_id: *call_frames.last()?.to(),
+        key: read_bytes32(memory, registers[key])?,
+        slots,
+        kind,
+    })
+}
@@ -21,17 +21,17 @@ use fuel_core_types::{
         primitives::DaBlockHeight,
     },
     fuel_asm::Word,
-    fuel_tx::{
-        Receipt,
-        Transaction,
-    },
+    fuel_tx::Transaction,
     fuel_types::{
         BlockHeight,
         Bytes32,
     },
     services::{
         block_producer::Components,
-        executor::UncommittedResult,
+        executor::{
+            TransactionExecutionStatus,
+            UncommittedResult,
+        },
     },
     tai64::Tai64,
 };
@@ -82,6 +82,7 @@ where
     ViewProvider::View: BlockProducerDatabase,
 {
     /// Produces and execute block for the specified height.
+    #[tracing::instrument(skip_all, fields(height = %height))]
     async fn produce_and_execute<TxSource, ExecutorDB>(
         &self,
         height: BlockHeight,
@@ -178,47 +179,68 @@ where
     Executor: ports::DryRunner + 'static,
 {
     // TODO: Support custom `block_time` for `dry_run`.
-    /// Simulate a transaction without altering any state. Does not aquire the production lock
+    /// Simulate transactions without altering any state. Does not aquire the production lock
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
-    /// production.
+    /// production. Transactions are executed in order, so each of them can use the outputs
+    /// of the previous ones.
+    ///
+    /// If the `height` belongs to the already produced block, transactions are executed
+    /// as if they were included into that block: with the same timestamp and DA height, on top
+    /// of the state of the previous block.
     pub async fn dry_run(
         &self,
-        transaction: Transaction,
+        transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
-    ) -> anyhow::Result<Vec<Receipt>> {
+        trace: bool,
+        state_diff: bool,
+    ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
+        let latest_height = self.view_provider.latest_height();
         let height = height.unwrap_or_else(|| {
-            self.view_provider
-                .latest_height()
+            latest_height
                 .succ()
                 .expect("It is impossible to overflow the current block height")
         });
 
-        let is_script = transaction.is_script();
-        // The dry run execution should use the state of the blockchain based on the
-        // last available block, not on the upcoming one. It means that we need to
-        // use the same configuration as the last block -> the same DA height.
-        // It is deterministic from the result perspective, plus it is more performant
-        // because we don't need to wait for the relayer to sync.
-        let header = self._new_header(height, Tai64::now())?;
+        let is_script: Vec<_> = transactions.iter().map(Transaction::is_script).collect();
+        // The transactions of the existing block are executed on top of the state of
+        // the previous block. The transactions of the next block are executed on top of
+        // the latest state, even if a new block is imported in the meantime.
+        let (header, state_height) = if height <= latest_height {
+            (self.historical_header(height)?, height.pred())
+        } else {
+            // The dry run execution should use the state of the blockchain based on the
+            // last available block, not on the upcoming one. It means that we need to
+            // use the same configuration as the last block -> the same DA height.
+            // It is deterministic from the result perspective, plus it is more performant
+            // because we don't need to wait for the relayer to sync.
+            (self._new_header(height, Tai64::now())?, None)
+        };
         let component = Components {
             header_to_produce: header,
-            transactions_source: transaction,
+            transactions_source: transactions,
             gas_limit: u64::MAX,
         };
 
         let executor = self.executor.clone();
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
-        let res: Vec<_> =
-            tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<Receipt>> {
-                Ok(executor
-                    .dry_run(component, utxo_validation)?
-                    .into_iter()
-                    .flatten()
-                    .collect())
-            })
-            .await?;
-        if is_script && res.is_empty() {
+        let res: Vec<_> = tokio_rayon::spawn_fifo(
+            move || -> anyhow::Result<Vec<TransactionExecutionStatus>> {
+                Ok(executor.dry_run(
+                    component,
+                    state_height,
+                    utxo_validation,
+                    trace,
+                    state_diff,
+                )?)
+            },
+        )
+        .await?;
+        if res
+            .iter()
+            .zip(is_script)
+            .any(|(status, is_script)| is_script && status.receipts.is_empty())
+        {
             return Err(anyhow!("Expected at least one set of receipts"))
         }
         Ok(res)
@@ -282,6 +304,32 @@ where
         })
     }
 
+    /// Returns the header of the already produced block at the `height`.
+    fn historical_header(
+        &self,
+        height: BlockHeight,
+    ) -> anyhow::Result<PartialBlockHeader> {
+        if height == 0u32.into() {
+            return Err(Error::GenesisBlock.into())
+        }
+        let view = self.view_provider.latest_view();
+        let block = view.get_block(&height)?;
+        let header = block.header();
+
+        Ok(PartialBlockHeader {
+            application: ApplicationHeader {
+                da_height: header.da_height,
+                generated: Default::default(),
+            },
+            consensus: ConsensusHeader {
+                prev_root: *header.prev_root(),
+                height,
+                time: header.time(),
+                generated: Default::default(),
+            },
+        })
+    }
+
     fn previous_block_info(
         &self,
         height: BlockHeight,
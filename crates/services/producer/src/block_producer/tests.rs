@@ -3,6 +3,7 @@ use crate::{
     mocks::{
         FailingMockExecutor,
         MockDb,
+        MockDryRunner,
         MockExecutor,
         MockRelayer,
         MockTxPool,
@@ -23,6 +24,7 @@ use fuel_core_types::{
             PartialBlockHeader,
         },
     },
+    fuel_tx::Transaction,
     services::executor::Error as ExecutorError,
     tai64::Tai64,
 };
@@ -200,6 +202,139 @@ async fn production_fails_on_execution_error() {
     );
 }
 
+fn historical_db(header: PartialBlockHeader) -> MockDb {
+    let height = header.consensus.height;
+    let block = PartialFuelBlock {
+        header,
+        transactions: vec![],
+    }
+    .generate(&[])
+    .compress(&Default::default());
+
+    MockDb {
+        blocks: Arc::new(Mutex::new(
+            vec![(0u32.into(), CompressedBlock::default()), (height, block)]
+                .into_iter()
+                .collect(),
+        )),
+    }
+}
+
+#[tokio::test]
+async fn dry_run_at_height_of_existing_block_uses_its_header() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(0u64);
+    let header = PartialBlockHeader {
+        application: ApplicationHeader {
+            da_height: 13u64.into(),
+            ..Default::default()
+        },
+        consensus: ConsensusHeader {
+            height: 1u32.into(),
+            prev_root: rng.gen(),
+            time: Tai64(1234),
+            ..Default::default()
+        },
+    };
+    let ctx = TestContext::default_from_db_and_executor(
+        historical_db(header.clone()),
+        MockDryRunner::default(),
+    );
+    let executor = ctx.executor.clone();
+    let producer = ctx.producer();
+
+    // When
+    producer
+        .dry_run(
+            vec![Transaction::default_test_tx()],
+            Some(1u32.into()),
+            None,
+            false,
+            false,
+        )
+        .await
+        .expect("Dry run should succeed");
+
+    // Then
+    let headers = executor.0.lock().unwrap();
+    assert_eq!(headers.len(), 1);
+    let used_header = &headers[0];
+    assert_eq!(used_header.consensus.height, header.consensus.height);
+    assert_eq!(used_header.consensus.time, header.consensus.time);
+    assert_eq!(used_header.consensus.prev_root, header.consensus.prev_root);
+    assert_eq!(
+        used_header.application.da_height,
+        header.application.da_height
+    );
+}
+
+#[tokio::test]
+async fn dry_run_without_height_uses_next_height() {
+    // Given
+    let header = PartialBlockHeader {
+        application: ApplicationHeader {
+            da_height: 13u64.into(),
+            ..Default::default()
+        },
+        consensus: ConsensusHeader {
+            height: 1u32.into(),
+            time: Tai64(1234),
+            ..Default::default()
+        },
+    };
+    let ctx = TestContext::default_from_db_and_executor(
+        historical_db(header.clone()),
+        MockDryRunner::default(),
+    );
+    let executor = ctx.executor.clone();
+    let producer = ctx.producer();
+
+    // When
+    producer
+        .dry_run(
+            vec![Transaction::default_test_tx()],
+            None,
+            None,
+            false,
+            false,
+        )
+        .await
+        .expect("Dry run should succeed");
+
+    // Then
+    let headers = executor.0.lock().unwrap();
+    assert_eq!(headers.len(), 1);
+    let used_header = &headers[0];
+    assert_eq!(used_header.consensus.height, 2u32.into());
+    assert_ne!(used_header.consensus.time, header.consensus.time);
+    assert_eq!(
+        used_header.application.da_height,
+        header.application.da_height
+    );
+}
+
+#[tokio::test]
+async fn cant_dry_run_at_genesis_height() {
+    let ctx = TestContext::default_from_executor(MockDryRunner::default());
+    let producer = ctx.producer();
+
+    let err = producer
+        .dry_run(
+            vec![Transaction::default_test_tx()],
+            Some(0u32.into()),
+            None,
+            false,
+            false,
+        )
+        .await
+        .expect_err("expected failure");
+
+    assert!(
+        matches!(err.downcast_ref::<Error>(), Some(Error::GenesisBlock)),
+        "unexpected err {err:?}"
+    );
+}
+
 struct TestContext<Executor> {
     config: Config,
     db: MockDb,
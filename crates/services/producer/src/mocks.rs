@@ -1,5 +1,6 @@
 use crate::ports::{
     BlockProducerDatabase,
+    DryRunner,
     Executor,
     Relayer,
     TxPool,
@@ -19,8 +20,11 @@ use fuel_core_types::{
             Block,
             CompressedBlock,
         },
+        header::PartialBlockHeader,
         primitives::DaBlockHeight,
     },
+    fuel_tx,
+    fuel_tx::Receipt,
     fuel_types::{
         Address,
         BlockHeight,
@@ -33,6 +37,8 @@ use fuel_core_types::{
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -184,6 +190,31 @@ impl Executor<Vec<ArcPoolTx>> for FailingMockExecutor {
     }
 }
 
+/// Records the headers of dry-run blocks.
+#[derive(Default)]
+pub struct MockDryRunner(pub Mutex<Vec<PartialBlockHeader>>);
+
+impl DryRunner for MockDryRunner {
+    fn dry_run(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        _: Option<BlockHeight>,
+        _: Option<bool>,
+        _: bool,
+        _: bool,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
+        self.0.lock().unwrap().push(block.header_to_produce);
+        Ok(vec![TransactionExecutionStatus {
+            id: Default::default(),
+            result: TransactionExecutionResult::Success { result: None },
+            receipts: vec![Receipt::ret(Default::default(), 1, 0, 0)],
+            used_gas: 0,
+            trace: None,
+            state_diff: None,
+        }])
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct MockDb {
     pub blocks: Arc<Mutex<HashMap<BlockHeight, CompressedBlock>>>,
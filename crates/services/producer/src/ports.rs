@@ -10,7 +10,6 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Bytes32,
-        Receipt,
         Transaction,
     },
     fuel_types::BlockHeight,
@@ -18,6 +17,7 @@ use fuel_core_types::{
         block_producer::Components,
         executor::{
             Result as ExecutorResult,
+            TransactionExecutionStatus,
             UncommittedResult,
         },
     },
@@ -69,11 +69,17 @@ pub trait Executor<TxSource>: Send + Sync {
 
 pub trait DryRunner: Send + Sync {
     /// Executes the block without committing it to the database. During execution collects the
-    /// receipts to return them. The `utxo_validation` field can be used to disable the validation
-    /// of utxos during execution.
+    /// statuses of transactions to return them. The block is executed on top of the state at
+    /// the `state_height`, or on top of the latest state if it is `None`.
+    /// The `utxo_validation` field can be used to disable
+    /// the validation of utxos during execution. The `trace` field enables the collection of
+    /// per-instruction traces, and the `state_diff` field enables the recording of state changes.
     fn dry_run(
         &self,
-        block: Components<Transaction>,
+        block: Components<Vec<Transaction>>,
+        state_height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<Vec<Receipt>>>;
+        trace: bool,
+        state_diff: bool,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>>;
 }
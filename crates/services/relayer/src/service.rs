@@ -65,6 +65,8 @@ type CustomizableService<P, D> = ServiceRunner<NotInitializedTask<P, D>>;
 pub struct SharedState<D> {
     /// Receives signals when the relayer reaches consistency with the DA layer.
     synced: Synced,
+    /// Receives the latest finalized da height observed on the DA node.
+    da_finalized: Synced,
     database: D,
 }
 
@@ -72,6 +74,8 @@ pub struct SharedState<D> {
 pub struct NotInitializedTask<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the latest finalized da height observed on the DA node.
+    da_finalized: NotifySynced,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -84,6 +88,8 @@ pub struct NotInitializedTask<P, D> {
 pub struct Task<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the latest finalized da height observed on the DA node.
+    da_finalized: NotifySynced,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -99,8 +105,10 @@ impl<P, D> NotInitializedTask<P, D> {
     /// Create a new relayer task.
     fn new(eth_node: P, database: D, config: Config) -> Self {
         let (synced, _) = watch::channel(None);
+        let (da_finalized, _) = watch::channel(None);
         Self {
             synced,
+            da_finalized,
             eth_node,
             database,
             config,
@@ -175,9 +183,11 @@ where
 
     fn shared_data(&self) -> Self::SharedData {
         let synced = self.synced.subscribe();
+        let da_finalized = self.da_finalized.subscribe();
 
         SharedState {
             synced,
+            da_finalized,
             database: self.database.clone(),
         }
     }
@@ -190,12 +200,14 @@ where
         let shutdown = watcher.clone();
         let NotInitializedTask {
             synced,
+            da_finalized,
             eth_node,
             database,
             config,
         } = self;
         let mut task = Task {
             synced,
+            da_finalized,
             eth_node,
             database,
             config,
@@ -273,6 +285,18 @@ impl<D> SharedState<D> {
         Ok(())
     }
 
+    /// Returns the da height of the last synchronization with the da layer.
+    /// Returns `None` if the relayer has never been in sync.
+    pub fn synced_da_height(&self) -> Option<DaBlockHeight> {
+        *self.synced.borrow()
+    }
+
+    /// Returns the latest finalized da height observed on the DA node.
+    /// Returns `None` if the relayer has never reached the DA node.
+    pub fn latest_da_finalized_height(&self) -> Option<DaBlockHeight> {
+        *self.da_finalized.borrow()
+    }
+
     /// Get finalized da height that represents last block from da layer that got finalized.
     /// Panics if height is not set as of initialization of the relayer.
     pub fn get_finalized_da_height(&self) -> anyhow::Result<DaBlockHeight>
@@ -301,6 +325,7 @@ where
                     .and_then(|block| block.number)
                     .ok_or(anyhow::anyhow!("Block pending"))?
                     .as_u64();
+                self.da_finalized.send_replace(Some(block_number.into()));
                 Ok(block_number)
             }
         }
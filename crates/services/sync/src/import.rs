@@ -2,6 +2,7 @@
 //! This module contains the import task which is responsible for
 //! importing blocks from the network into the local blockchain.
 
+use fuel_core_metrics::sync_metrics::sync_metrics;
 use fuel_core_services::{
     SharedMutex,
     StateWatcher,
@@ -25,6 +26,7 @@ use futures::{
     FutureExt,
     Stream,
 };
+use prometheus_client::metrics::gauge::Gauge;
 use std::{
     future::Future,
     ops::{
@@ -144,6 +146,26 @@ impl<T> Batch<T> {
 type SealedHeaderBatch = Batch<SealedBlockHeader>;
 type SealedBlockBatch = Batch<SealedBlock>;
 
+/// Counts the items in the download queue while the guard is alive.
+struct QueueGuard {
+    gauge: &'static Gauge,
+    size: i64,
+}
+
+impl QueueGuard {
+    fn new(gauge: &'static Gauge, size: usize) -> Self {
+        let size = i64::try_from(size).unwrap_or(i64::MAX);
+        gauge.inc_by(size);
+        Self { gauge, size }
+    }
+}
+
+impl Drop for QueueGuard {
+    fn drop(&mut self) {
+        self.gauge.dec_by(self.size);
+    }
+}
+
 impl<P, E, C> Import<P, E, C>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
@@ -439,6 +461,7 @@ where
         range.start,
         range.end
     );
+    let _queued = QueueGuard::new(&sync_metrics().header_queue_size, range.len());
     p2p.get_sealed_block_headers(range)
         .await
         .trace_err("Failed to get headers")
@@ -454,6 +477,7 @@ async fn get_transactions<P>(
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
+    let _queued = QueueGuard::new(&sync_metrics().transaction_queue_size, range.len());
     let range = peer_id.clone().bind(range);
     let res = p2p
         .get_transactions(range)
@@ -102,6 +102,19 @@ impl Dependency {
         }
     }
 
+    /// Returns the coins used as inputs by the transactions inside txpool.
+    pub fn spent_coins(&self) -> impl Iterator<Item = &UtxoId> + '_ {
+        self.coins
+            .iter()
+            .filter(|(_, state)| state.is_spend_by.is_some())
+            .map(|(utxo_id, _)| utxo_id)
+    }
+
+    /// Returns the messages used as inputs by the transactions inside txpool.
+    pub fn spent_messages(&self) -> impl Iterator<Item = &Nonce> + '_ {
+        self.messages.keys()
+    }
+
     /// find all dependent Transactions that are inside txpool.
     /// Does not check db. They can be sorted by gasPrice to get order of dependency
     pub(crate) fn find_dependent(
@@ -48,6 +48,10 @@ pub struct TxInfo {
     tx: ArcPoolTx,
     submitted_time: Duration,
     creation_instant: tokio::time::Instant,
+    /// The span that lives while the transaction is in the pool.
+    /// It is a child of the span that inserted the transaction,
+    /// and the block production links to it when it includes the transaction.
+    span: tracing::Span,
 }
 
 #[allow(missing_docs)]
@@ -56,11 +60,13 @@ impl TxInfo {
         let since_epoch = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .expect("Now is bellow of the `UNIX_EPOCH`");
+        let span = tracing::info_span!("txpool_tx", tx_id = %tx.id());
 
         Self {
             tx,
             submitted_time: since_epoch,
             creation_instant: tokio::time::Instant::now(),
+            span,
         }
     }
 
@@ -75,6 +81,10 @@ impl TxInfo {
     pub fn created(&self) -> tokio::time::Instant {
         self.creation_instant
     }
+
+    pub fn span(&self) -> &tracing::Span {
+        &self.span
+    }
 }
 
 impl Deref for TxInfo {
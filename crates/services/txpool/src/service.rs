@@ -23,6 +23,7 @@ use fuel_core_services::{
     StateWatcher,
 };
 use fuel_core_types::{
+    entities::coins::CoinId,
     fuel_tx::{
         ConsensusParameters,
         Transaction,
@@ -318,12 +319,33 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
         self.txpool.lock().find_dependent(&ids)
     }
 
+    /// Returns the ids of coins and messages used as inputs by the pending transactions.
+    pub fn spent_inputs(&self) -> Vec<CoinId> {
+        let txpool = self.txpool.lock();
+        let dependency = txpool.dependency();
+        dependency
+            .spent_coins()
+            .map(|utxo_id| CoinId::Utxo(*utxo_id))
+            .chain(
+                dependency
+                    .spent_messages()
+                    .map(|nonce| CoinId::Message(*nonce)),
+            )
+            .collect()
+    }
+
     pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
         let mut guard = self.txpool.lock();
         let txs = guard.includable();
         let sorted_txs = select_transactions(txs, max_gas);
 
+        let current_span = tracing::Span::current();
         for tx in sorted_txs.iter() {
+            // Link the block production to the lifetime of the transaction in the pool,
+            // so the transaction can be followed from its submission to its inclusion.
+            if let Some(info) = guard.find_one(&tx.id()) {
+                current_span.follows_from(info.span());
+            }
             guard.remove_committed_tx(&tx.id());
         }
         sorted_txs
@@ -109,6 +109,54 @@ async fn insert_simple_tx_dependency_chain_succeeds() {
         .expect("Tx2 dependent should be OK, got Err");
 }
 
+#[tokio::test]
+async fn spent_inputs_of_pending_transactions_are_tracked() {
+    let mut context = TextContext::default();
+
+    let (_, gas_coin_1) = context.setup_coin();
+    let (output, unset_input) = context.create_output_and_input(1);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin_1.clone())
+        .add_output(output)
+        .finalize_as_transaction();
+    let tx1_output = UtxoId::new(tx1.id(&Default::default()), 0);
+
+    let (_, gas_coin_2) = context.setup_coin();
+    let (message, message_input) = create_message_predicate_from_message(5000, 0);
+    context.database_mut().insert_message(message.clone());
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(unset_input.into_input(tx1_output))
+        .add_input(gas_coin_2.clone())
+        .add_input(message_input)
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+
+    txpool.insert_single(tx1).expect("Tx1 should be OK");
+    // The output of the pending transaction is not spent yet
+    let spent_coins: Vec<_> = txpool.dependency().spent_coins().copied().collect();
+    assert_eq!(spent_coins, vec![*gas_coin_1.utxo_id().unwrap()]);
+
+    txpool.insert_single(tx2).expect("Tx2 should be OK");
+    let mut spent_coins: Vec<_> = txpool.dependency().spent_coins().copied().collect();
+    spent_coins.sort();
+    let mut expected = vec![
+        *gas_coin_1.utxo_id().unwrap(),
+        *gas_coin_2.utxo_id().unwrap(),
+        tx1_output,
+    ];
+    expected.sort();
+    assert_eq!(spent_coins, expected);
+    let spent_messages: Vec<_> = txpool.dependency().spent_messages().copied().collect();
+    assert_eq!(spent_messages, vec![*message.id()]);
+}
+
 #[tokio::test]
 async fn faulty_t2_collided_on_contract_id_from_tx1() {
     let mut context = TextContext::default();
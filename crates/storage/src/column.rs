@@ -115,6 +115,26 @@ column_definition! {
         TransactionsByOwnerBlockIdx = 25,
         /// The column of the table that stores `true` if `owner` owns `Message` with `message_id`
         OwnedMessageIds = 26,
+        /// Transaction id to the changes of the state made by the transaction
+        TransactionStateDiffs = 27,
+        /// The column of the table that orders `owner`'s coins of each asset by the amount
+        OwnedCoinsByAmount = 28,
+        /// The column of the table of all transactions that use the contract
+        TransactionsByContractBlockIdx = 29,
+        /// Asset id to the information about the asset aggregated from the executed transactions
+        Assets = 30,
+        /// The column of the table that stores `true` if `owner` has unspent coins of the `asset_id`
+        AssetHolders = 31,
+        /// The column of the table that links the time of the block to its height
+        FuelBlockHeightsByTime = 32,
+        /// The column of the table of all transactions that spend from the predicate root
+        TransactionsByPredicateRootBlockIdx = 33,
+        /// The column of the table of all transactions with the script or deployed bytecode
+        TransactionsByScriptHashBlockIdx = 34,
+        /// The column of the table that stores the state of each off-chain index
+        OffChainIndexes = 35,
+        /// The column of the table that stores the previous values of the keys modified by each block
+        ModificationsHistory = 36,
     }
 }
 
@@ -2,12 +2,55 @@
 #![deny(clippy::cast_possible_truncation)]
 
 use ctor::ctor;
+use opentelemetry::{
+    trace::TraceError,
+    KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::Resource;
 use std::env::var;
+use tracing::Subscriber;
 use tracing_subscriber::{
     fmt::format,
+    registry::LookupSpan,
     EnvFilter,
+    Layer,
 };
 
+/// The default endpoint of the OTLP collector accepting spans over HTTP.
+pub const DEFAULT_OTLP_ENDPOINT: &str = "http://localhost:4318";
+
+/// Creates the layer that exports spans to the OTLP collector at the `endpoint`.
+/// The spans are exported in batches from a separate thread,
+/// so the layer can be created outside of the Tokio runtime.
+pub fn otlp_layer<S>(
+    endpoint: &str,
+    service_name: &str,
+) -> Result<impl Layer<S>, TraceError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .http()
+                .with_endpoint(endpoint),
+        )
+        .with_trace_config(opentelemetry_sdk::trace::config().with_resource(
+            Resource::new([KeyValue::new("service.name", service_name.to_string())]),
+        ))
+        .install_batch(opentelemetry_sdk::runtime::TokioCurrentThread)?;
+
+    Ok(tracing_opentelemetry::layer().with_tracer(tracer))
+}
+
+/// Exports all pending spans and stops the OTLP exporter.
+/// The call blocks until the spans are sent to the collector.
+pub fn shutdown_otlp() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
 #[ctor]
 pub static TRACE: () = {
     if let Ok(v) = var("FUEL_TRACE") {
@@ -63,6 +106,27 @@ pub static TRACE: () = {
                     .with(log);
                 let _ = subscriber.try_init();
             }
+            "otlp" => {
+                use tracing_subscriber::prelude::*;
+                let endpoint = var("FUEL_TRACE_OTLP_ENDPOINT")
+                    .unwrap_or_else(|_| DEFAULT_OTLP_ENDPOINT.to_string());
+
+                let subscriber = tracing_subscriber::registry()
+                    .with(EnvFilter::from_default_env())
+                    .with(
+                        tracing_subscriber::fmt::Layer::new()
+                            .with_writer(std::io::stderr),
+                    );
+                match otlp_layer(&endpoint, "fuel-core-tests") {
+                    Ok(otlp) => {
+                        let _ = subscriber.with(otlp).try_init();
+                    }
+                    Err(err) => {
+                        eprintln!("Unable to export spans to {endpoint}: {err}");
+                        let _ = subscriber.try_init();
+                    }
+                }
+            }
             _ => (),
         }
     }
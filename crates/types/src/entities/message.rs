@@ -273,6 +273,7 @@ impl MessageProof {
 }
 
 /// Represents the status of a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MessageStatus {
     /// The message state
     pub state: MessageState,
@@ -302,6 +303,7 @@ impl MessageStatus {
 }
 
 /// The possible states a Message can be in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MessageState {
     /// Message is still unspent
     Unspent,
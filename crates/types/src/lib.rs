@@ -34,10 +34,12 @@ pub mod fuel_vm {
     pub use fuel_vm_private::{
         checked_transaction,
         consts,
+        context,
         crypto,
         double_key,
         error::PredicateVerificationFailed,
         interpreter,
+        predicate,
         prelude::{
             Backtrace,
             Call,
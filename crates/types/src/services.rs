@@ -4,6 +4,7 @@ pub mod block_importer;
 pub mod block_producer;
 pub mod executor;
 pub mod graphql_api;
+pub mod health;
 pub mod p2p;
 pub mod relayer;
 pub mod txpool;
@@ -8,6 +8,12 @@ use crate::{
         },
         primitives::BlockId,
     },
+    entities::coins::coin::Coin,
+    fuel_asm::{
+        PanicReason,
+        RawInstruction,
+        Word,
+    },
     fuel_tx::{
         Receipt,
         TxId,
@@ -15,6 +21,7 @@ use crate::{
         ValidityError,
     },
     fuel_types::{
+        AssetId,
         Bytes32,
         ContractId,
         Nonce,
@@ -56,6 +63,12 @@ pub struct TransactionExecutionStatus {
     pub result: TransactionExecutionResult,
     /// The receipts generated by the executed transaction.
     pub receipts: Vec<Receipt>,
+    /// The gas used by the executed transaction.
+    pub used_gas: u64,
+    /// The trace of the execution, if it was requested.
+    pub trace: Option<TransactionTrace>,
+    /// The changes of the state made by the transaction, if they were requested.
+    pub state_diff: Option<StateDiff>,
 }
 
 /// The result of transaction execution.
@@ -75,6 +88,115 @@ pub enum TransactionExecutionResult {
     },
 }
 
+/// The per-instruction trace of the transaction execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionTrace {
+    /// The executed instructions in the order of execution.
+    pub steps: Vec<TraceStep>,
+}
+
+/// The execution of one instruction by the VM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    /// The program counter of the instruction.
+    pub pc: Word,
+    /// The raw instruction.
+    pub instruction: RawInstruction,
+    /// The gas consumed by the instruction.
+    pub gas_used: Word,
+    /// The registers modified by the instruction with their new values.
+    pub changed_registers: Vec<(u8, Word)>,
+    /// The contract call frames active during the instruction, starting from the outermost.
+    pub call_frames: Vec<TraceCallFrame>,
+    /// The contract storage accessed by the instruction.
+    pub storage_access: Option<StorageAccess>,
+    /// The reason of the panic, if the instruction panicked.
+    pub panic_reason: Option<PanicReason>,
+}
+
+/// The contract call frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceCallFrame {
+    /// The called contract.
+    pub contract_id: ContractId,
+    /// The asset forwarded with the call.
+    pub asset_id: AssetId,
+    /// The size of the contract code.
+    pub code_size: Word,
+    /// The first parameter of the call.
+    pub param1: Word,
+    /// The second parameter of the call.
+    pub param2: Word,
+}
+
+/// The access to the contract storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageAccess {
+    /// The contract owning the storage.
+    pub contract_id: ContractId,
+    /// The key of the first accessed slot.
+    pub key: Bytes32,
+    /// The number of sequential slots accessed, starting from the `key`.
+    pub slots: Word,
+    /// The kind of the access.
+    pub kind: StorageAccessKind,
+}
+
+/// The kind of the access to the contract storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageAccessKind {
+    /// The slots were read.
+    Read,
+    /// The slots were written.
+    Write,
+    /// The slots were cleared.
+    Clear,
+}
+
+/// The changes of the state made by the execution of the transaction.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// The coins created from the outputs of the transaction.
+    pub coins_created: Vec<Coin>,
+    /// The coins spent by the inputs of the transaction.
+    pub coins_spent: Vec<UtxoId>,
+    /// The messages consumed by the inputs of the transaction.
+    pub messages_consumed: Vec<Nonce>,
+    /// The changed slots of the contracts storage.
+    pub storage_changes: Vec<StorageSlotChange>,
+    /// The changed balances of the contracts.
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+/// The change of the contract storage slot.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageSlotChange {
+    /// The contract owning the storage.
+    pub contract_id: ContractId,
+    /// The key of the slot.
+    pub key: Bytes32,
+    /// The value before the execution, `None` if the slot was unset.
+    pub previous: Option<Bytes32>,
+    /// The value after the execution, `None` if the slot was cleared.
+    pub current: Option<Bytes32>,
+}
+
+/// The change of the contract balance.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceChange {
+    /// The contract owning the balance.
+    pub contract_id: ContractId,
+    /// The asset of the balance.
+    pub asset_id: AssetId,
+    /// The balance before the execution.
+    pub previous: Word,
+    /// The balance after the execution.
+    pub current: Word,
+}
+
 /// Execution wrapper where the types
 /// depend on the type of execution.
 #[derive(Debug, Clone, Copy)]
@@ -312,6 +434,19 @@ pub enum Error {
     PreviousBlockIsNotFound,
     #[display(fmt = "The relayer gives incorrect messages for the requested da height")]
     RelayerGivesIncorrectMessages,
+    #[display(
+        fmt = "The trace of the transaction({transaction_id:#x}) exceeds the limit of {max_steps} steps"
+    )]
+    TraceTruncated {
+        transaction_id: Bytes32,
+        max_steps: usize,
+    },
+    #[display(fmt = "The execution of the transaction({_0:#x}) panicked")]
+    TransactionExecutionPanicked(Bytes32),
+    #[display(
+        fmt = "The transaction({_0:#x}) is not scheduled for the parallel execution"
+    )]
+    TransactionIsNotScheduled(Bytes32),
 }
 
 impl From<Error> for anyhow::Error {
@@ -1,9 +1,14 @@
 //! Types related to GraphQL API service.
 
-use crate::fuel_types::{
-    Address,
-    AssetId,
-    ContractId,
+use crate::{
+    fuel_tx::UtxoId,
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ContractId,
+    },
 };
 
 /// The cumulative balance(`amount`) of the `Owner` of `asset_id`.
@@ -21,3 +26,131 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The information about the asset aggregated from the executed transactions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AssetDetails {
+    /// The contract that minted the asset.
+    /// `None` if the asset was never minted by a contract, like the base asset.
+    pub contract_id: Option<ContractId>,
+    /// The sub identifier of the asset within the `contract_id`.
+    pub sub_id: Option<Bytes32>,
+    /// The cumulative amount minted by the `Mint` receipts.
+    pub total_minted: u128,
+    /// The cumulative amount burned by the `Burn` receipts.
+    pub total_burned: u128,
+    /// The number of owners with unspent coins of the asset.
+    pub holders: u64,
+}
+
+impl AssetDetails {
+    /// The amount of the asset minted by the contract and not burned yet.
+    pub fn total_supply(&self) -> u128 {
+        self.total_minted.saturating_sub(self.total_burned)
+    }
+}
+
+/// The index of the off-chain database maintained by the GraphQL worker.
+/// The transaction statuses and receipts are not an optional index,
+/// because they can't be rebuilt from the stored blocks.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OffChainIndex {
+    /// The transactions of the owners of inputs and outputs.
+    Owners,
+    /// The transactions of the contracts used as inputs.
+    Contracts,
+    /// The transactions of the roots of the spent predicates.
+    PredicateRoots,
+    /// The transactions of the scripts and the deployed bytecode.
+    ScriptHashes,
+    /// The coins of owners ordered by the amount and the holders of assets.
+    CoinsByAmount,
+    /// The minted and burned amounts of assets.
+    Assets,
+    /// The heights of blocks by the time.
+    BlockTimes,
+}
+
+impl OffChainIndex {
+    /// All off-chain indexes.
+    pub const ALL: [Self; 7] = [
+        Self::Owners,
+        Self::Contracts,
+        Self::PredicateRoots,
+        Self::ScriptHashes,
+        Self::CoinsByAmount,
+        Self::Assets,
+        Self::BlockTimes,
+    ];
+
+    /// Returns the name of the index used in the configuration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Owners => "owners",
+            Self::Contracts => "contracts",
+            Self::PredicateRoots => "predicate-roots",
+            Self::ScriptHashes => "script-hashes",
+            Self::CoinsByAmount => "coins-by-amount",
+            Self::Assets => "assets",
+            Self::BlockTimes => "block-times",
+        }
+    }
+}
+
+impl std::fmt::Display for OffChainIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for OffChainIndex {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|index| index.name() == s)
+            .ok_or_else(|| format!("Unknown off-chain index `{s}`"))
+    }
+}
+
+/// The state of the off-chain index stored by the GraphQL worker.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexState {
+    /// The index is being built from the stored blocks,
+    /// and the blocks below the `next_height` are already indexed.
+    Backfilling {
+        /// The height of the next stored block to index.
+        next_height: BlockHeight,
+    },
+    /// The index is built, and the worker indexes the imported blocks
+    /// starting from the `since` height.
+    Ready {
+        /// The height of the first block indexed on import.
+        since: BlockHeight,
+    },
+    /// The index is being built from the unspent coins, and the coins below
+    /// the `next_coin` are already indexed. The worker indexes the imported blocks
+    /// starting from the `since` height meanwhile.
+    ScanningCoins {
+        /// The height of the first block indexed on import.
+        since: BlockHeight,
+        /// The id of the next unspent coin to index.
+        next_coin: UtxoId,
+    },
+}
+
+impl IndexState {
+    /// Returns `true` if the imported block at the `height` should be indexed.
+    pub fn is_indexed_on_import(&self, height: BlockHeight) -> bool {
+        match self {
+            IndexState::Backfilling { .. } => false,
+            IndexState::Ready { since } | IndexState::ScanningCoins { since, .. } => {
+                height >= *since
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Types related to the health and readiness of the node.
+
+use crate::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::BlockHeight,
+};
+
+/// The readiness report of the node. It aggregates the state of all components
+/// required for the node to serve up-to-date data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReadinessReport {
+    /// The state of the relayer, if the relayer is enabled.
+    pub relayer: Option<RelayerReadiness>,
+    /// The state of the P2P network, if the P2P is enabled.
+    pub p2p: Option<P2pReadiness>,
+    /// The synchronization state of the block production, if the block production is enabled.
+    pub sync: Option<SyncReadiness>,
+    /// The state of the block importer.
+    pub importer: ImporterReadiness,
+    /// Whether the database accepts writes.
+    pub database_writable: bool,
+    /// The state of each sub-service of the node.
+    pub services: Vec<ServiceReadiness>,
+}
+
+impl ReadinessReport {
+    /// Returns `true` if all components of the node are ready.
+    pub fn is_ready(&self) -> bool {
+        self.relayer
+            .as_ref()
+            .map_or(true, RelayerReadiness::is_ready)
+            && self.p2p.as_ref().map_or(true, P2pReadiness::is_ready)
+            && self.sync.as_ref().map_or(true, |sync| sync.synced)
+            && self.importer.is_ready()
+            && self.database_writable
+            && self.services.iter().all(ServiceReadiness::is_ready)
+    }
+}
+
+/// The state of the relayer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelayerReadiness {
+    /// The da height of the last synchronization with the da layer.
+    /// `None` if the relayer has never been in sync.
+    pub synced_da_height: Option<DaBlockHeight>,
+    /// The finalized da height known to the node.
+    pub finalized_da_height: Option<DaBlockHeight>,
+    /// The latest finalized da height observed on the da layer.
+    /// `None` if the relayer has never reached the da layer.
+    pub latest_da_finalized_height: Option<DaBlockHeight>,
+    /// The maximum number of da blocks the relayer can lag behind the da layer to be ready.
+    pub max_lag: u64,
+}
+
+impl RelayerReadiness {
+    /// Returns `true` if the relayer doesn't lag behind the da layer more than allowed.
+    pub fn is_ready(&self) -> bool {
+        match (self.synced_da_height, self.latest_da_finalized_height) {
+            (Some(synced), Some(latest)) => {
+                synced.0.saturating_add(self.max_lag) >= latest.0
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The state of the P2P network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct P2pReadiness {
+    /// The number of connected peers.
+    pub connected_peers: u32,
+    /// The number of connected reserved peers.
+    pub connected_reserved_peers: u32,
+    /// The minimum number of the connected reserved peers required to be ready.
+    pub min_connected_reserved_peers: u32,
+}
+
+impl P2pReadiness {
+    /// Returns `true` if the node is connected to enough reserved peers.
+    pub fn is_ready(&self) -> bool {
+        self.connected_reserved_peers >= self.min_connected_reserved_peers
+    }
+}
+
+/// The synchronization state of the block production.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyncReadiness {
+    /// Whether the node considers itself synced with the network.
+    pub synced: bool,
+    /// The height of the block at which the node became synced.
+    pub synced_height: Option<BlockHeight>,
+}
+
+/// The state of the block importer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImporterReadiness {
+    /// The height of the latest imported block.
+    pub latest_height: BlockHeight,
+    /// The highest block height reported by peers.
+    pub highest_peer_height: Option<BlockHeight>,
+    /// The number of blocks the node is behind the highest peer height.
+    pub lag: u32,
+    /// The maximum lag allowed to be ready.
+    pub max_lag: u32,
+}
+
+impl ImporterReadiness {
+    /// Returns `true` if the importer doesn't lag behind the network.
+    pub fn is_ready(&self) -> bool {
+        self.lag <= self.max_lag
+    }
+}
+
+/// The state of the sub-service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceReadiness {
+    /// The name of the service.
+    pub name: String,
+    /// The state of the service.
+    pub state: ServiceState,
+    /// The error that stopped the service, if any.
+    pub error: Option<String>,
+}
+
+impl ServiceReadiness {
+    /// Returns `true` if the service is running.
+    pub fn is_ready(&self) -> bool {
+        self.state == ServiceState::Started
+    }
+}
+
+/// The lifecycle state of the sub-service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ServiceState {
+    /// The service is initialized but not started.
+    NotStarted,
+    /// The service is starting.
+    Starting,
+    /// The service is running.
+    Started,
+    /// The service is stopping.
+    Stopping,
+    /// The service is stopped.
+    Stopped,
+    /// The service is stopped with an error.
+    StoppedWithError,
+}
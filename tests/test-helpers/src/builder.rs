@@ -8,6 +8,7 @@ use fuel_core::{
     service::{
         Config,
         FuelService,
+        IndexesConfig,
     },
 };
 use fuel_core_client::client::FuelClient;
@@ -92,6 +93,7 @@ pub struct TestSetupBuilder {
     pub starting_block: Option<BlockHeight>,
     pub utxo_validation: bool,
     pub trigger: Trigger,
+    pub indexes: IndexesConfig,
 }
 
 impl TestSetupBuilder {
@@ -202,6 +204,7 @@ impl TestSetupBuilder {
             },
             chain_conf: chain_config,
             block_production: self.trigger,
+            indexes: self.indexes.clone(),
             ..Config::local_node()
         };
 
@@ -227,6 +230,7 @@ impl Default for TestSetupBuilder {
             starting_block: None,
             utxo_validation: true,
             trigger: Trigger::Instant,
+            indexes: Default::default(),
         }
     }
 }
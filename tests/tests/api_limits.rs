@@ -0,0 +1,138 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+    RateLimitConfig,
+};
+use reqwest::StatusCode;
+use serde_json::{
+    json,
+    Value,
+};
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
+
+async fn graphql_request(
+    address: SocketAddr,
+    query: &str,
+    api_key: Option<&str>,
+) -> (StatusCode, Value) {
+    let mut request = reqwest::Client::new()
+        .post(format!("http://{address}/graphql"))
+        .json(&json!({ "query": query }));
+    if let Some(api_key) = api_key {
+        request = request.header("X-Api-Key", api_key);
+    }
+    let response = request.send().await.unwrap();
+    let status = response.status();
+    let body = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    (status, body)
+}
+
+fn error_code(body: &Value) -> &str {
+    body["errors"][0]["extensions"]["code"]
+        .as_str()
+        .expect("The error should have a code")
+}
+
+#[tokio::test]
+async fn query_deeper_than_limit_is_rejected() {
+    let mut config = Config::local_node();
+    config.api_limits.max_depth = 3;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let (_, body) = graphql_request(
+        srv.bound_address,
+        "{ chain { latestBlock { header { height } } } }",
+        None,
+    )
+    .await;
+    assert_eq!(error_code(&body), "QUERY_TOO_DEEP");
+
+    let (_, body) =
+        graphql_request(srv.bound_address, "{ chain { latestBlock { id } } }", None)
+            .await;
+    assert!(body["errors"].is_null());
+}
+
+#[tokio::test]
+async fn query_more_complex_than_limit_is_rejected() {
+    let mut config = Config::local_node();
+    config.api_limits.max_complexity = 100;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    // The complexity of the page is multiplied by its size
+    let (_, body) = graphql_request(
+        srv.bound_address,
+        "{ blocks(first: 100) { nodes { id header { height } } } }",
+        None,
+    )
+    .await;
+    assert_eq!(error_code(&body), "QUERY_TOO_COMPLEX");
+
+    let (_, body) = graphql_request(
+        srv.bound_address,
+        "{ blocks(first: 10) { nodes { id header { height } } } }",
+        None,
+    )
+    .await;
+    assert!(body["errors"].is_null());
+}
+
+#[tokio::test]
+async fn page_larger_than_limit_is_rejected() {
+    let mut config = Config::local_node();
+    config.api_limits.max_page_size = 5;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let (_, body) = graphql_request(
+        srv.bound_address,
+        "{ blocks(last: 6) { nodes { id } } }",
+        None,
+    )
+    .await;
+    assert_eq!(error_code(&body), "PAGE_SIZE_EXCEEDED");
+
+    let (_, body) = graphql_request(
+        srv.bound_address,
+        "{ blocks(last: 5) { nodes { id } } }",
+        None,
+    )
+    .await;
+    assert!(body["errors"].is_null());
+}
+
+#[tokio::test]
+async fn requests_above_rate_limit_are_rejected() {
+    let mut config = Config::local_node();
+    config.api_limits.rate_limit = Some(RateLimitConfig {
+        requests: 2,
+        period: Duration::from_secs(3600),
+        api_keys: ["secret".to_string()].into_iter().collect(),
+        forwarded_for_header: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let query = "{ health }";
+
+    for _ in 0..2 {
+        let (status, _) = graphql_request(srv.bound_address, query, None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+    let (status, body) = graphql_request(srv.bound_address, query, None).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(error_code(&body), "RATE_LIMITED");
+
+    // The requests with the known API key are limited separately from the IP address
+    let (status, _) = graphql_request(srv.bound_address, query, Some("secret")).await;
+    assert_eq!(status, StatusCode::OK);
+    // The unknown API keys are ignored
+    let (status, _) = graphql_request(srv.bound_address, query, Some("unknown")).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+
+    // The probes are not limited
+    let response = reqwest::get(format!("http://{}/health", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,170 @@
+#![allow(non_snake_case)]
+
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
+use fuel_core_client::client::pagination::{
+    PageDirection,
+    PaginationRequest,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        GTFArgs,
+        RegId,
+    },
+    fuel_tx::{
+        Address,
+        AssetId,
+        Bytes32,
+        ContractId,
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        UtxoId,
+        Witness,
+    },
+    fuel_types::canonical::Serialize,
+    fuel_vm::Call,
+};
+
+fn call_contract_tx(contract_id: ContractId, revert: bool) -> Transaction {
+    let script: Vec<u8> = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        if revert {
+            op::rvrt(RegId::ONE)
+        } else {
+            op::ret(RegId::ONE)
+        },
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn asset__tracks_minted_and_burned_amounts() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    // The sub id of the asset is the id of the contract,
+    // because the call frame starts with it.
+    let contract_code: Vec<u8> = [
+        op::movi(0x10, 100),
+        op::mint(0x10, RegId::FP),
+        op::movi(0x11, 30),
+        op::burn(0x11, RegId::FP),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, None, None, None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    let sub_id = Bytes32::new(*contract_id);
+    let asset_id = contract_id.asset_id(&sub_id);
+
+    // Given
+    client
+        .submit_and_await_commit(&call_contract_tx(contract_id, false))
+        .await
+        .unwrap();
+    // The receipts of the reverted transaction are not counted
+    client
+        .submit_and_await_commit(&call_contract_tx(contract_id, true))
+        .await
+        .unwrap();
+
+    // When
+    let asset = client
+        .asset(&asset_id)
+        .await
+        .unwrap()
+        .expect("Asset exists");
+
+    // Then
+    assert_eq!(asset.id, asset_id);
+    assert_eq!(asset.contract_id, Some(contract_id));
+    assert_eq!(asset.sub_id, Some(sub_id));
+    assert_eq!(asset.total_minted, 100);
+    assert_eq!(asset.total_burned, 30);
+    assert_eq!(asset.total_supply, 70);
+    assert_eq!(asset.holders, 0);
+
+    let assets = client
+        .assets(PaginationRequest {
+            cursor: None,
+            results: 100,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+    assert!(assets.results.contains(&asset));
+}
+
+#[tokio::test]
+async fn asset__counts_owners_with_unspent_coins() {
+    let funder = Address::from([9; 32]);
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let asset_id = AssetId::default();
+
+    let mut context = TestContext::new(100).await;
+    let holders = |asset: Option<fuel_core_client::client::types::Asset>| {
+        asset.map(|asset| asset.holders).unwrap_or_default()
+    };
+    let initial_holders = holders(context.client.asset(&asset_id).await.unwrap());
+
+    // Given
+    context.transfer(funder, alice, 10).await.unwrap();
+    let tx_id = context.transfer(funder, bob, 10).await.unwrap();
+    assert_eq!(
+        holders(context.client.asset(&asset_id).await.unwrap()),
+        initial_holders + 2
+    );
+
+    // When
+    let spend_bob_coin =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_input(Input::coin_signed(
+                UtxoId::new(tx_id, 0),
+                bob,
+                10,
+                asset_id,
+                Default::default(),
+                0,
+                Default::default(),
+            ))
+            .add_witness(Witness::default())
+            .add_output(Output::coin(alice, 10, asset_id))
+            .finalize_as_transaction();
+    context
+        .client
+        .submit_and_await_commit(&spend_bob_coin)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        holders(context.client.asset(&asset_id).await.unwrap()),
+        initial_holders + 1
+    );
+}
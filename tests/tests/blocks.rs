@@ -305,6 +305,109 @@ async fn block_connection_5(
     };
 }
 
+/// Produces 5 blocks with 10 seconds between them starting from the returned time
+/// and waits until the off-chain worker indexes all of them.
+async fn produce_blocks_with_interval() -> (FuelService, FuelClient, u64) {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Interval {
+        block_time: Duration::from_secs(10),
+    };
+
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let start_timestamp = Tai64::UNIX_EPOCH.0 + 100u64;
+    client
+        .produce_blocks(5, Some(start_timestamp))
+        .await
+        .unwrap();
+
+    // The off-chain worker indexes the time of blocks asynchronously
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while client
+            .block_by_time(Tai64(start_timestamp + 40))
+            .await
+            .unwrap()
+            .is_none()
+        {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("The last block should be indexed");
+
+    (srv, client, start_timestamp)
+}
+
+#[tokio::test]
+async fn block_by_time() {
+    let (_srv, client, start_timestamp) = produce_blocks_with_interval().await;
+
+    let height_at = |time: u64| {
+        let client = &client;
+        async move {
+            client
+                .block_by_time(Tai64(time))
+                .await
+                .unwrap()
+                .map(|block| block.header.height)
+        }
+    };
+
+    assert_eq!(height_at(start_timestamp).await, Some(1));
+    assert_eq!(height_at(start_timestamp + 10).await, Some(2));
+    assert_eq!(height_at(start_timestamp + 15).await, Some(3));
+    assert_eq!(height_at(start_timestamp + 40).await, Some(5));
+    assert_eq!(height_at(start_timestamp + 41).await, None);
+}
+
+#[rstest]
+#[tokio::test]
+async fn blocks_by_time(
+    #[values(PageDirection::Forward, PageDirection::Backward)]
+    pagination_direction: PageDirection,
+) {
+    let (_srv, client, start_timestamp) = produce_blocks_with_interval().await;
+
+    let blocks = client
+        .blocks_by_time(
+            Some(Tai64(start_timestamp + 5)),
+            Some(Tai64(start_timestamp + 30)),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: pagination_direction,
+            },
+        )
+        .await
+        .unwrap();
+
+    let heights = blocks
+        .results
+        .into_iter()
+        .map(|b| b.header.height)
+        .collect_vec();
+    match pagination_direction {
+        PageDirection::Forward => assert_eq!(heights, vec![2, 3, 4]),
+        PageDirection::Backward => assert_eq!(heights, vec![4, 3, 2]),
+    }
+
+    let blocks = client
+        .blocks_by_time(
+            Some(Tai64(start_timestamp + 41)),
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: pagination_direction,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(blocks.results.is_empty());
+}
+
 mod full_block {
     use super::*;
     use cynic::QueryBuilder;
@@ -12,9 +12,14 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
-    types::CoinType,
+    types::{
+        CoinSelectionStrategy,
+        CoinType,
+        CoinsToSpendOptions,
+    },
     FuelClient,
 };
+use fuel_core_poa::Trigger;
 use fuel_core_types::fuel_tx::*;
 use rand::{
     prelude::StdRng,
@@ -31,9 +36,19 @@ mod coin {
         owner: Address,
         asset_id_a: AssetId,
         asset_id_b: AssetId,
+    ) -> TestContext {
+        setup_with_trigger(owner, asset_id_a, asset_id_b, Trigger::Instant).await
+    }
+
+    async fn setup_with_trigger(
+        owner: Address,
+        asset_id_a: AssetId,
+        asset_id_b: AssetId,
+        trigger: Trigger,
     ) -> TestContext {
         // setup config
         let mut config = Config::local_node();
+        config.block_production = trigger;
         config.chain_conf.initial_state = Some(StateConfig {
             height: None,
             contracts: None,
@@ -149,6 +164,142 @@ mod coin {
         assert!(remaining_coins_b.is_err())
     }
 
+    #[tokio::test]
+    async fn excludes_coins_spent_by_pending_transactions() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let asset_id_a: AssetId = rng.gen();
+        let asset_id_b: AssetId = rng.gen();
+        let secret_key: SecretKey = SecretKey::random(&mut rng);
+        let pk = secret_key.public_key();
+        let owner = Input::owner(&pk);
+        // the transactions stay in the pool
+        let context =
+            setup_with_trigger(owner, asset_id_a, asset_id_b, Trigger::Never).await;
+
+        // spend the largest coin of the asset a
+        let coin = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 150, Some(1))], None)
+            .await
+            .unwrap()
+            .remove(0)
+            .remove(0);
+        let CoinType::Coin(coin) = coin else {
+            panic!("Only coins are owned")
+        };
+        let mut script = TransactionBuilder::script(vec![], vec![]);
+        script.add_unsigned_coin_input(
+            secret_key,
+            coin.utxo_id,
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+            coin.maturity.into(),
+        );
+        script.add_output(Output::change(owner, 0, asset_id_a));
+        let tx = script.finalize_as_transaction();
+        context.client.submit(&tx).await.unwrap();
+
+        // the pending spending is ignored by default
+        let coins_per_asset = context
+            .client
+            .coins_to_spend(&owner, vec![(asset_id_a, 300, None)], None)
+            .await
+            .unwrap();
+        assert_eq!(coins_per_asset[0].amount(), 300);
+
+        // the coin spent by the pending transaction is excluded
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_opt(
+                &owner,
+                vec![(asset_id_a, 150, None)],
+                None,
+                CoinsToSpendOptions {
+                    exclude_pending_inputs: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let mut amounts: Vec<_> = coins_per_asset[0]
+            .iter()
+            .map(|coin| coin.amount())
+            .collect();
+        amounts.sort();
+        assert_eq!(amounts, vec![50, 100]);
+
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_opt(
+                &owner,
+                vec![(asset_id_a, 300, None)],
+                None,
+                CoinsToSpendOptions {
+                    exclude_pending_inputs: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(coins_per_asset.is_err());
+    }
+
+    #[tokio::test]
+    async fn selects_coins_with_strategy() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let owner = rng.gen();
+        let asset_id_a = rng.gen();
+        let asset_id_b = rng.gen();
+        let context = setup(owner, asset_id_a, asset_id_b).await;
+
+        let select = |target: u64, max: Option<u32>, strategy: CoinSelectionStrategy| {
+            let client = &context.client;
+            async move {
+                let coins_per_asset = client
+                    .coins_to_spend_opt(
+                        &owner,
+                        vec![(asset_id_a, target, max)],
+                        None,
+                        CoinsToSpendOptions {
+                            strategy: Some(strategy),
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .unwrap();
+                let mut amounts: Vec<_> = coins_per_asset[0]
+                    .iter()
+                    .map(|coin| coin.amount())
+                    .collect();
+                amounts.sort();
+                amounts
+            }
+        };
+
+        assert_eq!(
+            select(60, None, CoinSelectionStrategy::LargestFirst).await,
+            vec![150]
+        );
+        // the dust is swept up to the `max` number of coins
+        assert_eq!(
+            select(60, Some(2), CoinSelectionStrategy::SmallestFirst).await,
+            vec![50, 100]
+        );
+        assert_eq!(
+            select(60, None, CoinSelectionStrategy::SmallestFirst).await,
+            vec![50, 100, 150]
+        );
+        // the coins without the change
+        assert_eq!(
+            select(200, None, CoinSelectionStrategy::ExactMatch).await,
+            vec![50, 150]
+        );
+        assert_eq!(
+            select(250, None, CoinSelectionStrategy::ExactMatch).await,
+            vec![100, 150]
+        );
+    }
+
     async fn query_target_1(owner: Address, asset_id_a: AssetId, asset_id_b: AssetId) {
         let context = setup(owner, asset_id_a, asset_id_b).await;
 
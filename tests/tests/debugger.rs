@@ -1,12 +1,45 @@
-use fuel_core::service::{
-    Config,
-    FuelService,
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
+use fuel_core::{
+    database::history::StateRewindPolicy,
+    service::{
+        Config,
+        FuelService,
+    },
 };
 use fuel_core_client::client::FuelClient;
 use fuel_core_types::{
-    fuel_tx::Transaction,
-    fuel_types::ContractId,
+    fuel_asm::{
+        op,
+        GTFArgs,
+        RegId,
+    },
+    fuel_tx::{
+        Input,
+        Output,
+        PanicReason,
+        Receipt,
+        ScriptExecutionResult,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        AssetId,
+        Bytes32,
+        ChainId,
+        ContractId,
+        Salt,
+    },
+    fuel_vm::{
+        consts::MEM_SIZE,
+        Call,
+    },
 };
+use std::time::Duration;
 
 /// Tests that debugger doesn't produce any errors with a running local node,
 /// and also verifies that breakpoints are working as they should
@@ -44,3 +77,336 @@ async fn debugger_integration() {
     let result = client.end_session(session_id).await.unwrap();
     assert!(result);
 }
+
+#[tokio::test]
+async fn debugger_starts_transaction_from_chain() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::bhei(0x10),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+
+    // The transaction is included into the first block
+    client.submit_and_await_commit(&tx).await.unwrap();
+    client.produce_blocks(2, None).await.unwrap();
+
+    let session = client.start_session().await.unwrap();
+    let session_id = session.as_str();
+
+    let status = client
+        .start_tx_from_chain(session_id, &tx_id)
+        .await
+        .unwrap();
+    assert!(status.breakpoint.is_none());
+    let receipts: Vec<_> = status.receipts().collect();
+    assert!(matches!(receipts[0], Receipt::Log { ra, .. } if ra == 1));
+
+    let result = client.end_session(session_id).await.unwrap();
+    assert!(result);
+}
+
+#[tokio::test]
+async fn debugger_requires_history_to_start_transaction_from_chain() {
+    let mut config = Config::local_node();
+    config.state_rewind_policy = StateRewindPolicy::NoRewind;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script: Vec<u8> = u32::from(op::ret(RegId::ONE)).to_be_bytes().to_vec();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let session = client.start_session().await.unwrap();
+    let error = client
+        .start_tx_from_chain(session.as_str(), &tx_id)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("--state-rewind-blocks"));
+}
+
+#[tokio::test]
+async fn debugger_rejects_transaction_not_included_into_chain() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let session = client.start_session().await.unwrap();
+    let result = client
+        .start_tx_from_chain(session.as_str(), &tx.id(&ChainId::default()))
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn debugger_inspects_call_frames_and_contract_state() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let contract_code: Vec<u8> = [op::noop(), op::noop(), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect();
+    let (_, contract_id) = test_builder.setup_contract(
+        contract_code,
+        Some(vec![(AssetId::zeroed(), 100)]),
+        None,
+        None,
+    );
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let script: Vec<u8> = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(contract_id, 1, 2).to_bytes();
+    let tx = TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let session = client.start_session().await.unwrap();
+    let session_id = session.as_str();
+
+    // No frames outside of the contract call
+    assert!(client.call_frames(session_id).await.unwrap().is_empty());
+
+    client
+        .set_breakpoint(session_id, contract_id, 1)
+        .await
+        .unwrap();
+    let status = client.start_tx(session_id, &tx).await.unwrap();
+    let breakpoint = status.breakpoint.expect("The breakpoint should be reached");
+    assert_eq!(breakpoint.contract.0 .0, contract_id);
+    // The offset of the breakpoint is reported in bytes
+    assert_eq!(breakpoint.pc.0, 4);
+
+    let frames = client.call_frames(session_id).await.unwrap();
+    assert_eq!(frames.len(), 1);
+    assert_eq!(frames[0].contract_id.0 .0, contract_id);
+    assert_eq!(frames[0].code_offset.0, 1);
+    assert_eq!(frames[0].param1.0, 1);
+    assert_eq!(frames[0].param2.0, 2);
+
+    let balance = client
+        .session_balance(session_id, &contract_id, &AssetId::zeroed())
+        .await
+        .unwrap();
+    assert_eq!(balance, 100);
+    let slot = client
+        .session_storage_slot(session_id, &contract_id, &Bytes32::zeroed())
+        .await
+        .unwrap();
+    assert!(slot.is_none());
+
+    client
+        .remove_breakpoint(session_id, contract_id, 1)
+        .await
+        .unwrap();
+    let status = client.continue_tx(session_id).await.unwrap();
+    assert!(status.breakpoint.is_none());
+
+    let result = client.end_session(session_id).await.unwrap();
+    assert!(result);
+}
+
+#[tokio::test]
+async fn debugger_reports_panic_of_script() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The script panics on the division by zero
+    let script: Vec<u8> = [
+        op::noop(),
+        op::div(0x10, RegId::ONE, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let session = client.start_session().await.unwrap();
+    let session_id = session.as_str();
+
+    let assert_panic = |receipts: Vec<Receipt>| {
+        assert!(matches!(
+            &receipts[..],
+            [
+                ..,
+                Receipt::Panic { reason, .. },
+                Receipt::ScriptResult {
+                    result: ScriptExecutionResult::Panic,
+                    ..
+                },
+            ] if *reason.reason() == PanicReason::ArithmeticError
+        ));
+    };
+
+    // The breakpoint after the panic is never reached
+    client
+        .set_breakpoint(session_id, ContractId::zeroed(), 2)
+        .await
+        .unwrap();
+    let status = client.start_tx(session_id, &tx).await.unwrap();
+    assert!(status.breakpoint.is_none());
+    assert_panic(status.receipts().collect());
+
+    // The script is stepped by the session after the first instruction
+    client.set_single_stepping(session_id, true).await.unwrap();
+    let status = client.start_tx(session_id, &tx).await.unwrap();
+    assert!(status.breakpoint.is_some());
+    client.set_single_stepping(session_id, false).await.unwrap();
+    let status = client.continue_tx(session_id).await.unwrap();
+    assert!(status.breakpoint.is_none());
+    assert_panic(status.receipts().collect());
+
+    let result = client.end_session(session_id).await.unwrap();
+    assert!(result);
+}
+
+#[tokio::test]
+async fn debugger_evaluates_predicates() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The predicate returns 5 instead of 1, so it fails on the last instruction
+    let predicate: Vec<u8> = [op::addi(0x10, RegId::ZERO, 5), op::noop(), op::ret(0x10)]
+        .into_iter()
+        .collect();
+    let input = Input::coin_predicate(
+        Default::default(),
+        Input::predicate_owner(&predicate),
+        1000,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        predicate,
+        vec![],
+    );
+    let script = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_000_000)
+        .add_input(input.clone())
+        .finalize_as_transaction();
+    let create = TransactionBuilder::create(vec![].into(), Salt::zeroed(), vec![])
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let session = client.start_session().await.unwrap();
+    let session_id = session.as_str();
+    client
+        .set_breakpoint(session_id, ContractId::zeroed(), 1)
+        .await
+        .unwrap();
+
+    for tx in [script, create] {
+        let status = client
+            .start_predicate(session_id, &tx, 0, true)
+            .await
+            .unwrap();
+        let breakpoint = status.breakpoint.expect("The breakpoint should be reached");
+        assert_eq!(breakpoint.pc.0, 4);
+        assert!(status.predicate_failure.is_none());
+        let register = client.register(session_id, 0x10).await.unwrap();
+        assert_eq!(register, 5);
+
+        let status = client.continue_tx(session_id).await.unwrap();
+        assert!(status.breakpoint.is_none());
+        let failure = status.predicate_failure.expect("The predicate should fail");
+        assert_eq!(failure.input_index.0, 0);
+        assert_eq!(failure.pc.0, 2);
+        assert!(failure.reason.contains("PredicateReturnedNonOne"));
+    }
+
+    let result = client.end_session(session_id).await.unwrap();
+    assert!(result);
+}
+
+#[tokio::test]
+async fn debugger_limits_number_of_sessions() {
+    let mut config = Config::local_node();
+    config.debugger.max_sessions = 1;
+    config.debugger.session_timeout = Duration::from_secs(2);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let session = client.start_session().await.unwrap();
+    assert!(client.start_session().await.is_err());
+    assert!(client.end_session(&session).await.unwrap());
+
+    let session = client.start_session().await.unwrap();
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    // The idle session is killed, so there is a place for a new one
+    assert!(client.register(&session, 0x10).await.is_err());
+    client.start_session().await.unwrap();
+}
+
+#[tokio::test]
+async fn debugger_limits_memory_of_sessions() {
+    let mut config = Config::local_node();
+    config.debugger.max_memory = MEM_SIZE;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let predicate: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_predicate(
+            Default::default(),
+            Input::predicate_owner(&predicate),
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        ))
+        .finalize_as_transaction();
+
+    let session = client.start_session().await.unwrap();
+    // Neither a new session nor a predicate have memory for their VMs
+    assert!(client.start_session().await.is_err());
+    assert!(client
+        .start_predicate(&session, &tx, 0, true)
+        .await
+        .is_err());
+
+    assert!(client.end_session(&session).await.unwrap());
+    let session = client.start_session().await.unwrap();
+    assert!(client.end_session(&session).await.unwrap());
+}
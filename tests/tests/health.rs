@@ -6,6 +6,7 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::FuelClient;
+use fuel_core_types::services::health::ServiceState;
 
 #[tokio::test]
 async fn health() {
@@ -18,6 +19,58 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn readiness() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let report = client.readiness().await.unwrap();
+    assert!(report.is_ready());
+    assert!(report.database_writable);
+    assert!(report.sync.unwrap().synced);
+    assert!(!report.services.is_empty());
+    assert!(report
+        .services
+        .iter()
+        .all(|service| service.state == ServiceState::Started));
+
+    let resp = reqwest::get(format!("http://{}/health/ready", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    let body: serde_json::Value =
+        serde_json::from_str(&resp.text().await.unwrap()).unwrap();
+    assert_eq!(body["ready"], true);
+}
+
+#[cfg(feature = "p2p")]
+#[tokio::test]
+async fn readiness_requires_reserved_peers() {
+    let mut config = Config::local_node();
+    config.min_connected_reserved_peers = 1;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let report = client.readiness().await.unwrap();
+    assert!(!report.is_ready());
+    let network = report.p2p.unwrap();
+    assert_eq!(network.connected_reserved_peers, 0);
+    assert_eq!(network.min_connected_reserved_peers, 1);
+    assert!(!report.sync.unwrap().synced);
+
+    let resp = reqwest::get(format!("http://{}/health/ready", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::SERVICE_UNAVAILABLE);
+    let body: serde_json::Value =
+        serde_json::from_str(&resp.text().await.unwrap()).unwrap();
+    assert_eq!(body["ready"], false);
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
@@ -27,7 +80,7 @@ async fn can_restart_node() {
     // start node once
     {
         use fuel_core::service::ServiceTrait;
-        let database = Database::open(tmp_dir.path(), None).unwrap();
+        let database = Database::open(tmp_dir.path(), None, false).unwrap();
         let first_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
@@ -35,7 +88,7 @@ async fn can_restart_node() {
     }
 
     {
-        let database = Database::open(tmp_dir.path(), None).unwrap();
+        let database = Database::open(tmp_dir.path(), None, false).unwrap();
         let _second_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
@@ -0,0 +1,146 @@
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+        IndexesConfig,
+        ServiceTrait,
+    },
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        Address,
+        AssetId,
+        ContractId,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    services::graphql_api::OffChainIndex,
+};
+use std::time::Duration;
+
+fn all_results() -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor: None,
+        results: 100,
+        direction: PageDirection::Forward,
+    }
+}
+
+fn transfer_tx(owner: Address) -> Transaction {
+    TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .add_output(Output::coin(owner, 10, AssetId::default()))
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn index__disabled_index_returns_error() {
+    // Given
+    let mut config = Config::local_node();
+    config.indexes = IndexesConfig::new([OffChainIndex::Owners]);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let by_owner = client
+        .transactions_by_owner(&Address::default(), all_results())
+        .await;
+    let by_contract = client
+        .transactions_by_contract(&ContractId::default(), all_results())
+        .await;
+
+    // Then
+    assert!(by_owner.is_ok());
+    let err = by_contract.expect_err("The index is disabled");
+    assert!(err
+        .to_string()
+        .contains("The `contracts` index is disabled on the node"));
+}
+
+#[tokio::test]
+async fn index__coins_to_spend_works_without_coins_by_amount_index() {
+    let owner = Address::from([1; 32]);
+    let tx = transfer_tx(owner);
+
+    // Given
+    let mut config = Config::local_node();
+    config.indexes.disable(OffChainIndex::CoinsByAmount);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // When
+    let coins = client
+        .coins_to_spend(&owner, vec![(AssetId::default(), 10, None)], None)
+        .await
+        .unwrap();
+
+    // Then
+    let amounts: Vec<_> = coins[0].iter().map(|coin| coin.amount()).collect();
+    assert_eq!(amounts, vec![10]);
+}
+
+#[tokio::test]
+async fn index__enabled_index_is_backfilled_from_stored_blocks() {
+    let database = Database::default();
+    let owner = Address::from([1; 32]);
+    let tx = transfer_tx(owner);
+
+    // Given
+    let mut config = Config::local_node();
+    let chain_id = config.chain_conf.consensus_parameters.chain_id;
+    config.indexes.disable(OffChainIndex::Owners);
+    let srv = FuelService::from_database(database.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let err = client
+        .transactions_by_owner(&owner, all_results())
+        .await
+        .expect_err("The index is disabled");
+    assert!(err
+        .to_string()
+        .contains("The `owners` index is disabled on the node"));
+    srv.stop_and_await().await.unwrap();
+
+    // When
+    let srv = FuelService::from_database(database, Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Then
+    // The index is backfilled in the background after the start of the node
+    let mut transactions = None;
+    for _ in 0..100 {
+        if let Ok(result) = client.transactions_by_owner(&owner, all_results()).await {
+            transactions = Some(result.results);
+            break
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    let ids: Vec<_> = transactions
+        .expect("The index should be backfilled")
+        .into_iter()
+        .map(|tx| tx.transaction.id(&chain_id))
+        .collect();
+    assert_eq!(ids, vec![tx.id(&chain_id)]);
+}
@@ -1,6 +1,8 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+mod api_limits;
+mod assets;
 mod balances;
 mod blocks;
 mod chain;
@@ -13,9 +15,11 @@ mod deployment;
 mod fee_collection_contract;
 mod health;
 mod helpers;
+mod indexes;
 mod messages;
 mod metrics;
 mod node_info;
+mod otlp;
 mod poa;
 #[cfg(feature = "relayer")]
 mod relayer;
@@ -36,8 +36,12 @@ use fuel_core_types::{
     },
     fuel_types::ChainId,
 };
+use futures::StreamExt;
 use rstest::rstest;
-use std::ops::Deref;
+use std::{
+    ops::Deref,
+    time::Duration,
+};
 
 #[cfg(feature = "relayer")]
 mod relayer;
@@ -275,6 +279,69 @@ async fn message_status__can_get_spent() {
     assert_eq!(status, MessageStatus::Spent);
 }
 
+#[tokio::test]
+async fn message_status_change__notifies_until_spent() {
+    // Given
+    let msg_recipient = Address::from([1; 32]);
+    let output_recipient = Address::from([2; 32]);
+    let msg_sender = Address::from([3; 32]);
+
+    let nonce = 1.into();
+    let amount = 1_000;
+
+    let msg = MessageConfig {
+        sender: msg_sender,
+        recipient: msg_recipient,
+        nonce,
+        amount,
+        ..Default::default()
+    };
+
+    let mut config = Config::local_node();
+    config.chain_conf.initial_state = Some(StateConfig {
+        messages: Some(vec![msg]),
+        ..Default::default()
+    });
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let input = Input::message_coin_signed(
+        msg_sender,
+        msg_recipient,
+        amount,
+        nonce,
+        Default::default(),
+    );
+
+    let output = Output::coin(output_recipient, amount, Default::default());
+
+    let tx = Transaction::script(
+        1_000_000,
+        vec![],
+        vec![],
+        policies::Policies::new().with_gas_price(0),
+        vec![input],
+        vec![output],
+        vec![Vec::new().into()],
+    )
+    .into();
+
+    // When
+    let mut statuses = client.subscribe_message_status(&nonce).await.unwrap();
+    let first = statuses.next().await.unwrap().unwrap();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let second = tokio::time::timeout(Duration::from_secs(5), statuses.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    // Then
+    assert_eq!(first, MessageStatus::Unspent);
+    assert_eq!(second, MessageStatus::Spent);
+}
+
 #[tokio::test]
 async fn message_status__can_get_notfound() {
     // Given
@@ -567,6 +634,52 @@ async fn can_get_message_proof() {
                 blocks_count as u64,
             ));
         }
+
+        // Await the proof relatively to the commit block that is not produced yet.
+        let commit_height = last_height
+            .succ()
+            .expect("Should be able to get next height");
+        let (proof, _) = tokio::join!(
+            client.await_message_proof(&transaction_id, nonces[0], commit_height),
+            client.produce_blocks(1, None),
+        );
+        let proof = proof.unwrap();
+        assert_eq!(&proof.nonce, nonces[0]);
+        assert_eq!(proof.commit_block_header.height, u32::from(commit_height));
+
+        // Request the proofs of all messages at once.
+        let messages: Vec<_> = nonces
+            .iter()
+            .map(|nonce| (transaction_id, **nonce))
+            .collect();
+        let proofs = client
+            .message_proofs(&messages, None, Some(last_height))
+            .await
+            .unwrap();
+        assert_eq!(proofs.len(), nonces.len());
+        for (proof, nonce) in proofs.into_iter().zip(nonces) {
+            let proof = proof.expect("Should be able to prove the message");
+            let expected = client
+                .message_proof(&transaction_id, nonce, None, Some(last_height))
+                .await
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(&proof.nonce, nonce);
+            assert_eq!(
+                proof.message_proof.proof_index,
+                expected.message_proof.proof_index
+            );
+            assert_eq!(
+                proof.message_proof.proof_set,
+                expected.message_proof.proof_set
+            );
+            assert_eq!(
+                proof.block_proof.proof_index,
+                expected.block_proof.proof_index
+            );
+            assert_eq!(proof.block_proof.proof_set, expected.block_proof.proof_set);
+        }
     }
 }
 
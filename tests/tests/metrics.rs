@@ -61,3 +61,38 @@ async fn test_metrics_endpoint() {
     // Gt check exists because testing can be weird with multiple instances running
     assert!(categories.len() >= 16);
 }
+
+#[tokio::test]
+async fn test_block_production_metrics() {
+    let mut config = Config::local_node();
+    config.block_producer.metrics = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let client = FuelClient::from(srv.bound_address);
+    client
+        .submit_and_await_commit(&Transaction::default_test_tx())
+        .await
+        .unwrap();
+
+    let resp = reqwest::get(format!("http://{}/metrics", srv.bound_address))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    srv.stop_and_await().await.unwrap();
+
+    for metric in [
+        "poa_block_production_duration_s_count",
+        "poa_available_transactions",
+        "poa_selected_transactions",
+        "poa_gas_utilization_count",
+        "executor_tx_gas_used_count",
+        "executor_predicate_verification_duration_s_count",
+        "sync_header_queue_size",
+        "sync_transaction_queue_size",
+    ] {
+        assert!(resp.contains(metric), "The metric `{metric}` is missing");
+    }
+}
@@ -0,0 +1,111 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::Transaction;
+use hyper::{
+    service::{
+        make_service_fn,
+        service_fn,
+    },
+    Body,
+    Request,
+    Response,
+    Server,
+};
+use opentelemetry_proto::tonic::{
+    collector::trace::v1::ExportTraceServiceRequest,
+    trace::v1::Span,
+};
+use prost::Message;
+use std::convert::Infallible;
+use tokio::sync::mpsc;
+use tracing_subscriber::layer::SubscriberExt;
+
+/// Starts the stand-in for the OTLP collector that forwards all exported spans to the channel.
+fn start_collector() -> (String, mpsc::UnboundedReceiver<Vec<Span>>) {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let make_service = make_service_fn(move |_| {
+        let sender = sender.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                let sender = sender.clone();
+                async move {
+                    let body = hyper::body::to_bytes(request.into_body()).await?;
+                    let request = ExportTraceServiceRequest::decode(body)
+                        .expect("The exporter should send valid OTLP request");
+                    let spans = request
+                        .resource_spans
+                        .into_iter()
+                        .flat_map(|spans| spans.scope_spans)
+                        .flat_map(|spans| spans.spans)
+                        .collect();
+                    let _ = sender.send(spans);
+                    Ok::<_, hyper::Error>(Response::new(Body::empty()))
+                }
+            }))
+        }
+    });
+    let server = Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(make_service);
+    let endpoint = format!("http://{}", server.local_addr());
+    tokio::spawn(server);
+    (endpoint, receiver)
+}
+
+fn find_span<'a>(spans: &'a [Span], name: &str) -> &'a Span {
+    spans
+        .iter()
+        .find(|span| span.name == name)
+        .unwrap_or_else(|| panic!("The span `{name}` should be exported"))
+}
+
+#[tokio::test]
+async fn transaction_can_be_followed_from_submission_to_block_production() {
+    let (endpoint, mut receiver) = start_collector();
+    let otlp = fuel_core_trace::otlp_layer(&endpoint, "fuel-core").unwrap();
+    let subscriber = tracing_subscriber::registry().with(otlp);
+    let guard = tracing::subscriber::set_default(subscriber);
+
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client
+        .submit_and_await_commit(&Transaction::default_test_tx())
+        .await
+        .unwrap();
+    srv.stop_and_await().await.unwrap();
+    drop(guard);
+
+    // The shutdown blocks until the collector receives the spans,
+    // so it can't block the thread of the collector.
+    tokio::task::spawn_blocking(fuel_core_trace::shutdown_otlp)
+        .await
+        .unwrap();
+
+    let mut spans = vec![];
+    while let Ok(exported) = receiver.try_recv() {
+        spans.extend(exported);
+    }
+
+    // The insertion is a part of the GraphQL request.
+    let insertion = find_span(&spans, "insert_submitted_txn");
+    assert!(!insertion.parent_span_id.is_empty());
+    // The transaction lives in the pool inside the trace of the request.
+    let pool_tx = find_span(&spans, "txpool_tx");
+    assert_eq!(pool_tx.trace_id, insertion.trace_id);
+    // The execution of the block links to the transaction it includes.
+    let execution = find_span(&spans, "execute_block");
+    assert!(execution
+        .links
+        .iter()
+        .any(|link| link.trace_id == pool_tx.trace_id
+            && link.span_id == pool_tx.span_id));
+    // The block production and execution belong to the same trace.
+    let production = find_span(&spans, "produce_block");
+    assert_eq!(production.trace_id, execution.trace_id);
+}
@@ -1,4 +1,7 @@
-use crate::helpers::TestContext;
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
 use fuel_core::{
     schema::tx::receipt::all_receipts,
     service::{
@@ -11,15 +14,25 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        DryRunOptions,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_poa::service::Mode;
 use fuel_core_types::{
     fuel_asm::*,
-    fuel_crypto::SecretKey,
-    fuel_tx::*,
+    fuel_crypto::{
+        Hasher,
+        SecretKey,
+    },
+    fuel_tx::{
+        field::Inputs,
+        *,
+    },
     fuel_types::ChainId,
+    services::graphql_api::OffChainIndex,
 };
 use itertools::Itertools;
 use rand::{
@@ -79,7 +92,8 @@ async fn dry_run_script() {
         .add_random_fee_input()
         .finalize_as_transaction();
 
-    let log = client.dry_run(&tx).await.unwrap();
+    let statuses = client.dry_run(&[tx.clone()]).await.unwrap();
+    let log = &statuses[0].receipts;
     assert_eq!(3, log.len());
 
     assert!(matches!(log[0],
@@ -100,6 +114,162 @@ async fn dry_run_script() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_script_with_trace() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::addi(0x11, RegId::ZERO, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let status = client
+        .dry_run_opt(
+            &[tx.clone()],
+            DryRunOptions {
+                trace: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .remove(0);
+    assert_eq!(3, status.receipts.len());
+
+    let trace = status.trace.expect("The trace was requested");
+    let opcodes: Vec<_> = trace
+        .steps
+        .iter()
+        .map(|step| step.opcode.clone().unwrap())
+        .collect();
+    assert_eq!(opcodes, vec!["ADDI", "ADDI", "LOG", "RET"]);
+    assert!(trace.steps[0].changed_registers.contains(&(0x10, 0xca)));
+    assert!(trace.steps.iter().all(|step| step.call_frames.is_empty()));
+
+    let statuses = client.dry_run_opt(&[tx], Default::default()).await.unwrap();
+    assert!(statuses[0].trace.is_none());
+}
+
+#[tokio::test]
+async fn dry_run_script_with_state_diff() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [op::ret(RegId::ONE)];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize();
+    let spent_coin = *tx.inputs()[0].utxo_id().unwrap();
+    let tx: Transaction = tx.into();
+
+    let status = client
+        .dry_run_opt(
+            &[tx.clone()],
+            DryRunOptions {
+                state_diff: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .remove(0);
+    let state_diff = status.state_diff.expect("The state diff was requested");
+    assert_eq!(state_diff.coins_spent, vec![spent_coin]);
+    assert!(state_diff.storage_changes.is_empty());
+    assert!(state_diff.balance_changes.is_empty());
+
+    let statuses = client.dry_run(&[tx]).await.unwrap();
+    assert!(statuses[0].state_diff.is_none());
+}
+
+#[tokio::test]
+async fn dry_run_script_at_historical_height() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+
+    let script = [
+        op::bhei(0x10),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.gen(),
+            1000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::change(rng.gen(), 0, Default::default()))
+        .finalize();
+    test_builder.config_coin_inputs_from_transactions(&[&tx]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    let tx: Transaction = tx.into();
+
+    // Given
+    client.produce_blocks(2, None).await.unwrap();
+    // The coin is spent by the block at height 3.
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // When
+    let at_second_block = client
+        .dry_run_opt(
+            &[tx.clone()],
+            DryRunOptions {
+                block_height: Some(2u32.into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let at_third_block = client
+        .dry_run_opt(
+            &[tx.clone()],
+            DryRunOptions {
+                block_height: Some(3u32.into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let at_next_block = client.dry_run(&[tx]).await;
+
+    // Then
+    assert!(matches!(at_second_block[0].receipts[0], Receipt::Log { ra, .. } if ra == 2));
+    // The coin is still unspent in the state before the third block.
+    assert!(matches!(at_third_block[0].receipts[0], Receipt::Log { ra, .. } if ra == 3));
+    assert!(at_next_block.is_err());
+}
+
 #[tokio::test]
 async fn dry_run_create() {
     let mut rng = StdRng::seed_from_u64(2322);
@@ -118,8 +288,8 @@ async fn dry_run_create() {
         .add_output(Output::contract_created(contract_id, state_root))
         .finalize_as_transaction();
 
-    let receipts = client.dry_run(&tx).await.unwrap();
-    assert_eq!(0, receipts.len());
+    let statuses = client.dry_run(&[tx.clone()]).await.unwrap();
+    assert_eq!(0, statuses[0].receipts.len());
 
     // ensure the tx isn't available in the blockchain history
     let err = client
@@ -129,6 +299,81 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_empty_list_of_transactions_is_rejected() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client.dry_run(&[]).await;
+
+    let error = result.expect_err("Should reject the empty list");
+    assert!(error.to_string().contains("is empty"));
+}
+
+#[tokio::test]
+async fn dry_run_more_transactions_than_limit_is_rejected() {
+    let mut config = Config::local_node();
+    config.api_limits.max_dry_run_transactions = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = |_| {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .script_gas_limit(10000)
+            .add_random_fee_input()
+            .finalize_as_transaction()
+    };
+
+    let within_limit: Vec<_> = (0..2).map(tx).collect();
+    let above_limit: Vec<_> = (0..3).map(tx).collect();
+
+    assert!(client.dry_run(&within_limit).await.is_ok());
+    let error = client
+        .dry_run(&above_limit)
+        .await
+        .expect_err("Should reject transactions above the limit");
+    assert!(error.to_string().contains("exceeds the limit 2"));
+}
+
+#[tokio::test]
+async fn dry_run_below_the_rewind_limit_is_rejected() {
+    let mut config = Config::local_node();
+    config.api_limits.max_dry_run_rewind = 1;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .script_gas_limit(10000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    // Given
+    client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let within_limit = client
+        .dry_run_opt(
+            &[tx.clone()],
+            DryRunOptions {
+                block_height: Some(3u32.into()),
+                ..Default::default()
+            },
+        )
+        .await;
+    let above_limit = client
+        .dry_run_opt(
+            &[tx],
+            DryRunOptions {
+                block_height: Some(1u32.into()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // Then
+    assert!(within_limit.is_ok());
+    let error = above_limit.expect_err("Should reject the rewind above the limit");
+    assert!(error.to_string().contains("exceeds the limit 1"));
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -637,6 +882,273 @@ async fn get_owned_transactions() {
     assert_eq!(&charlie_txs, &[tx1, tx2, tx3]);
 }
 
+#[tokio::test]
+async fn get_contract_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let (_, contract_a) = test_builder.setup_contract(vec![], None, None, None);
+    let (_, contract_b) = test_builder.setup_contract(vec![], None, None, None);
+
+    let tx1 = create_contract_call_tx(&mut rng, &[contract_a]);
+    let tx2 = create_contract_call_tx(&mut rng, &[contract_a, contract_b]);
+    let tx3 = create_contract_call_tx(&mut rng, &[]);
+    test_builder.config_coin_inputs_from_transactions(&[&tx1, &tx2, &tx3]);
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    let txs: Vec<Transaction> = vec![tx1.into(), tx2.into(), tx3.into()];
+    for tx in &txs {
+        client.submit_and_await_commit(tx).await.unwrap();
+    }
+
+    let contract_txs = |contract_id, direction| {
+        let client = &client;
+        async move {
+            let page_request = PaginationRequest {
+                cursor: None,
+                results: 5,
+                direction,
+            };
+            client
+                .transactions_by_contract(&contract_id, page_request)
+                .await
+                .unwrap()
+                .results
+                .iter()
+                .map(|tx| tx.transaction.id(&ChainId::default()))
+                .collect_vec()
+        }
+    };
+    let tx_ids = txs
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .collect_vec();
+
+    assert_eq!(
+        contract_txs(contract_a, PageDirection::Forward).await,
+        vec![tx_ids[0], tx_ids[1]]
+    );
+    assert_eq!(
+        contract_txs(contract_a, PageDirection::Backward).await,
+        vec![tx_ids[1], tx_ids[0]]
+    );
+    assert_eq!(
+        contract_txs(contract_b, PageDirection::Forward).await,
+        vec![tx_ids[1]]
+    );
+}
+
+#[tokio::test]
+async fn get_predicate_root_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    test_builder.indexes.enable(OffChainIndex::PredicateRoots);
+    let predicate_a = op::ret(RegId::ONE).to_bytes().to_vec();
+    let predicate_b = [op::noop(), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect::<Vec<u8>>();
+
+    let txs: Vec<Transaction> = vec![
+        create_predicate_tx(&mut rng, predicate_a.clone()),
+        create_predicate_tx(&mut rng, predicate_b.clone()),
+        create_predicate_tx(&mut rng, predicate_a.clone()),
+    ];
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    for tx in &txs {
+        client.submit_and_await_commit(tx).await.unwrap();
+    }
+
+    let predicate_txs = |predicate: &[u8], direction| {
+        let client = &client;
+        let predicate_root = Input::predicate_owner(predicate);
+        async move {
+            let page_request = PaginationRequest {
+                cursor: None,
+                results: 5,
+                direction,
+            };
+            client
+                .transactions_by_predicate_root(&predicate_root, page_request)
+                .await
+                .unwrap()
+                .results
+                .iter()
+                .map(|tx| tx.transaction.id(&ChainId::default()))
+                .collect_vec()
+        }
+    };
+    let tx_ids = txs
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .collect_vec();
+
+    assert_eq!(
+        predicate_txs(&predicate_a, PageDirection::Forward).await,
+        vec![tx_ids[0], tx_ids[2]]
+    );
+    assert_eq!(
+        predicate_txs(&predicate_a, PageDirection::Backward).await,
+        vec![tx_ids[2], tx_ids[0]]
+    );
+    assert_eq!(
+        predicate_txs(&predicate_b, PageDirection::Forward).await,
+        vec![tx_ids[1]]
+    );
+}
+
+#[tokio::test]
+async fn get_script_hash_transactions() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    test_builder.indexes.enable(OffChainIndex::ScriptHashes);
+    let script_a = op::ret(RegId::ONE).to_bytes().to_vec();
+    let script_b = [op::noop(), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect::<Vec<u8>>();
+    let bytecode = [op::noop(), op::noop(), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect::<Vec<u8>>();
+
+    let contract = Contract::from(bytecode.as_slice());
+    let state_root = Contract::initial_state_root(std::iter::empty());
+    let contract_id = contract.id(&Salt::zeroed(), &contract.root(), &state_root);
+    let create = TransactionBuilder::create(bytecode.into(), Salt::zeroed(), vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    let script = |script: &[u8]| {
+        TransactionBuilder::script(script.to_vec(), vec![])
+            .script_gas_limit(10000)
+            .add_random_fee_input()
+            .finalize_as_transaction()
+    };
+
+    let txs = vec![
+        script(&script_a),
+        script(&script_b),
+        create,
+        script(&script_a),
+    ];
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    for tx in &txs {
+        client.submit_and_await_commit(tx).await.unwrap();
+    }
+
+    let script_txs = |script_hash: Bytes32| {
+        let client = &client;
+        async move {
+            let page_request = PaginationRequest {
+                cursor: None,
+                results: 5,
+                direction: PageDirection::Forward,
+            };
+            client
+                .transactions_by_script_hash(&script_hash, page_request)
+                .await
+                .unwrap()
+                .results
+                .iter()
+                .map(|tx| tx.transaction.id(&ChainId::default()))
+                .collect_vec()
+        }
+    };
+    let tx_ids = txs
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .collect_vec();
+
+    assert_eq!(
+        script_txs(Hasher::hash(&script_a)).await,
+        vec![tx_ids[0], tx_ids[3]]
+    );
+    assert_eq!(script_txs(Hasher::hash(&script_b)).await, vec![tx_ids[1]]);
+    assert_eq!(script_txs(contract.root()).await, vec![tx_ids[2]]);
+}
+
+#[tokio::test]
+async fn get_predicate_root_transactions_fails_if_index_is_disabled() {
+    let context = TestContext::new(100).await;
+
+    let result = context
+        .client
+        .transactions_by_predicate_root(
+            &Address::default(),
+            PaginationRequest {
+                cursor: None,
+                results: 5,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await;
+
+    let err = result.expect_err("The index is disabled by default");
+    assert!(err
+        .to_string()
+        .contains("The `predicate-roots` index is disabled on the node"));
+}
+
+fn create_predicate_tx(rng: &mut StdRng, predicate: Vec<u8>) -> Transaction {
+    let owner = Input::predicate_owner(&predicate);
+    let asset_id = rng.gen();
+    TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            500,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .script_gas_limit(1000)
+        .finalize_as_transaction()
+}
+
+fn create_contract_call_tx(rng: &mut StdRng, contracts: &[ContractId]) -> Script {
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.script_gas_limit(10000).add_unsigned_coin_input(
+        SecretKey::random(rng),
+        rng.gen(),
+        1_000_000,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+    for (i, contract_id) in contracts.iter().enumerate() {
+        // The first input is the coin
+        let input_idx = u8::try_from(i + 1).unwrap();
+        builder
+            .add_input(Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                *contract_id,
+            ))
+            .add_output(Output::contract(
+                input_idx,
+                Default::default(),
+                Default::default(),
+            ));
+    }
+    builder.finalize()
+}
+
 // add random val for unique tx
 fn create_mock_tx(val: u64) -> Transaction {
     let mut rng = StdRng::seed_from_u64(val);
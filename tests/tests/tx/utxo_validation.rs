@@ -9,7 +9,11 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        DryRunOptions,
+        DryRunTransactionStatus,
+        TransactionStatus,
+    },
 };
 use fuel_core_types::{
     fuel_asm::*,
@@ -170,7 +174,19 @@ async fn dry_run_override_utxo_validation() {
 
     let context = TestSetupBuilder::new(2322).finalize().await;
 
-    let log = context.client.dry_run_opt(&tx, Some(false)).await.unwrap();
+    let log = context
+        .client
+        .dry_run_opt(
+            &[tx],
+            DryRunOptions {
+                utxo_validation: Some(false),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .remove(0)
+        .receipts;
     assert_eq!(2, log.len());
 
     assert!(matches!(log[0],
@@ -216,8 +232,91 @@ async fn dry_run_no_utxo_validation_override() {
     let client = TestSetupBuilder::new(2322).finalize().await.client;
 
     // verify that the client validated the inputs and failed the tx
-    let res = client.dry_run_opt(&tx, None).await;
+    let res = client.dry_run_opt(&[tx], Default::default()).await;
+    assert!(res.is_err());
+}
+
+// verify that dry run of several transactions allows spending outputs of the previous ones
+#[tokio::test]
+async fn dry_run_dependent_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let second_owner = SecretKey::random(&mut rng);
+
+    let first = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(10000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1000,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .add_output(Output::coin(
+        Input::owner(&second_owner.public_key()),
+        400,
+        Default::default(),
+    ))
+    .add_output(Output::change(rng.gen(), 0, Default::default()))
+    .finalize();
+    let second = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(10000)
+    .add_unsigned_coin_input(
+        second_owner,
+        UtxoId::new(first.id(&ChainId::default()), 0),
+        400,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .add_output(Output::change(rng.gen(), 0, Default::default()))
+    .finalize_as_transaction();
+
+    // only the coin of the first transaction exists in the genesis block
+    test_builder.config_coin_inputs_from_transactions(&[&first]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // the second transaction is invalid without the first one
+    let res = client
+        .dry_run_opt(
+            &[second.clone()],
+            DryRunOptions {
+                utxo_validation: Some(true),
+                ..Default::default()
+            },
+        )
+        .await;
     assert!(res.is_err());
+
+    let statuses = client
+        .dry_run_opt(
+            &[first.into(), second],
+            DryRunOptions {
+                utxo_validation: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(2, statuses.len());
+    for status in statuses {
+        assert!(matches!(
+            status.status,
+            DryRunTransactionStatus::Success { .. }
+        ));
+        assert!(matches!(status.receipts[0], Receipt::Return { val, .. } if val == 1));
+    }
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 8)]